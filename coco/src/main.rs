@@ -33,6 +33,15 @@ fn main() {
         random_generator,
     );
 
+    run_experiment(
+        ecrs_cmaes_search,
+        SuiteName::Bbob,
+        "",
+        ObserverName::Bbob,
+        "result_folder: ecrs_cmaes_on_bbob",
+        random_generator,
+    );
+
     run_experiment(
         random_search,
         SuiteName::Bbob,
@@ -127,6 +136,28 @@ fn ecrs_ga_search(problem: &mut Problem, _max_budget: usize, _random_generator:
     solver.run();
 }
 
+fn ecrs_cmaes_search(problem: &mut Problem, max_budget: usize, random_generator: &mut RandomState) {
+    let dimension = problem.dimension();
+    let (lower_bound, upper_bound) = problem.get_ranges_of_interest()[0].clone().into_inner();
+    let output_cell = &mut [f64::MAX];
+
+    let mut algorithm = ecrs::cmaes::builder::CmaesAlgorithmBuilder::new()
+        .set_dimensions(dimension)
+        .set_lower_bound(lower_bound)
+        .set_upper_bound(upper_bound)
+        .set_function(|x: &[f64]| {
+            problem.evaluate_function(x, output_cell);
+            output_cell[0]
+        })
+        .set_restart_strategy(ecrs::cmaes::RestartStrategy::Bipop)
+        .set_evaluation_limit(max_budget)
+        .set_seed((random_generator.uniform() * u32::MAX as f64) as u64)
+        .set_probe(Box::new(ecrs::cmaes::probe::empty_probe::EmptyProbe::new()))
+        .build();
+
+    algorithm.run();
+}
+
 /// Code taken from docs of COCO platform
 /// See their GitHub for more information and links
 /// https://github.com/numbbo/coco
@@ -10,28 +10,48 @@
 //!
 //! Logging system details can be found [here](probe)
 //!
+//! Solutions of problems other than TSP can be constructed with use of [graph] module
+//!
+//! [AntColonyOptimization::run] returns [AcoResult] with the best solution found. To embed the algorithm
+//! in a larger control loop, drive it with [AntColonyOptimization::iterate_once] instead.
+//!
+//! Problem may be modified between iterations, see [dynamic] module & population-based ACO in [population].
+//!
 //! ```
 pub mod builder;
 pub mod colony;
+pub mod continuous;
+pub mod daemon;
+pub mod dynamic;
+pub mod goodness;
 pub mod grader;
+pub mod graph;
 pub mod pheromone;
+pub mod population;
 pub mod probe;
 mod solution;
+pub mod stagnation;
 pub mod termination_condition;
 
 #[cfg(feature = "aco_tsp")]
 pub mod tsp;
 
+#[cfg(feature = "aco_vrp")]
+pub mod vrp;
+
 pub use builder::Builder;
 use itertools::Itertools;
 pub use solution::Solution;
 
 use crate::aco::colony::Colony;
+use crate::aco::daemon::DaemonAction;
+use crate::aco::dynamic::{Dynamic, ProblemChange};
 use crate::aco::grader::Grader;
 use crate::aco::pheromone::{Pheromone, PheromoneUpdate};
 use crate::aco::probe::Probe;
 use crate::aco::termination_condition::TerminationCondition;
 use nalgebra::{Dyn, OMatrix};
+use std::time::{Duration, Instant};
 
 pub type FMatrix = OMatrix<f64, Dyn, Dyn>;
 
@@ -39,6 +59,30 @@ pub trait AdditionalArgs {}
 
 impl AdditionalArgs for () {}
 
+/// # ACO Result
+///
+/// Returned by [AntColonyOptimization::run] and [AntColonyOptimization::finish].
+#[derive(Clone, Debug)]
+pub struct AcoResult<Ph> {
+    /// The best solution found during the whole run, [None] if no ant has ever built a solution
+    pub best: Option<Solution>,
+    /// Pheromone after the last iteration
+    pub pheromone: Ph,
+    /// Number of performed iterations
+    pub iterations: usize,
+    /// Time elapsed since the first iteration started
+    pub elapsed: Duration,
+}
+
+/// Progress of the algorithm, kept between iterations
+#[derive(Default)]
+struct RunState {
+    best: Option<Solution>,
+    iterations: usize,
+    start_time: Option<Instant>,
+    terminated: bool,
+}
+
 /// # Ant Colony Optimization
 ///
 /// Encapsulates common ACO algorithm patterns.
@@ -60,7 +104,9 @@ where
     grader: G,
     termination_cond: T,
     probe: Pr,
+    daemon_action: Option<Box<dyn DaemonAction<Args>>>,
     additional_args: Args,
+    state: RunState,
 }
 
 impl<P, C, G, T, Pr, Ph, Args> AntColonyOptimization<P, C, G, T, Pr, Ph, Args>
@@ -74,28 +120,88 @@ where
     Args: AdditionalArgs,
 {
     /// Executes the algorithm
-    pub fn run(mut self) {
-        self.termination_cond.init(&self.pheromone, &self.additional_args);
-        while !self
+    ///
+    /// Returns the best solution found, final pheromone & run statistics.
+    pub fn run(mut self) -> AcoResult<Ph> {
+        while self.iterate_once() {}
+
+        self.finish()
+    }
+
+    /// Performs a single iteration of the algorithm, unless termination condition is met.
+    ///
+    /// Returns false (and does nothing) when the algorithm has already terminated.
+    /// Call [AntColonyOptimization::finish] afterwards to get the results.
+    pub fn iterate_once(&mut self) -> bool {
+        if self.state.terminated {
+            return false;
+        }
+        if self.state.start_time.is_none() {
+            self.state.start_time = Some(Instant::now());
+            self.termination_cond.init(&self.pheromone, &self.additional_args);
+        }
+        if self
             .termination_cond
             .update_and_check(&self.pheromone, &self.additional_args)
         {
-            self.probe.on_iteration_start(&self.additional_args);
-            self.iterate();
-            self.probe.on_iteration_end(&self.additional_args);
+            self.state.terminated = true;
+            return false;
         }
 
-        self.end()
+        self.probe.on_iteration_start(&self.additional_args);
+        self.iterate();
+        self.probe.on_iteration_end(&self.additional_args);
+        self.state.iterations += 1;
+        true
+    }
+
+    /// Returns the best solution found so far
+    pub fn best_solution(&self) -> Option<&Solution> {
+        self.state.best.as_ref()
+    }
+
+    /// Returns current pheromone
+    pub fn pheromone(&self) -> &Ph {
+        &self.pheromone
+    }
+
+    /// Returns number of performed iterations
+    pub fn iterations(&self) -> usize {
+        self.state.iterations
+    }
+
+    /// Ends the run, notifying the probe, and returns the results.
+    pub fn finish(mut self) -> AcoResult<Ph> {
+        self.probe.on_end(&self.additional_args);
+        AcoResult {
+            best: self.state.best,
+            pheromone: self.pheromone,
+            iterations: self.state.iterations,
+            elapsed: self
+                .state
+                .start_time
+                .map(|start| start.elapsed())
+                .unwrap_or_default(),
+        }
     }
 
     fn iterate(&mut self) {
-        let paths = self
+        let mut paths = self
             .colony
             .build_solutions(&mut self.pheromone, &self.additional_args);
+        if let Some(daemon_action) = self.daemon_action.as_mut() {
+            daemon_action.apply(&mut paths, &self.additional_args);
+        }
         let sols = self.grade(paths);
 
-        let best = self.find_best(&sols);
-        self.probe.on_current_best(best, &self.additional_args);
+        // Every ant may get stuck on construction graphs with restricted moves
+        if let Some(best) = self.find_best(&sols) {
+            self.probe.on_current_best(best, &self.additional_args);
+            self.termination_cond.on_current_best(best, &self.additional_args);
+            if !matches!(&self.state.best, Some(best_ever) if best_ever.fitness >= best.fitness) {
+                self.state.best = Some(best.clone());
+            }
+        }
 
         self.pheromone_update
             .apply(&mut self.pheromone, &sols, &self.additional_args);
@@ -104,11 +210,9 @@ where
             .on_pheromone_update(&self.pheromone, &self.additional_args);
     }
 
-    fn find_best<'a>(&mut self, sols: &'a [Solution]) -> &'a Solution {
-        let best = sols
-            .iter()
-            .reduce(|a, b| if a.fitness > b.fitness { a } else { b });
-        best.unwrap()
+    fn find_best<'a>(&mut self, sols: &'a [Solution]) -> Option<&'a Solution> {
+        sols.iter()
+            .reduce(|a, b| if a.fitness > b.fitness { a } else { b })
     }
 
     fn grade(&mut self, paths: Vec<Vec<usize>>) -> Vec<Solution> {
@@ -116,8 +220,93 @@ where
         self.grader.apply(&mut sols, &self.additional_args);
         sols
     }
+}
 
-    fn end(mut self) {
-        self.probe.on_end(&self.additional_args);
+impl<P, C, G, T, Pr, Ph, Args> AntColonyOptimization<P, C, G, T, Pr, Ph, Args>
+where
+    P: PheromoneUpdate<Ph, Args> + Dynamic,
+    C: Colony<Ph, Args> + Dynamic,
+    G: Grader<Args> + Dynamic,
+    T: TerminationCondition<Ph, Args>,
+    Pr: Probe<Ph, Args>,
+    Ph: Pheromone + Dynamic,
+    Args: AdditionalArgs,
+{
+    /// Modifies the problem between iterations, e.g. adds or removes a vertex or changes an edge weight.
+    ///
+    /// The change is forwarded to the colony, pheromone, pheromone update, grader & daemon action.
+    /// The best solution found so far is forgotten, as it solves the old problem.
+    ///
+    /// ## Arguments
+    /// * `change` - change of the problem
+    pub fn change_problem(&mut self, change: &ProblemChange) {
+        self.colony.apply_change(change);
+        self.pheromone.apply_change(change);
+        self.pheromone_update.apply_change(change);
+        self.grader.apply_change(change);
+        if let Some(daemon_action) = self.daemon_action.as_mut() {
+            daemon_action.apply_change(change);
+        }
+        self.state.best = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::goodness::CanonicalGoodness;
+    use crate::aco::graph::{Graph, GraphAntSystemPU, GraphColony, PathCostInverse, Topology};
+    use crate::aco::probe::Probe;
+    use crate::aco::{Builder, FMatrix, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[derive(Default)]
+    struct BestProbe {
+        iteration_bests: Vec<f64>,
+    }
+
+    impl Probe<FMatrix> for BestProbe {
+        fn on_current_best(&mut self, best: &Solution, _: &()) {
+            self.iteration_bests.push(best.fitness);
+        }
+    }
+
+    fn weights() -> FMatrix {
+        FMatrix::from_fn(6, 6, |i, j| 1.0 + (i as f64 - j as f64).abs())
+    }
+
+    #[test]
+    fn run_returns_best_ever_solution() {
+        let weights = weights();
+        let heuristic = weights.map(|w| 1.0 / w);
+        let colony = GraphColony::with_rng(
+            Graph::complete(6),
+            CanonicalGoodness::new(1.0, 1.0, heuristic),
+            4,
+            StdRng::seed_from_u64(7),
+        );
+        let mut aco = Builder::new()
+            .set_colony(colony)
+            .set_pheromone_update(GraphAntSystemPU::new(0.5, Topology::TOUR))
+            .set_grader(PathCostInverse::new(weights, Topology::TOUR))
+            .set_start_pheromone(FMatrix::repeat(6, 6, 1.0))
+            .set_probe(BestProbe::default())
+            .with_iteration_termination(10)
+            .build();
+
+        assert!(aco.iterate_once());
+        assert_eq!(aco.iterations(), 1);
+        let first_best = aco.best_solution().unwrap().fitness;
+
+        while aco.iterate_once() {}
+        assert!(!aco.iterate_once());
+        let best_ever = aco.probe.iteration_bests.iter().cloned().fold(f64::MIN, f64::max);
+        let result = aco.finish();
+
+        assert_eq!(result.iterations, 10);
+        assert_eq!(result.pheromone.nrows(), 6);
+        let best = result.best.unwrap();
+        assert_eq!(best.fitness, best_ever);
+        assert!(best.fitness >= first_best);
     }
 }
@@ -1,9 +1,10 @@
 use crate::aco::colony::Colony;
+use crate::aco::daemon::DaemonAction;
 use crate::aco::grader::Grader;
 use crate::aco::pheromone::{Pheromone, PheromoneUpdate};
 use crate::aco::probe::{Probe, StdoutProbe};
 use crate::aco::termination_condition::{IterationCond, TerminationCondition};
-use crate::aco::{AdditionalArgs, AntColonyOptimization, FMatrix};
+use crate::aco::{AdditionalArgs, AntColonyOptimization};
 use std::marker::PhantomData;
 
 pub trait HasAdditionalArgs {}
@@ -31,6 +32,7 @@ where
     termination_cond: Option<T>,
     start_pheromone: Option<Ph>,
     probe: Option<Pr>,
+    daemon_action: Option<Box<dyn DaemonAction<Args>>>,
     additional_args: Option<Args>,
     _phantom: PhantomData<HasArgs>,
 }
@@ -104,6 +106,17 @@ where
         self.colony = Some(colony);
         self
     }
+
+    /// Sets daemon action applied to paths built by ants before grading, e.g. local search.
+    ///
+    /// For more info see [aco::daemon] module.
+    ///
+    /// ## Arguments
+    /// * `daemon_action` - Implementation of [DaemonAction] trait.
+    pub fn set_daemon_action<D: DaemonAction<Args> + 'static>(mut self, daemon_action: D) -> Self {
+        self.daemon_action = Some(Box::new(daemon_action));
+        self
+    }
 }
 
 impl<P, C, G, T, Pr, Ph, Args> Builder<P, C, G, T, Pr, Ph, Args, Yes>
@@ -132,9 +145,11 @@ where
             grader: self.grader.expect("Grader operator wasn't set"),
             termination_cond: self.termination_cond.expect("Termination condition wasn't set"),
             probe: self.probe.expect("Probe wasn't set"),
+            daemon_action: self.daemon_action,
             additional_args: self
                 .additional_args
                 .expect("AdditionalArgs type has been specified, but no struct was provided"),
+            state: Default::default(),
         }
     }
 }
@@ -157,6 +172,7 @@ where
             termination_cond: None,
             start_pheromone: None,
             probe: None,
+            daemon_action: None,
             additional_args: None,
             _phantom: PhantomData,
         }
@@ -170,6 +186,7 @@ where
             termination_cond: self.termination_cond,
             start_pheromone: self.start_pheromone,
             probe: self.probe,
+            daemon_action: self.daemon_action,
             additional_args: Some(args),
             _phantom: Default::default(),
         }
@@ -201,7 +218,9 @@ where
             grader: self.grader.expect("Grader operator wasn't set"),
             termination_cond: self.termination_cond.expect("Termination condition wasn't set"),
             probe: self.probe.expect("Probe wasn't set"),
+            daemon_action: self.daemon_action,
             additional_args: (),
+            state: Default::default(),
         }
     }
 }
@@ -226,12 +245,13 @@ where
     }
 }
 
-impl<P, C, G, T, Args, HasArgs> Builder<P, C, G, T, StdoutProbe, FMatrix, Args, HasArgs>
+impl<P, C, G, T, Ph, Args, HasArgs> Builder<P, C, G, T, StdoutProbe, Ph, Args, HasArgs>
 where
-    P: PheromoneUpdate<FMatrix, Args>,
-    C: Colony<FMatrix, Args>,
+    P: PheromoneUpdate<Ph, Args>,
+    C: Colony<Ph, Args>,
     G: Grader<Args>,
-    T: TerminationCondition<FMatrix, Args>,
+    T: TerminationCondition<Ph, Args>,
+    Ph: Pheromone,
     Args: AdditionalArgs,
     HasArgs: HasAdditionalArgs,
 {
@@ -0,0 +1,400 @@
+//! Ant Colony Optimization for continuous domains (ACO_R).
+//!
+//! Implementation is based on K. Socha & M. Dorigo, "Ant colony optimization for continuous domains",
+//! DOI: 10.1016/j.ejor.2006.06.046.
+//!
+//! Instead of pheromone matrix, ACO_R keeps an archive of `k` best solutions found so far, sorted by objective
+//! value. Solution of rank `l` gets weight of Gaussian function with mean 1 and standard deviation `q * k`,
+//! evaluated at `l`. Every ant chooses one archived solution (with probability proportional to its weight) and
+//! samples new solution from normal distributions centered at it. Standard deviation in every dimension is
+//! the average distance from the chosen solution to other archived ones, scaled by convergence speed `ξ`.
+//! New solutions are merged into the archive & only `k` best are kept.
+//!
+//! The objective function is minimised, so functions from `test_functions` module can be used directly.
+//!
+//! Algorithm is configured with [Builder](builder::Builder), which follows [aco::Builder](crate::aco::Builder):
+//! it reuses [aco termination conditions](crate::aco::termination_condition) & [aco probes](crate::aco::probe),
+//! with [SolutionArchive] in place of pheromone.
+//!
+//! # Usage example:
+//! ```rust
+//! use ecrs::aco::continuous::builder::Builder;
+//!
+//! let result = Builder::new()
+//!     .set_function(|x: &[f64]| x.iter().map(|xi| xi * xi).sum())
+//!     .set_dimensions(4)
+//!     .set_bounds(-5.0, 5.0)
+//!     .set_seed(42)
+//!     .with_iteration_termination(500)
+//!     .with_stdout_probe()
+//!     .build()
+//!     .run();
+//!
+//! assert!(result.best.value < 1e-6);
+//! ```
+pub mod builder;
+
+use crate::aco::pheromone::Pheromone;
+use crate::aco::probe::Probe;
+use crate::aco::termination_condition::TerminationCondition;
+use crate::util::standard_normal;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Point of the search space together with objective value
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuousSolution {
+    pub x: Vec<f64>,
+    pub value: f64,
+}
+
+/// # Solution Archive
+///
+/// Implements [Pheromone]. Stores the best solutions found so far, sorted by increasing objective value,
+/// together with their weights.
+#[derive(Clone, Debug)]
+pub struct SolutionArchive {
+    solutions: Vec<ContinuousSolution>,
+    weights: Vec<f64>,
+}
+
+impl Pheromone for SolutionArchive {}
+
+impl SolutionArchive {
+    /// Creates a new instance of [SolutionArchive].
+    ///
+    /// ## Arguments
+    /// * `solutions` - initial solutions, their number is the archive size.
+    /// * `locality` - `q`, smaller values make the search concentrate around the best solutions.
+    pub fn new(mut solutions: Vec<ContinuousSolution>, locality: f64) -> Self {
+        assert!(solutions.len() >= 2, "Archive must contain at least 2 solutions");
+        assert!(locality > 0.0, "Locality must be greater than 0");
+        solutions.sort_by(|a, b| a.value.total_cmp(&b.value));
+
+        let k = solutions.len() as f64;
+        let deviation = locality * k;
+        let weights = (0..solutions.len())
+            .map(|rank| {
+                let rank = rank as f64;
+                (-rank * rank / (2.0 * deviation * deviation)).exp()
+                    / (deviation * (2.0 * std::f64::consts::PI).sqrt())
+            })
+            .collect();
+
+        Self { solutions, weights }
+    }
+
+    /// Returns archived solutions, the best first
+    pub fn solutions(&self) -> &[ContinuousSolution] {
+        &self.solutions
+    }
+
+    /// Returns weights of consecutive archived solutions
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Returns the best archived solution
+    pub fn best(&self) -> &ContinuousSolution {
+        &self.solutions[0]
+    }
+
+    /// Merges new solutions into the archive, keeping the size of the archive.
+    ///
+    /// ## Arguments
+    /// * `solutions` - solutions built by ants
+    pub fn update(&mut self, solutions: Vec<ContinuousSolution>) {
+        let size = self.solutions.len();
+        self.solutions.extend(solutions);
+        self.solutions.sort_by(|a, b| a.value.total_cmp(&b.value));
+        self.solutions.truncate(size);
+    }
+
+    /// Samples new point around archived solution chosen with probability proportional to its weight.
+    fn sample<R: Rng>(&self, rng: &mut R, convergence_speed: f64, lower: f64, upper: f64) -> Vec<f64> {
+        let mut random = rng.gen_range(0.0..self.weights.iter().sum::<f64>());
+        let mut guide = self.solutions.len() - 1;
+        for (rank, weight) in self.weights.iter().enumerate() {
+            random -= weight;
+            if random < 0.0 {
+                guide = rank;
+                break;
+            }
+        }
+
+        let mean = &self.solutions[guide].x;
+        let others = (self.solutions.len() - 1) as f64;
+        (0..mean.len())
+            .map(|i| {
+                let deviation = convergence_speed
+                    * self
+                        .solutions
+                        .iter()
+                        .map(|s| (s.x[i] - mean[i]).abs())
+                        .sum::<f64>()
+                    / others;
+                (mean[i] + deviation * standard_normal(rng)).clamp(lower, upper)
+            })
+            .collect()
+    }
+}
+
+/// # Target Value Condition
+///
+/// Implements [TerminationCondition].
+///
+/// Met when objective value of the best archived solution is not greater than the target.
+pub struct TargetValueCond {
+    target: f64,
+}
+
+impl TargetValueCond {
+    /// Creates a new instance of [TargetValueCond]. Implements [TerminationCondition]
+    ///
+    /// ## Arguments
+    /// * `target` - objective value to reach.
+    pub fn new(target: f64) -> Self {
+        Self { target }
+    }
+}
+
+impl TerminationCondition<SolutionArchive> for TargetValueCond {
+    fn init(&mut self, _archive: &SolutionArchive, _: &()) {}
+
+    fn update_and_check(&mut self, archive: &SolutionArchive, _: &()) -> bool {
+        archive.best().value <= self.target
+    }
+}
+
+/// # Continuous Result
+///
+/// Returned by [AcoR::run].
+#[derive(Clone, Debug)]
+pub struct ContinuousResult {
+    /// The best solution found
+    pub best: ContinuousSolution,
+    /// Archive after the last iteration
+    pub archive: SolutionArchive,
+    /// Number of performed iterations
+    pub iterations: usize,
+    /// Number of objective function evaluations
+    pub evaluations: usize,
+    /// Time elapsed since the algorithm started
+    pub elapsed: Duration,
+}
+
+/// Parameters of [AcoR], see [module](self) docs
+pub(crate) struct AcoRConfig {
+    pub(crate) dimensions: usize,
+    pub(crate) lower_bound: f64,
+    pub(crate) upper_bound: f64,
+    pub(crate) archive_size: usize,
+    pub(crate) ants_count: usize,
+    pub(crate) locality: f64,
+    pub(crate) convergence_speed: f64,
+}
+
+/// # ACO_R
+///
+/// Ant Colony Optimization for continuous domains, see [module](self) docs.
+/// Use [Builder](builder::Builder) to create it.
+pub struct AcoR<F, T, Pr>
+where
+    F: FnMut(&[f64]) -> f64,
+    T: TerminationCondition<SolutionArchive>,
+    Pr: Probe<SolutionArchive>,
+{
+    config: AcoRConfig,
+    function: F,
+    termination_cond: T,
+    probe: Pr,
+    rng: StdRng,
+    archive: Option<SolutionArchive>,
+    iterations: usize,
+    evaluations: usize,
+    start_time: Option<Instant>,
+    terminated: bool,
+}
+
+impl<F, T, Pr> AcoR<F, T, Pr>
+where
+    F: FnMut(&[f64]) -> f64,
+    T: TerminationCondition<SolutionArchive>,
+    Pr: Probe<SolutionArchive>,
+{
+    pub(crate) fn new(config: AcoRConfig, function: F, termination_cond: T, probe: Pr, rng: StdRng) -> Self {
+        assert!(config.dimensions > 0, "Dimensions must be > 0");
+        assert!(
+            config.lower_bound < config.upper_bound,
+            "Lower bound must be smaller than upper bound"
+        );
+        assert!(config.archive_size >= 2, "Archive size must be >= 2");
+        assert!(config.ants_count > 0, "Ants count must be > 0");
+        assert!(config.convergence_speed > 0.0, "Convergence speed must be > 0");
+
+        Self {
+            config,
+            function,
+            termination_cond,
+            probe,
+            rng,
+            archive: None,
+            iterations: 0,
+            evaluations: 0,
+            start_time: None,
+            terminated: false,
+        }
+    }
+
+    /// Executes the algorithm
+    ///
+    /// Returns the best solution found, final archive & run statistics.
+    pub fn run(mut self) -> ContinuousResult {
+        while self.iterate_once() {}
+
+        self.finish()
+    }
+
+    /// Performs a single iteration of the algorithm, unless termination condition is met.
+    /// The archive is filled with random solutions before the first iteration.
+    ///
+    /// Returns false (and does nothing) when the algorithm has already terminated.
+    pub fn iterate_once(&mut self) -> bool {
+        if self.terminated {
+            return false;
+        }
+        if self.archive.is_none() {
+            self.start_time = Some(Instant::now());
+            let archive = self.initial_archive();
+            self.termination_cond.init(&archive, &());
+            self.archive = Some(archive);
+        }
+        let archive = self.archive.as_mut().unwrap();
+        if self.termination_cond.update_and_check(archive, &()) {
+            self.terminated = true;
+            return false;
+        }
+
+        self.probe.on_iteration_start(&());
+        let (lower, upper) = (self.config.lower_bound, self.config.upper_bound);
+        let solutions = (0..self.config.ants_count)
+            .map(|_| {
+                let x = archive.sample(&mut self.rng, self.config.convergence_speed, lower, upper);
+                let value = (self.function)(&x);
+                ContinuousSolution { x, value }
+            })
+            .collect::<Vec<_>>();
+        self.evaluations += solutions.len();
+        archive.update(solutions);
+        self.probe.on_pheromone_update(archive, &());
+        self.probe.on_iteration_end(&());
+        self.iterations += 1;
+        true
+    }
+
+    /// Returns the archive, [None] before the first iteration
+    pub fn archive(&self) -> Option<&SolutionArchive> {
+        self.archive.as_ref()
+    }
+
+    /// Ends the run, notifying the probe, and returns the results.
+    pub fn finish(mut self) -> ContinuousResult {
+        if self.archive.is_none() {
+            self.archive = Some(self.initial_archive());
+        }
+        self.probe.on_end(&());
+        let archive = self.archive.unwrap();
+        ContinuousResult {
+            best: archive.best().clone(),
+            archive,
+            iterations: self.iterations,
+            evaluations: self.evaluations,
+            elapsed: self.start_time.map(|start| start.elapsed()).unwrap_or_default(),
+        }
+    }
+
+    fn initial_archive(&mut self) -> SolutionArchive {
+        let (lower, upper) = (self.config.lower_bound, self.config.upper_bound);
+        let solutions = (0..self.config.archive_size)
+            .map(|_| {
+                let x = (0..self.config.dimensions)
+                    .map(|_| self.rng.gen_range(lower..upper))
+                    .collect::<Vec<_>>();
+                let value = (self.function)(&x);
+                ContinuousSolution { x, value }
+            })
+            .collect();
+        self.evaluations += self.config.archive_size;
+        SolutionArchive::new(solutions, self.config.locality)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::continuous::builder::Builder;
+    use crate::aco::continuous::{ContinuousSolution, SolutionArchive, TargetValueCond};
+    use crate::aco::probe::Probe;
+
+    struct EmptyProbe;
+
+    impl Probe<SolutionArchive> for EmptyProbe {}
+
+    fn solution(value: f64) -> ContinuousSolution {
+        ContinuousSolution {
+            x: vec![value],
+            value,
+        }
+    }
+
+    #[test]
+    fn archive_is_sorted_and_keeps_its_size() {
+        let mut archive = SolutionArchive::new(vec![solution(3.0), solution(1.0), solution(2.0)], 0.5);
+        assert!(archive.weights().windows(2).all(|w| w[0] > w[1]));
+
+        archive.update(vec![solution(0.5), solution(4.0)]);
+        let values = archive.solutions().iter().map(|s| s.value).collect::<Vec<_>>();
+        assert_eq!(values, vec![0.5, 1.0, 2.0]);
+        assert_eq!(archive.best(), &solution(0.5));
+    }
+
+    #[test]
+    fn aco_r_minimises_shifted_sphere() {
+        let mut aco = Builder::new()
+            .set_function(|x: &[f64]| x.iter().map(|xi| (xi - 1.5).powi(2)).sum())
+            .set_dimensions(3)
+            .set_bounds(-10.0, 10.0)
+            .set_archive_size(20)
+            .set_ants_count(4)
+            .set_seed(7)
+            .set_termination_condition(TargetValueCond::new(1e-8))
+            .set_probe(EmptyProbe)
+            .build();
+
+        assert!(aco.archive().is_none());
+        while aco.iterate_once() {
+            assert!(aco.archive().unwrap().solutions().len() == 20);
+        }
+        let result = aco.finish();
+
+        assert!(result.best.value <= 1e-8);
+        assert!(result.best.x.iter().all(|xi| (xi - 1.5).abs() < 1e-3));
+        assert_eq!(result.evaluations, 20 + 4 * result.iterations);
+    }
+
+    #[cfg(feature = "test_functions")]
+    #[test]
+    fn aco_r_solves_test_functions() {
+        let result = Builder::new()
+            .set_function(crate::test_functions::ackley)
+            .set_dimensions(2)
+            .set_bounds(-5.0, 5.0)
+            .set_seed(3)
+            .with_iteration_termination(2000)
+            .set_probe(EmptyProbe)
+            .build()
+            .run();
+
+        assert!(result.best.value < 1e-3);
+    }
+}
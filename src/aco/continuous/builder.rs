@@ -0,0 +1,188 @@
+use crate::aco::continuous::{AcoR, AcoRConfig, SolutionArchive};
+use crate::aco::probe::{Probe, StdoutProbe};
+use crate::aco::termination_condition::{IterationCond, TerminationCondition};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Builder for [AcoR]
+///
+/// Default parameters: 2 dimensions, bounds `[-5, 5]`, archive of 50 solutions, 2 ants,
+/// locality `q = 0.1` and convergence speed `ξ = 0.85`.
+pub struct Builder<F, T, Pr>
+where
+    F: FnMut(&[f64]) -> f64,
+    T: TerminationCondition<SolutionArchive>,
+    Pr: Probe<SolutionArchive>,
+{
+    config: AcoRConfig,
+    function: Option<F>,
+    termination_cond: Option<T>,
+    probe: Option<Pr>,
+    seed: Option<u64>,
+}
+
+impl<F, T, Pr> Builder<F, T, Pr>
+where
+    F: FnMut(&[f64]) -> f64,
+    T: TerminationCondition<SolutionArchive>,
+    Pr: Probe<SolutionArchive>,
+{
+    /// Creates a new instance of [Builder] with default parameters.
+    pub fn new() -> Self {
+        Self {
+            config: AcoRConfig {
+                dimensions: 2,
+                lower_bound: -5.0,
+                upper_bound: 5.0,
+                archive_size: 50,
+                ants_count: 2,
+                locality: 0.1,
+                convergence_speed: 0.85,
+            },
+            function: None,
+            termination_cond: None,
+            probe: None,
+            seed: None,
+        }
+    }
+
+    /// Sets the minimised objective function.
+    ///
+    /// ## Arguments
+    /// * `function` - objective function.
+    pub fn set_function(mut self, function: F) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    /// Sets number of dimensions of the search space.
+    ///
+    /// ## Arguments
+    /// * `dimensions` - must be > 0.
+    pub fn set_dimensions(mut self, dimensions: usize) -> Self {
+        self.config.dimensions = dimensions;
+        self
+    }
+
+    /// Sets bounds of the search space, the same in every dimension.
+    ///
+    /// ## Arguments
+    /// * `lower_bound` - lower bound of every coordinate.
+    /// * `upper_bound` - upper bound of every coordinate.
+    pub fn set_bounds(mut self, lower_bound: f64, upper_bound: f64) -> Self {
+        self.config.lower_bound = lower_bound;
+        self.config.upper_bound = upper_bound;
+        self
+    }
+
+    /// Sets size of the solution archive.
+    ///
+    /// ## Arguments
+    /// * `archive_size` - `k`, must be >= 2.
+    pub fn set_archive_size(mut self, archive_size: usize) -> Self {
+        self.config.archive_size = archive_size;
+        self
+    }
+
+    /// Sets number of solutions built in every iteration.
+    ///
+    /// ## Arguments
+    /// * `ants_count` - `m`, must be > 0.
+    pub fn set_ants_count(mut self, ants_count: usize) -> Self {
+        self.config.ants_count = ants_count;
+        self
+    }
+
+    /// Sets locality of the search.
+    ///
+    /// ## Arguments
+    /// * `locality` - `q`, smaller values make ants follow the best solutions more often.
+    pub fn set_locality(mut self, locality: f64) -> Self {
+        self.config.locality = locality;
+        self
+    }
+
+    /// Sets convergence speed.
+    ///
+    /// ## Arguments
+    /// * `convergence_speed` - `ξ`, smaller values concentrate sampling around archived solutions.
+    pub fn set_convergence_speed(mut self, convergence_speed: f64) -> Self {
+        self.config.convergence_speed = convergence_speed;
+        self
+    }
+
+    /// Sets seed of random numbers generator, random if not set.
+    ///
+    /// ## Arguments
+    /// * `seed` - seed.
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the termination condition.
+    ///
+    /// For more info see [aco::termination_condition](crate::aco::termination_condition) module.
+    ///
+    /// ## Arguments
+    /// * `termination_condition` - Implementation of [TerminationCondition] trait.
+    pub fn set_termination_condition(mut self, termination_condition: T) -> Self {
+        self.termination_cond = Some(termination_condition);
+        self
+    }
+
+    /// Sets the used [Probe].
+    ///
+    /// ## Arguments
+    /// * `probe` - [Probe] trait implementation.
+    pub fn set_probe(mut self, probe: Pr) -> Self {
+        self.probe = Some(probe);
+        self
+    }
+
+    /// Builds [AcoR] with provided parameters.
+    ///
+    /// * `function` needs to be specified, if not program will panic
+    /// * `termination_condition` needs to be specified, if not program will panic
+    /// * `probe` needs to be specified, if not program will panic
+    pub fn build(self) -> AcoR<F, T, Pr> {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        AcoR::new(
+            self.config,
+            self.function.expect("Objective function wasn't set"),
+            self.termination_cond.expect("Termination condition wasn't set"),
+            self.probe.expect("Probe wasn't set"),
+            rng,
+        )
+    }
+}
+
+impl<F, Pr> Builder<F, IterationCond, Pr>
+where
+    F: FnMut(&[f64]) -> f64,
+    Pr: Probe<SolutionArchive>,
+{
+    /// Sets iteration termination condition.
+    ///
+    /// ## Arguments
+    /// * `iterations_limit` - maximal number of iterations.
+    pub fn with_iteration_termination(mut self, iterations_limit: usize) -> Self {
+        self.termination_cond = Some(IterationCond::new(iterations_limit));
+        self
+    }
+}
+
+impl<F, T> Builder<F, T, StdoutProbe>
+where
+    F: FnMut(&[f64]) -> f64,
+    T: TerminationCondition<SolutionArchive>,
+{
+    /// Sets probe to [StdoutProbe].
+    pub fn with_stdout_probe(mut self) -> Self {
+        self.probe = Some(StdoutProbe::new());
+        self
+    }
+}
@@ -0,0 +1,28 @@
+//! Daemon actions, i.e. centralised actions applied to solutions constructed by ants
+//! before they are graded, e.g. local search.
+//!
+//! Daemon action is optional, it can be set with [Builder::set_daemon_action](crate::aco::Builder::set_daemon_action).
+//! Implementations of local search for TSP can be found in [tsp::daemon](crate::aco::tsp::daemon) module.
+
+use crate::aco::dynamic::ProblemChange;
+use crate::aco::AdditionalArgs;
+
+/// # Daemon Action
+///
+/// This trait defines common behaviour for daemon actions.
+/// You can implement this trait to provide your custom daemon action to the ACO.
+pub trait DaemonAction<Args: AdditionalArgs = ()> {
+    /// Modifies paths constructed by ants in current iteration.
+    ///
+    /// ## Arguments
+    /// * `paths` - paths built by the colony, not graded yet
+    /// * `args` - problem specific args
+    fn apply(&mut self, paths: &mut [Vec<usize>], args: &Args);
+
+    /// Updates internal state when the problem changes, see [dynamic](crate::aco::dynamic) module.
+    /// Does nothing by default.
+    ///
+    /// ## Arguments
+    /// * `change` - change of the problem
+    fn apply_change(&mut self, _change: &ProblemChange) {}
+}
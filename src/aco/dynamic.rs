@@ -0,0 +1,171 @@
+//! Support for dynamic problems.
+//!
+//! Problem solved by [AntColonyOptimization](crate::aco::AntColonyOptimization) may be changed between
+//! iterations with [AntColonyOptimization::change_problem](crate::aco::AntColonyOptimization::change_problem).
+//! Every change is described by [ProblemChange] and forwarded to the colony, pheromone, pheromone update,
+//! grader & daemon action, which have to implement [Dynamic].
+//!
+//! Both the generic pipeline ([GraphColony](crate::aco::graph::GraphColony), optionally wrapped in
+//! [PopulationColony](crate::aco::population::PopulationColony)) and the TSP one
+//! ([TspColony](crate::aco::tsp::TspColony) with [CanonicalAnt](crate::aco::tsp::ant::CanonicalAnt)
+//! or [ExploitingAnt](crate::aco::tsp::ant::ExploitingAnt), pheromone updates from
+//! [tsp::pheromone](crate::aco::tsp::pheromone), [PathLengthInverse](crate::aco::tsp::PathLengthInverse)
+//! & local searches from [tsp::daemon](crate::aco::tsp::daemon)) support changes.
+//!
+//! Pheromone models differ in how well they adapt to changes, see
+//! [PopulationPheromone](crate::aco::population::PopulationPheromone) for one designed for dynamic problems.
+use crate::aco::FMatrix;
+
+/// # Problem Change
+///
+/// Describes a change of the weighted graph the problem is defined on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProblemChange {
+    /// Appends a new vertex with index equal to the current number of vertices.
+    /// `outgoing[v]` is weight of edge from the new vertex to `v`, `incoming[v]` weight of edge from `v`
+    /// to the new vertex. Both must have length equal to the current number of vertices.
+    AddVertex { outgoing: Vec<f64>, incoming: Vec<f64> },
+    /// Removes the vertex, indices of vertices after it are decreased by one.
+    RemoveVertex(usize),
+    /// Changes weight of a single (directed) edge. For symmetric problems change both directions.
+    SetWeight { from: usize, to: usize, weight: f64 },
+}
+
+impl ProblemChange {
+    /// Returns index of `vertex` after the change, [None] if it was removed.
+    ///
+    /// ## Arguments
+    /// * `vertex` - index of vertex before the change
+    pub fn map_vertex(&self, vertex: usize) -> Option<usize> {
+        match *self {
+            ProblemChange::RemoveVertex(removed) if vertex == removed => None,
+            ProblemChange::RemoveVertex(removed) if vertex > removed => Some(vertex - 1),
+            _ => Some(vertex),
+        }
+    }
+
+    /// Applies the change to matrix indexed by vertices, e.g. weights or heuristic.
+    ///
+    /// ## Arguments
+    /// * `matrix` - square matrix
+    /// * `map` - converts weight to the value stored in the matrix
+    /// * `diagonal` - value stored for edge from the new vertex to itself
+    pub fn apply_to_matrix(&self, matrix: &mut FMatrix, map: impl Fn(f64) -> f64, diagonal: f64) {
+        match self {
+            ProblemChange::AddVertex { outgoing, incoming } => {
+                let n = matrix.nrows();
+                assert_eq!(
+                    outgoing.len(),
+                    n,
+                    "Weights of all outgoing edges must be provided"
+                );
+                assert_eq!(
+                    incoming.len(),
+                    n,
+                    "Weights of all incoming edges must be provided"
+                );
+                let mut resized = std::mem::replace(matrix, FMatrix::zeros(0, 0))
+                    .insert_row(n, diagonal)
+                    .insert_column(n, diagonal);
+                for v in 0..n {
+                    resized[(n, v)] = map(outgoing[v]);
+                    resized[(v, n)] = map(incoming[v]);
+                }
+                *matrix = resized;
+            }
+            ProblemChange::RemoveVertex(vertex) => {
+                *matrix = std::mem::replace(matrix, FMatrix::zeros(0, 0))
+                    .remove_row(*vertex)
+                    .remove_column(*vertex);
+            }
+            ProblemChange::SetWeight { from, to, weight } => matrix[(*from, *to)] = map(*weight),
+        }
+    }
+
+    /// Applies the change to a path, removed vertex is dropped from it.
+    ///
+    /// ## Arguments
+    /// * `path` - vertices in order of visiting
+    pub fn apply_to_path(&self, path: &mut Vec<usize>) {
+        if let ProblemChange::RemoveVertex(_) = self {
+            *path = path.iter().filter_map(|v| self.map_vertex(*v)).collect();
+        }
+    }
+}
+
+/// # Dynamic
+///
+/// Implemented by ACO components that can adapt to [ProblemChange].
+pub trait Dynamic {
+    /// Updates internal state to reflect the change.
+    ///
+    /// ## Arguments
+    /// * `change` - change of the problem
+    fn apply_change(&mut self, change: &ProblemChange);
+}
+
+/// Pheromone on edges of a new vertex is set to the average pheromone on existing edges,
+/// other changes do not affect the pheromone.
+impl Dynamic for FMatrix {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        match change {
+            ProblemChange::AddVertex { .. } => {
+                let average = if self.is_empty() { 1.0 } else { self.mean() };
+                change.apply_to_matrix(self, |_| average, 0.0);
+            }
+            ProblemChange::RemoveVertex(_) => change.apply_to_matrix(self, |w| w, 0.0),
+            ProblemChange::SetWeight { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::dynamic::{Dynamic, ProblemChange};
+    use crate::aco::FMatrix;
+
+    #[test]
+    fn changes_are_applied_to_matrices_and_paths() {
+        let mut weights = FMatrix::from_row_slice(2, 2, &[0.0, 1.0, 2.0, 0.0]);
+
+        ProblemChange::AddVertex {
+            outgoing: vec![3.0, 4.0],
+            incoming: vec![5.0, 6.0],
+        }
+        .apply_to_matrix(&mut weights, |w| w, 0.0);
+        assert_eq!(
+            weights,
+            FMatrix::from_row_slice(3, 3, &[0.0, 1.0, 5.0, 2.0, 0.0, 6.0, 3.0, 4.0, 0.0])
+        );
+
+        let change = ProblemChange::RemoveVertex(1);
+        change.apply_to_matrix(&mut weights, |w| w, 0.0);
+        assert_eq!(weights, FMatrix::from_row_slice(2, 2, &[0.0, 5.0, 3.0, 0.0]));
+
+        let mut path = vec![2, 1, 0];
+        change.apply_to_path(&mut path);
+        assert_eq!(path, vec![1, 0]);
+
+        ProblemChange::SetWeight {
+            from: 0,
+            to: 1,
+            weight: 2.0,
+        }
+        .apply_to_matrix(&mut weights, |w| 1.0 / w, 0.0);
+        assert_eq!(weights[(0, 1)], 0.5);
+    }
+
+    #[test]
+    fn pheromone_matrix_of_new_vertex_is_average() {
+        let mut pheromone = FMatrix::from_row_slice(2, 2, &[0.0, 2.0, 2.0, 0.0]);
+        pheromone.apply_change(&ProblemChange::AddVertex {
+            outgoing: vec![1.0, 1.0],
+            incoming: vec![1.0, 1.0],
+        });
+
+        assert_eq!(
+            pheromone.row(2).iter().cloned().collect::<Vec<_>>(),
+            vec![1.0, 1.0, 0.0]
+        );
+    }
+}
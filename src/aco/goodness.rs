@@ -6,6 +6,7 @@
 //! In this library we refer to this precalculated value as goodness, and this module contains
 //! trait [Goodness] that must be implemented for every goodness calculating object and
 //! implementations of aforementioned trait.
+use crate::aco::dynamic::{Dynamic, ProblemChange};
 use crate::aco::pheromone::Pheromone;
 use crate::aco::FMatrix;
 
@@ -66,9 +67,17 @@ impl Goodness<FMatrix> for CanonicalGoodness {
     }
 }
 
+/// Heuristic is assumed to be the inverse of weights, as created by
+/// [create_heuristic_from_weights](crate::aco::tsp::util::create_heuristic_from_weights).
+impl Dynamic for CanonicalGoodness {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        change.apply_to_matrix(&mut self.heuristic, |w| if w == 0.0 { 0.0 } else { 1.0 / w }, 0.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::aco::tsp::goodness::{CanonicalGoodness, Goodness};
+    use crate::aco::goodness::{CanonicalGoodness, Goodness};
     use crate::aco::FMatrix;
 
     #[test]
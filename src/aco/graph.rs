@@ -0,0 +1,602 @@
+//! Problem independent solution construction.
+//!
+//! Ants in [tsp](crate::aco::tsp) module always build Hamiltonian cycles in a symmetric graph.
+//! This module generalises solution construction, so that ACO can be applied to other
+//! problems, e.g. shortest path, vehicle routing or assignment problems:
+//!
+//! * [ConstructionGraph] - problem specific rules: where ants start, which moves are feasible
+//!   and when a solution is complete,
+//! * [Graph] - configurable [ConstructionGraph] implementation supporting asymmetric graphs,
+//!   paths with start & end nodes and user provided feasibility callbacks,
+//! * [Topology] - describes which edges a path consists of, shared by [GraphColony], [GraphAntSystemPU]
+//!   and [PathCostInverse],
+//! * [GraphColony] - [Colony] implementation building solutions on any [ConstructionGraph].
+//!
+//! ```
+//! use ecrs::aco::graph::{ConstructionGraph, Graph, GraphAntSystemPU, GraphColony, PathCostInverse};
+//! use ecrs::aco::goodness::CanonicalGoodness;
+//! use ecrs::aco::{Builder, FMatrix};
+//!
+//! // Directed graph, missing edges have infinite cost
+//! let inf = f64::INFINITY;
+//! let weights = FMatrix::from_row_slice(4, 4, &[
+//!     0.0, 1.0, 5.0, inf,
+//!     inf, 0.0, 1.0, 9.0,
+//!     inf, inf, 0.0, 1.0,
+//!     inf, inf, inf, 0.0,
+//! ]);
+//! let graph = Graph::from_weights(&weights).with_start(0).with_end(3);
+//! let topology = graph.topology();
+//! let heuristic = weights.map(|w| if w == 0.0 { 0.0 } else { 1.0 / w });
+//! let goodness = CanonicalGoodness::new(1.0, 2.0, heuristic);
+//!
+//! let aco = Builder::new()
+//!     .set_colony(GraphColony::new(graph, goodness, 10))
+//!     .set_pheromone_update(GraphAntSystemPU::new(0.1, topology))
+//!     .set_grader(PathCostInverse::new(weights, topology))
+//!     .set_start_pheromone(FMatrix::repeat(4, 4, 1.0))
+//!     .with_iteration_termination(20)
+//!     .with_stdout_probe()
+//!     .build();
+//!
+//! let result = aco.run();
+//! assert_eq!(result.iterations, 20);
+//! assert_eq!(result.best.unwrap().path, vec![0, 1, 2, 3]);
+//! ```
+
+use crate::aco::colony::Colony;
+use crate::aco::dynamic::{Dynamic, ProblemChange};
+use crate::aco::goodness::Goodness;
+use crate::aco::grader::Grader;
+use crate::aco::pheromone::PheromoneUpdate;
+use crate::aco::{AdditionalArgs, FMatrix, Solution};
+use itertools::Itertools;
+use rand::prelude::ThreadRng;
+use rand::{thread_rng, Rng};
+
+/// # Topology
+///
+/// Describes which edges are traversed by a path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Topology {
+    /// Path is a cycle, i.e. edge from the last to the first node is a part of the solution
+    pub closed: bool,
+    /// Edges are undirected, i.e. pheromone is deposited in both directions
+    pub symmetric: bool,
+}
+
+impl Topology {
+    /// Topology of TSP tours: closed & symmetric
+    pub const TOUR: Topology = Topology {
+        closed: true,
+        symmetric: true,
+    };
+
+    /// Topology of paths in directed graphs: open & asymmetric
+    pub const DIRECTED_PATH: Topology = Topology {
+        closed: false,
+        symmetric: false,
+    };
+
+    /// Returns edges traversed by `path`, each in the direction it was traversed in.
+    ///
+    /// ## Arguments
+    /// * `path` - nodes in order of visiting
+    pub fn edges(&self, path: &[usize]) -> Vec<(usize, usize)> {
+        let mut edges = path
+            .iter()
+            .cloned()
+            .tuple_windows::<(usize, usize)>()
+            .collect_vec();
+        if self.closed && path.len() > 1 {
+            edges.push((path[path.len() - 1], path[0]));
+        }
+        edges
+    }
+
+    /// Adds `amount` of pheromone on edges traversed by `path`.
+    ///
+    /// ## Arguments
+    /// * `pheromone` - pheromone matrix
+    /// * `path` - nodes in order of visiting
+    /// * `amount` - pheromone left on every edge
+    pub fn deposit(&self, pheromone: &mut FMatrix, path: &[usize], amount: f64) {
+        for (i, j) in self.edges(path) {
+            pheromone[(i, j)] += amount;
+            if self.symmetric && i != j {
+                pheromone[(j, i)] += amount;
+            }
+        }
+    }
+}
+
+/// # Construction Graph
+///
+/// This trait defines problem specific rules of solution construction.
+/// You can implement this trait to provide your custom construction rules to [GraphColony].
+pub trait ConstructionGraph {
+    /// Returns number of graph nodes.
+    fn size(&self) -> usize;
+
+    /// Returns node every ant starts from. When `None` is returned, starting node is drawn for each ant.
+    fn start_node(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns true when ant having built `path` may go to `next` node.
+    ///
+    /// ## Arguments
+    /// * `path` - partial solution, contains at least starting node
+    /// * `visited` - `visited[v]` is true iff node `v` is already in `path`
+    /// * `next` - candidate node
+    fn is_feasible(&self, path: &[usize], visited: &[bool], next: usize) -> bool;
+
+    /// Returns true when `path` is a complete solution.
+    ///
+    /// ## Arguments
+    /// * `path` - partial solution, contains at least starting node
+    fn is_complete(&self, path: &[usize]) -> bool;
+
+    /// Returns topology of constructed solutions.
+    fn topology(&self) -> Topology;
+}
+
+/// # Graph
+///
+/// Implements [ConstructionGraph].
+///
+/// Ant may go to a node iff it has not been visited yet, there is an edge leading to it &
+/// feasibility callback (if provided) allows it. Solution is complete when end node is
+/// reached or, if no end node was specified, when every node is visited.
+pub struct Graph {
+    size: usize,
+    adjacency: Option<Vec<bool>>,
+    start: Option<usize>,
+    end: Option<usize>,
+    feasibility: Option<fn(&[usize], usize) -> bool>,
+    topology: Topology,
+}
+
+impl Graph {
+    /// Creates complete graph with closed & symmetric topology, i.e. TSP construction graph.
+    ///
+    /// ## Arguments
+    /// * `size` - number of nodes
+    pub fn complete(size: usize) -> Self {
+        assert!(size > 0, "Graph must have at least one node");
+        Self {
+            size,
+            adjacency: None,
+            start: None,
+            end: None,
+            feasibility: None,
+            topology: Topology::TOUR,
+        }
+    }
+
+    /// Creates directed graph with open & asymmetric topology. Edge `(i, j)` exists iff `i != j`
+    /// and its weight is finite.
+    ///
+    /// ## Arguments
+    /// * `weights` - square matrix of edge weights, `f64::INFINITY` marks missing edges
+    pub fn from_weights(weights: &FMatrix) -> Self {
+        assert_eq!(weights.nrows(), weights.ncols(), "Weights matrix must be square");
+        let size = weights.nrows();
+        let adjacency = (0..size)
+            .cartesian_product(0..size)
+            .map(|(i, j)| i != j && weights[(i, j)].is_finite())
+            .collect_vec();
+
+        Self {
+            adjacency: Some(adjacency),
+            topology: Topology::DIRECTED_PATH,
+            ..Self::complete(size)
+        }
+    }
+
+    /// Sets node every ant starts from.
+    ///
+    /// ## Arguments
+    /// * `start` - starting node
+    pub fn with_start(mut self, start: usize) -> Self {
+        assert!(start < self.size, "Start node out of range");
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets node completing the solution.
+    ///
+    /// ## Arguments
+    /// * `end` - end node
+    pub fn with_end(mut self, end: usize) -> Self {
+        assert!(end < self.size, "End node out of range");
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets additional feasibility rule, checked after the default ones.
+    ///
+    /// ## Arguments
+    /// * `feasibility` - returns true iff ant having built path (first argument) may go to node (second argument)
+    pub fn with_feasibility(mut self, feasibility: fn(&[usize], usize) -> bool) -> Self {
+        self.feasibility = Some(feasibility);
+        self
+    }
+
+    /// Overrides topology of constructed solutions.
+    ///
+    /// ## Arguments
+    /// * `topology` - new topology
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        match &self.adjacency {
+            Some(adjacency) => adjacency[from * self.size + to],
+            None => from != to,
+        }
+    }
+}
+
+impl ConstructionGraph for Graph {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn start_node(&self) -> Option<usize> {
+        self.start
+    }
+
+    fn is_feasible(&self, path: &[usize], visited: &[bool], next: usize) -> bool {
+        let last = *path.last().expect("Path must contain starting node");
+        !visited[next]
+            && self.has_edge(last, next)
+            && !matches!(self.feasibility, Some(feasible) if !feasible(path, next))
+    }
+
+    fn is_complete(&self, path: &[usize]) -> bool {
+        match self.end {
+            Some(end) => path.last() == Some(&end),
+            None => path.len() == self.size,
+        }
+    }
+
+    fn topology(&self) -> Topology {
+        self.topology
+    }
+}
+
+/// Start & end nodes are kept, so they must not be removed.
+impl Dynamic for Graph {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        let map_node = |node: Option<usize>| {
+            node.map(|v| {
+                change
+                    .map_vertex(v)
+                    .expect("Start & end nodes can not be removed")
+            })
+        };
+        self.start = map_node(self.start);
+        self.end = map_node(self.end);
+
+        if let Some(adjacency) = &self.adjacency {
+            let mut edges = FMatrix::from_fn(self.size, self.size, |i, j| {
+                if adjacency[i * self.size + j] {
+                    1.0
+                } else {
+                    f64::INFINITY
+                }
+            });
+            change.apply_to_matrix(
+                &mut edges,
+                |w| if w.is_finite() { 1.0 } else { f64::INFINITY },
+                0.0,
+            );
+            self.adjacency = Some(
+                (0..edges.nrows())
+                    .cartesian_product(0..edges.ncols())
+                    .map(|(i, j)| i != j && edges[(i, j)].is_finite())
+                    .collect_vec(),
+            );
+        }
+
+        match change {
+            ProblemChange::AddVertex { .. } => self.size += 1,
+            ProblemChange::RemoveVertex(_) => self.size -= 1,
+            ProblemChange::SetWeight { .. } => {}
+        }
+    }
+}
+
+/// # Graph Colony
+///
+/// Implements [Colony].
+///
+/// Every ant builds a solution on [ConstructionGraph], choosing next node from feasible ones
+/// with probability proportional to edge goodness (see [Goodness]). Ants which get stuck,
+/// i.e. have no feasible move before completing a solution, are discarded.
+pub struct GraphColony<CG: ConstructionGraph, G: Goodness<FMatrix>, R: Rng = ThreadRng> {
+    graph: CG,
+    goodness: G,
+    ants_count: usize,
+    rng: R,
+}
+
+impl<CG: ConstructionGraph, G: Goodness<FMatrix>> GraphColony<CG, G, ThreadRng> {
+    /// Creates a new instance of [GraphColony] with default RNG.
+    ///
+    /// ## Arguments
+    /// * `graph` - construction rules.
+    /// * `goodness` - Implementation of [Goodness].
+    /// * `ants_count` - number of solutions built in every iteration.
+    pub fn new(graph: CG, goodness: G, ants_count: usize) -> Self {
+        Self::with_rng(graph, goodness, ants_count, thread_rng())
+    }
+}
+
+impl<CG: ConstructionGraph, G: Goodness<FMatrix>, R: Rng> GraphColony<CG, G, R> {
+    /// Creates a new instance of [GraphColony] with user specified RNG.
+    ///
+    /// ## Arguments
+    /// * `graph` - construction rules.
+    /// * `goodness` - Implementation of [Goodness].
+    /// * `ants_count` - number of solutions built in every iteration.
+    /// * `rng` - Random numbers generator.
+    pub fn with_rng(graph: CG, goodness: G, ants_count: usize, rng: R) -> Self {
+        assert!(ants_count > 0, "Colony must contain at least one ant");
+        Self {
+            graph,
+            goodness,
+            ants_count,
+            rng,
+        }
+    }
+
+    /// Returns construction graph
+    pub fn graph(&self) -> &CG {
+        &self.graph
+    }
+
+    /// Builds single solution. Returns `None` when the ant got stuck.
+    fn construct(&mut self, goodness: &FMatrix) -> Option<Vec<usize>> {
+        let size = self.graph.size();
+        let start = match self.graph.start_node() {
+            Some(start) => start,
+            None => self.rng.gen_range(0..size),
+        };
+        let mut visited = vec![false; size];
+        let mut path = Vec::with_capacity(size);
+        visited[start] = true;
+        path.push(start);
+
+        while !self.graph.is_complete(&path) {
+            let last = path[path.len() - 1];
+            let candidates = (0..size)
+                .filter(|&v| self.graph.is_feasible(&path, &visited, v))
+                .map(|v| (v, goodness[(last, v)]))
+                .collect_vec();
+
+            let goodness_sum: f64 = candidates.iter().map(|(_, g)| g).sum();
+            if candidates.is_empty() || goodness_sum <= 0.0 || !goodness_sum.is_finite() {
+                return None;
+            }
+
+            let mut random: f64 = self.rng.gen_range(0.0..goodness_sum);
+            let mut next = candidates[candidates.len() - 1].0;
+            for (v, g) in candidates {
+                random -= g;
+                if random < 0.0 {
+                    next = v;
+                    break;
+                }
+            }
+
+            visited[next] = true;
+            path.push(next);
+        }
+
+        Some(path)
+    }
+}
+
+impl<CG, G, R, Args> Colony<FMatrix, Args> for GraphColony<CG, G, R>
+where
+    CG: ConstructionGraph,
+    G: Goodness<FMatrix>,
+    R: Rng,
+    Args: AdditionalArgs,
+{
+    fn build_solutions(&mut self, pheromone: &mut FMatrix, _: &Args) -> Vec<Vec<usize>> {
+        let goodness = self.goodness.apply(pheromone);
+        (0..self.ants_count)
+            .filter_map(|_| self.construct(&goodness))
+            .collect_vec()
+    }
+}
+
+impl<CG, G, R> Dynamic for GraphColony<CG, G, R>
+where
+    CG: ConstructionGraph + Dynamic,
+    G: Goodness<FMatrix> + Dynamic,
+    R: Rng,
+{
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.graph.apply_change(change);
+        self.goodness.apply_change(change);
+    }
+}
+
+/// # Graph Ant System Pheromone Update
+///
+/// Implements [PheromoneUpdate]. Works like [AntSystemPU](crate::aco::tsp::pheromone::AntSystemPU),
+/// but deposits pheromone according to [Topology] of the construction graph.
+pub struct GraphAntSystemPU {
+    evaporation_rate: f64,
+    topology: Topology,
+}
+
+impl GraphAntSystemPU {
+    /// Creates a new instance of [GraphAntSystemPU]
+    ///
+    /// ## Arguments
+    /// * `evaporation_rate` - rate of old pheromone evaporation
+    /// * `topology` - topology of constructed solutions, see [ConstructionGraph::topology]
+    pub fn new(evaporation_rate: f64, topology: Topology) -> Self {
+        Self {
+            evaporation_rate,
+            topology,
+        }
+    }
+}
+
+impl<Args: AdditionalArgs> PheromoneUpdate<FMatrix, Args> for GraphAntSystemPU {
+    fn apply(&mut self, pheromone: &mut FMatrix, solutions: &[Solution], _: &Args) {
+        pheromone.scale_mut(1.0 - self.evaporation_rate);
+        for s in solutions.iter() {
+            self.topology.deposit(pheromone, &s.path, s.fitness);
+        }
+    }
+}
+
+/// Pheromone update does not depend on the problem, changes are ignored
+impl Dynamic for GraphAntSystemPU {
+    fn apply_change(&mut self, _change: &ProblemChange) {}
+}
+
+/// # Path Cost Inverse
+///
+/// Implements [Grader]. Calculates fitness as 1.0 / path_cost, where cost of the path is a sum of
+/// weights of edges determined by [Topology].
+pub struct PathCostInverse {
+    weights: FMatrix,
+    topology: Topology,
+}
+
+impl PathCostInverse {
+    /// Creates a new instance of [PathCostInverse]
+    ///
+    /// ## Arguments
+    /// * `weights` - edge weights
+    /// * `topology` - topology of graded solutions
+    pub fn new(weights: FMatrix, topology: Topology) -> Self {
+        Self { weights, topology }
+    }
+
+    fn grade_solution(&self, sol: &mut Solution) {
+        let cost: f64 = self
+            .topology
+            .edges(&sol.path)
+            .into_iter()
+            .map(|edge| self.weights[edge])
+            .sum();
+
+        sol.fitness = 1.0 / cost;
+    }
+}
+
+impl<Args: AdditionalArgs> Grader<Args> for PathCostInverse {
+    fn apply(&mut self, sols: &mut [Solution], _: &Args) {
+        sols.iter_mut().for_each(|sol| self.grade_solution(sol))
+    }
+}
+
+impl Dynamic for PathCostInverse {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        change.apply_to_matrix(&mut self.weights, |w| w, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::colony::Colony;
+    use crate::aco::goodness::CanonicalGoodness;
+    use crate::aco::grader::Grader;
+    use crate::aco::graph::{ConstructionGraph, Graph, GraphColony, PathCostInverse, Topology};
+    use crate::aco::{FMatrix, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn directed_weights() -> FMatrix {
+        let inf = f64::INFINITY;
+        FMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                0.0, 1.0, 5.0, inf, //
+                inf, 0.0, 1.0, 9.0, //
+                inf, inf, 0.0, 1.0, //
+                inf, inf, inf, 0.0,
+            ],
+        )
+    }
+
+    #[test]
+    fn topology_returns_traversed_edges() {
+        assert_eq!(Topology::TOUR.edges(&[0, 2, 1]), vec![(0, 2), (2, 1), (1, 0)]);
+        assert_eq!(Topology::DIRECTED_PATH.edges(&[0, 2, 1]), vec![(0, 2), (2, 1)]);
+
+        let mut pheromone = FMatrix::zeros(3, 3);
+        Topology::DIRECTED_PATH.deposit(&mut pheromone, &[0, 2, 1], 0.5);
+        assert_eq!(pheromone[(0, 2)], 0.5);
+        assert_eq!(pheromone[(2, 0)], 0.0);
+    }
+
+    #[test]
+    fn graph_colony_builds_paths_respecting_directed_edges() {
+        let weights = directed_weights();
+        let graph = Graph::from_weights(&weights).with_start(0).with_end(3);
+        let goodness = CanonicalGoodness::new(1.0, 1.0, FMatrix::repeat(4, 4, 1.0));
+        let mut colony = GraphColony::with_rng(graph, goodness, 20, StdRng::seed_from_u64(1));
+
+        let paths = colony.build_solutions(&mut FMatrix::repeat(4, 4, 1.0), &());
+
+        assert_eq!(paths.len(), 20);
+        for path in paths {
+            assert_eq!(path[0], 0);
+            assert_eq!(path[path.len() - 1], 3);
+            assert!(path.windows(2).all(|e| weights[(e[0], e[1])].is_finite()));
+        }
+    }
+
+    #[test]
+    fn graph_colony_respects_feasibility_callback() {
+        // Node 1 may be visited only as the last one
+        let graph = Graph::complete(4).with_feasibility(|path, next| next != 1 || path.len() == 3);
+        assert!(graph.is_complete(&[0, 2, 3, 1]));
+        let goodness = CanonicalGoodness::default(4);
+        let mut colony = GraphColony::with_rng(graph, goodness, 10, StdRng::seed_from_u64(2));
+
+        let paths = colony.build_solutions(&mut FMatrix::repeat(4, 4, 1.0), &());
+
+        // Ants starting at node 1 are feasible as well
+        assert!(!paths.is_empty());
+        for path in paths {
+            assert_eq!(path.len(), 4);
+            assert!(path[0] == 1 || path[3] == 1);
+        }
+    }
+
+    #[test]
+    fn stuck_ants_are_discarded() {
+        // There is no path from node 3 to node 0
+        let graph = Graph::from_weights(&directed_weights()).with_start(3).with_end(0);
+        let mut colony = GraphColony::new(graph, CanonicalGoodness::default(4), 5);
+
+        assert!(colony
+            .build_solutions(&mut FMatrix::repeat(4, 4, 1.0), &())
+            .is_empty());
+    }
+
+    #[test]
+    fn path_cost_inverse_uses_topology() {
+        let weights = directed_weights();
+        let mut grader = PathCostInverse::new(weights, Topology::DIRECTED_PATH);
+        let mut sols = [Solution::from_path(vec![0, 1, 2, 3])];
+
+        grader.apply(&mut sols, &());
+
+        assert_eq!(sols[0].fitness, 1.0 / 3.0);
+    }
+}
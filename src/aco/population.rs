@@ -0,0 +1,321 @@
+//! Population-based ACO (P-ACO).
+//!
+//! Instead of evaporating pheromone matrix, P-ACO keeps an archive (population) of good solutions.
+//! Pheromone is implied by the archive: every edge has `initial` pheromone, increased by `deposit`
+//! for every archived solution traversing it. When a solution leaves the archive, its influence disappears
+//! completely, which makes P-ACO well suited for [dynamic](crate::aco::dynamic) problems.
+//!
+//! Main parts of the model:
+//! * [PopulationPheromone] - the archive & implied pheromone matrix,
+//! * [PopulationPU] - inserts the best solution of every iteration into the archive, replacing archived
+//!   one according to [ArchivePolicy],
+//! * [PopulationColony] - adapts any colony working on pheromone matrix. The colony works on a copy
+//!   of the matrix, so local pheromone updates (e.g. in Ant Colony System) last for a single iteration only.
+//!
+//! ```
+//! use ecrs::aco::graph::{Graph, GraphColony, PathCostInverse, Topology};
+//! use ecrs::aco::goodness::CanonicalGoodness;
+//! use ecrs::aco::population::{ArchivePolicy, PopulationColony, PopulationPU, PopulationPheromone};
+//! use ecrs::aco::dynamic::ProblemChange;
+//! use ecrs::aco::{Builder, FMatrix};
+//!
+//! let weights = FMatrix::from_fn(5, 5, |i, j| 1.0 + (i as f64 - j as f64).abs());
+//! let heuristic = weights.map(|w| 1.0 / w);
+//! let colony = GraphColony::new(Graph::complete(5), CanonicalGoodness::new(1.0, 2.0, heuristic), 10);
+//!
+//! let mut aco = Builder::new()
+//!     .set_colony(PopulationColony::new(colony))
+//!     .set_pheromone_update(PopulationPU::new(5, ArchivePolicy::Fifo))
+//!     .set_grader(PathCostInverse::new(weights, Topology::TOUR))
+//!     .set_start_pheromone(PopulationPheromone::new(5, 1.0, 1.0, Topology::TOUR))
+//!     .with_stdout_probe()
+//!     .with_iteration_termination(20)
+//!     .build();
+//!
+//! for _ in 0..10 {
+//!     aco.iterate_once();
+//! }
+//! // City 2 disappears, archived solutions are repaired
+//! aco.change_problem(&ProblemChange::RemoveVertex(2));
+//! let result = aco.run();
+//! assert_eq!(result.best.unwrap().path.len(), 4);
+//! ```
+use crate::aco::colony::Colony;
+use crate::aco::dynamic::{Dynamic, ProblemChange};
+use crate::aco::graph::Topology;
+use crate::aco::pheromone::{Pheromone, PheromoneUpdate};
+use crate::aco::{AdditionalArgs, FMatrix, Solution};
+use std::collections::VecDeque;
+
+/// # Population Pheromone
+///
+/// Implements [Pheromone]. Archive of solutions, together with pheromone matrix implied by it.
+pub struct PopulationPheromone {
+    archive: VecDeque<Solution>,
+    matrix: FMatrix,
+    initial: f64,
+    deposit: f64,
+    topology: Topology,
+}
+
+impl Pheromone for PopulationPheromone {}
+
+impl PopulationPheromone {
+    /// Creates a new instance of [PopulationPheromone] with empty archive.
+    ///
+    /// ## Arguments
+    /// * `size` - number of vertices.
+    /// * `initial` - pheromone on every edge, when no archived solution traverses it. Must be > 0.
+    /// * `deposit` - pheromone added on edges of every archived solution.
+    /// * `topology` - topology of archived solutions.
+    pub fn new(size: usize, initial: f64, deposit: f64, topology: Topology) -> Self {
+        assert!(initial > 0.0, "Initial pheromone must be greater than 0");
+        Self {
+            archive: VecDeque::new(),
+            matrix: FMatrix::repeat(size, size, initial),
+            initial,
+            deposit,
+            topology,
+        }
+    }
+
+    /// Returns pheromone matrix implied by the archive
+    pub fn matrix(&self) -> &FMatrix {
+        &self.matrix
+    }
+
+    /// Returns archived solutions, the oldest first
+    pub fn archive(&self) -> &VecDeque<Solution> {
+        &self.archive
+    }
+
+    /// Appends solution to the archive.
+    ///
+    /// ## Arguments
+    /// * `solution` - archived solution
+    pub fn push(&mut self, solution: Solution) {
+        self.archive.push_back(solution);
+        self.recompute();
+    }
+
+    /// Removes solution from the archive.
+    ///
+    /// ## Arguments
+    /// * `idx` - index of solution, in order of insertion
+    pub fn remove(&mut self, idx: usize) -> Option<Solution> {
+        let removed = self.archive.remove(idx);
+        self.recompute();
+        removed
+    }
+
+    /// Recomputes pheromone matrix from scratch
+    fn recompute(&mut self) {
+        self.matrix.fill(self.initial);
+        for solution in self.archive.iter() {
+            self.topology
+                .deposit(&mut self.matrix, &solution.path, self.deposit);
+        }
+    }
+}
+
+/// Removed vertex is dropped from archived solutions, their fitness is left unchanged.
+impl Dynamic for PopulationPheromone {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        change.apply_to_matrix(&mut self.matrix, |_| self.initial, self.initial);
+        self.archive
+            .iter_mut()
+            .for_each(|solution| change.apply_to_path(&mut solution.path));
+        self.recompute();
+    }
+}
+
+/// # Archive Policy
+///
+/// Decides which solution leaves full archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchivePolicy {
+    /// The oldest solution is replaced
+    Fifo,
+    /// The worst solution is replaced, if the new one is better
+    Quality,
+}
+
+/// # Population Pheromone Update
+///
+/// Implements [PheromoneUpdate]. Inserts the best solution of the iteration into the archive
+/// of [PopulationPheromone], replacing archived solution chosen by [ArchivePolicy] when the archive is full.
+pub struct PopulationPU {
+    capacity: usize,
+    policy: ArchivePolicy,
+}
+
+impl PopulationPU {
+    /// Creates a new instance of [PopulationPU]
+    ///
+    /// ## Arguments
+    /// * `capacity` - maximal number of archived solutions, must be > 0.
+    /// * `policy` - archive replacement policy.
+    pub fn new(capacity: usize, policy: ArchivePolicy) -> Self {
+        assert!(capacity > 0, "Archive capacity must be greater than 0");
+        Self { capacity, policy }
+    }
+}
+
+impl<Args: AdditionalArgs> PheromoneUpdate<PopulationPheromone, Args> for PopulationPU {
+    fn apply(&mut self, pheromone: &mut PopulationPheromone, solutions: &[Solution], _: &Args) {
+        let Some(best) = solutions.iter().max_by(|a, b| a.fitness.total_cmp(&b.fitness)) else {
+            return;
+        };
+
+        if pheromone.archive.len() >= self.capacity {
+            let replaced = match self.policy {
+                ArchivePolicy::Fifo => 0,
+                ArchivePolicy::Quality => {
+                    let (worst, worst_sol) = pheromone
+                        .archive
+                        .iter()
+                        .enumerate()
+                        .min_by(|(_, a), (_, b)| a.fitness.total_cmp(&b.fitness))
+                        .unwrap();
+                    if worst_sol.fitness >= best.fitness {
+                        return;
+                    }
+                    worst
+                }
+            };
+            pheromone.archive.remove(replaced);
+        }
+        pheromone.push(best.clone());
+    }
+}
+
+/// Pheromone update does not depend on the problem, changes are ignored
+impl Dynamic for PopulationPU {
+    fn apply_change(&mut self, _change: &ProblemChange) {}
+}
+
+/// # Population Colony
+///
+/// Implements [Colony]. Builds solutions with wrapped colony, using copy of pheromone matrix implied by the archive,
+/// so that the matrix stays implied by the archive even if the colony modifies it.
+pub struct PopulationColony<C> {
+    colony: C,
+}
+
+impl<C> PopulationColony<C> {
+    /// Creates a new instance of [PopulationColony]
+    ///
+    /// ## Arguments
+    /// * `colony` - colony working on pheromone matrix.
+    pub fn new(colony: C) -> Self {
+        Self { colony }
+    }
+}
+
+impl<C: Colony<FMatrix, Args>, Args: AdditionalArgs> Colony<PopulationPheromone, Args>
+    for PopulationColony<C>
+{
+    fn build_solutions(&mut self, pheromone: &mut PopulationPheromone, args: &Args) -> Vec<Vec<usize>> {
+        let mut matrix = pheromone.matrix.clone();
+        self.colony.build_solutions(&mut matrix, args)
+    }
+}
+
+impl<C: Dynamic> Dynamic for PopulationColony<C> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.colony.apply_change(change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::colony::Colony;
+    use crate::aco::dynamic::{Dynamic, ProblemChange};
+    use crate::aco::graph::Topology;
+    use crate::aco::pheromone::PheromoneUpdate;
+    use crate::aco::population::{ArchivePolicy, PopulationColony, PopulationPU, PopulationPheromone};
+    use crate::aco::tsp::ant::CanonicalAnt;
+    use crate::aco::tsp::ants_behaviour::AntColonySystemAB;
+    use crate::aco::tsp::goodness::CanonicalGoodness;
+    use crate::aco::tsp::local_update::Decay;
+    use crate::aco::tsp::TspColony;
+    use crate::aco::{FMatrix, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn solution(path: Vec<usize>, fitness: f64) -> Solution {
+        Solution { path, fitness }
+    }
+
+    #[test]
+    fn pheromone_is_implied_by_archive() {
+        let mut pheromone = PopulationPheromone::new(3, 1.0, 2.0, Topology::DIRECTED_PATH);
+        pheromone.push(solution(vec![0, 1, 2], 1.0));
+        pheromone.push(solution(vec![0, 1], 1.0));
+
+        let expected = FMatrix::from_row_slice(3, 3, &[1.0, 5.0, 1.0, 1.0, 1.0, 3.0, 1.0, 1.0, 1.0]);
+        assert_eq!(pheromone.matrix(), &expected);
+
+        pheromone.remove(0);
+        let expected = FMatrix::from_row_slice(3, 3, &[1.0, 3.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(pheromone.matrix(), &expected);
+    }
+
+    #[test]
+    fn archive_policies_replace_expected_solutions() {
+        let iterations = [0.5, 0.25, 1.0, 0.125];
+        let archived_fitness = |policy| {
+            let mut pheromone = PopulationPheromone::new(3, 1.0, 1.0, Topology::TOUR);
+            let mut pu = PopulationPU::new(2, policy);
+            for fitness in iterations {
+                let solutions = [
+                    solution(vec![0, 1, 2], fitness / 2.0),
+                    solution(vec![0, 2, 1], fitness),
+                ];
+                pu.apply(&mut pheromone, &solutions, &());
+            }
+            pheromone.archive().iter().map(|s| s.fitness).collect::<Vec<_>>()
+        };
+
+        assert_eq!(archived_fitness(ArchivePolicy::Fifo), vec![1.0, 0.125]);
+        assert_eq!(archived_fitness(ArchivePolicy::Quality), vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn archived_solutions_are_repaired_on_vertex_removal() {
+        let mut pheromone = PopulationPheromone::new(4, 1.0, 1.0, Topology::TOUR);
+        pheromone.push(solution(vec![3, 1, 0, 2], 1.0));
+
+        pheromone.apply_change(&ProblemChange::RemoveVertex(1));
+        assert_eq!(pheromone.archive()[0].path, vec![2, 0, 1]);
+        assert_eq!(pheromone.matrix().nrows(), 3);
+        assert_eq!(pheromone.matrix()[(2, 0)], 2.0);
+
+        pheromone.apply_change(&ProblemChange::AddVertex {
+            outgoing: vec![1.0; 3],
+            incoming: vec![1.0; 3],
+        });
+        assert_eq!(pheromone.matrix().nrows(), 4);
+        assert_eq!(pheromone.matrix()[(3, 0)], 1.0);
+    }
+
+    #[test]
+    fn local_updates_of_colony_do_not_change_implied_pheromone() {
+        let mut pheromone = PopulationPheromone::new(4, 1.0, 1.0, Topology::TOUR);
+        pheromone.push(solution(vec![0, 1, 2, 3], 1.0));
+        let implied = pheromone.matrix().clone();
+        let ants = (0..3)
+            .map(|seed| CanonicalAnt::with_rng(4, StdRng::seed_from_u64(seed)))
+            .collect();
+        let mut colony = PopulationColony::new(TspColony::new(
+            AntColonySystemAB::with_rule(Decay::new(0.5)),
+            CanonicalGoodness::new(1.0, 1.0, FMatrix::repeat(4, 4, 1.0)),
+            ants,
+        ));
+
+        let paths = colony.build_solutions(&mut pheromone, &());
+
+        assert_eq!(paths.len(), 3);
+        assert_eq!(pheromone.matrix(), &implied);
+    }
+}
@@ -1,5 +1,6 @@
+use crate::aco::pheromone::Pheromone;
 use crate::aco::probe::Probe;
-use crate::aco::{AdditionalArgs, FMatrix, Solution};
+use crate::aco::{AdditionalArgs, Solution};
 
 /// Simple Probe implementation for writing algorithm output onto standard output.
 pub struct StdoutProbe {
@@ -13,7 +14,7 @@ impl StdoutProbe {
     }
 }
 
-impl<Args: AdditionalArgs> Probe<FMatrix, Args> for StdoutProbe {
+impl<P: Pheromone, Args: AdditionalArgs> Probe<P, Args> for StdoutProbe {
     fn on_current_best(&mut self, best: &Solution, _: &Args) {
         println!("Iteration best fitness: {}", best.fitness);
     }
@@ -1,10 +1,8 @@
-use crate::aco::FMatrix;
-use itertools::Itertools;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 
 /// Struct with path vector and its fitness
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Solution {
     pub path: Vec<usize>,
     pub fitness: f64,
@@ -20,13 +18,8 @@ impl Default for Solution {
 }
 
 impl Solution {
+    /// Creates a solution with given path & unknown (zero) fitness
     pub fn from_path(path: Vec<usize>) -> Self {
-        let mut matrix = FMatrix::zeros(path.len(), path.len());
-        for (i, j) in path.iter().circular_tuple_windows::<(&usize, &usize)>() {
-            matrix[(*i, *j)] = 1.0;
-            matrix[(*j, *i)] = 1.0;
-        }
-
         Self {
             path,
             ..Self::default()
@@ -0,0 +1,206 @@
+//! Stagnation detection & pheromone reinitialisation.
+//!
+//! When pheromone concentrates on few edges, ants keep building the same solutions and the search
+//! stagnates. This module provides measures of pheromone convergence computed from the pheromone matrix:
+//! * [lambda_branching_factor] - average number of edges leaving a vertex with relatively high pheromone,
+//! * [average_entropy] - average normalised entropy of pheromone on edges leaving a vertex,
+//!
+//! and [ReinitializingPU], which resets pheromone trails when stagnation is detected,
+//! as done in MAX-MIN Ant System. To terminate the algorithm on stagnation use
+//! [StagnationCond](crate::aco::termination_condition::StagnationCond).
+use crate::aco::dynamic::{Dynamic, ProblemChange};
+use crate::aco::pheromone::PheromoneUpdate;
+use crate::aco::{AdditionalArgs, FMatrix, Solution};
+
+/// Computes average λ-branching factor of the pheromone.
+///
+/// λ-branching factor of a vertex is the number of edges leaving it with pheromone not smaller than
+/// `min + lambda * (max - min)`, where `min` & `max` are the extreme pheromone values on those edges.
+/// Values close to 2 (for symmetric TSP) mean that the algorithm has converged.
+///
+/// ## Arguments
+/// * `pheromone` - Pheromone in matrix representation, the diagonal is ignored.
+/// * `lambda` - Number between 0.0 and 1.0, usually 0.05.
+pub fn lambda_branching_factor(pheromone: &FMatrix, lambda: f64) -> f64 {
+    assert!(
+        (0.0..=1.0).contains(&lambda),
+        "Lambda must be in range [0.0, 1.0]"
+    );
+    let n = pheromone.nrows();
+    if n == 0 {
+        return 0.0;
+    }
+
+    let mut branches = 0usize;
+    for i in 0..n {
+        let row = (0..n).filter(|&j| j != i).map(|j| pheromone[(i, j)]);
+        let min = row.clone().fold(f64::INFINITY, f64::min);
+        let max = row.clone().fold(f64::NEG_INFINITY, f64::max);
+        let threshold = min + lambda * (max - min);
+        branches += row.filter(|&tau| tau >= threshold).count();
+    }
+    branches as f64 / n as f64
+}
+
+/// Computes average entropy of the pheromone, normalised to range [0.0, 1.0].
+///
+/// Entropy of a vertex is computed for probability distribution proportional to pheromone on edges leaving it.
+/// Value of 1.0 means uniform pheromone, values close to 0.0 mean that the algorithm has converged.
+///
+/// ## Arguments
+/// * `pheromone` - Pheromone in matrix representation, the diagonal is ignored.
+pub fn average_entropy(pheromone: &FMatrix) -> f64 {
+    let n = pheromone.nrows();
+    if n <= 2 {
+        return 0.0;
+    }
+
+    let max_entropy = ((n - 1) as f64).ln();
+    let mut entropy_sum = 0.0;
+    for i in 0..n {
+        let row = (0..n).filter(|&j| j != i).map(|j| pheromone[(i, j)]);
+        let total: f64 = row.clone().sum();
+        if total <= 0.0 {
+            continue;
+        }
+        entropy_sum -= row
+            .filter(|&tau| tau > 0.0)
+            .map(|tau| tau / total)
+            .map(|p| p * p.ln())
+            .sum::<f64>();
+    }
+    entropy_sum / (n as f64 * max_entropy)
+}
+
+/// # Stagnation Measure
+///
+/// Decides whether the search has stagnated, based on the pheromone.
+#[derive(Clone, Copy, Debug)]
+pub enum StagnationMeasure {
+    /// Stagnated when [lambda_branching_factor] is not greater than `threshold`
+    BranchingFactor { lambda: f64, threshold: f64 },
+    /// Stagnated when [average_entropy] is not greater than `threshold`
+    Entropy { threshold: f64 },
+}
+
+impl StagnationMeasure {
+    /// Returns true iff the pheromone is stagnated.
+    ///
+    /// ## Arguments
+    /// * `pheromone` - Pheromone in matrix representation.
+    pub fn is_stagnated(&self, pheromone: &FMatrix) -> bool {
+        match *self {
+            StagnationMeasure::BranchingFactor { lambda, threshold } => {
+                lambda_branching_factor(pheromone, lambda) <= threshold
+            }
+            StagnationMeasure::Entropy { threshold } => average_entropy(pheromone) <= threshold,
+        }
+    }
+}
+
+/// # Reinitializing Pheromone Update
+///
+/// Implements [PheromoneUpdate]. Applies the wrapped update, then resets every trail to given value
+/// if the pheromone is stagnated according to [StagnationMeasure].
+pub struct ReinitializingPU<P> {
+    pheromone_update: P,
+    measure: StagnationMeasure,
+    value: f64,
+    reinitializations: usize,
+}
+
+impl<P> ReinitializingPU<P> {
+    /// Creates a new instance of [ReinitializingPU]
+    ///
+    /// ## Arguments
+    /// * `pheromone_update` - wrapped pheromone update.
+    /// * `measure` - measure used to detect stagnation.
+    /// * `value` - pheromone value set on every edge on reinitialisation, e.g. the upper bound in MMAS.
+    pub fn new(pheromone_update: P, measure: StagnationMeasure, value: f64) -> Self {
+        Self {
+            pheromone_update,
+            measure,
+            value,
+            reinitializations: 0,
+        }
+    }
+
+    /// Returns how many times the pheromone was reinitialised
+    pub fn reinitializations(&self) -> usize {
+        self.reinitializations
+    }
+}
+
+impl<P: PheromoneUpdate<FMatrix, Args>, Args: AdditionalArgs> PheromoneUpdate<FMatrix, Args>
+    for ReinitializingPU<P>
+{
+    fn apply(&mut self, pheromone: &mut FMatrix, solutions: &[Solution], args: &Args) {
+        self.pheromone_update.apply(pheromone, solutions, args);
+
+        if self.measure.is_stagnated(pheromone) {
+            pheromone.fill(self.value);
+            self.reinitializations += 1;
+        }
+    }
+}
+
+impl<P: Dynamic> Dynamic for ReinitializingPU<P> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.pheromone_update.apply_change(change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::pheromone::PheromoneUpdate;
+    use crate::aco::stagnation::{
+        average_entropy, lambda_branching_factor, ReinitializingPU, StagnationMeasure,
+    };
+    use crate::aco::{FMatrix, Solution};
+
+    /// Pheromone concentrated on tour 0 -> 1 -> 2 -> 3 -> 0
+    fn converged() -> FMatrix {
+        FMatrix::from_fn(4, 4, |i, j| {
+            if (i + 1) % 4 == j || (j + 1) % 4 == i {
+                5.0
+            } else {
+                0.1
+            }
+        })
+    }
+
+    #[test]
+    fn measures_distinguish_uniform_and_converged_pheromone() {
+        let uniform = FMatrix::repeat(4, 4, 1.0);
+
+        assert_eq!(lambda_branching_factor(&uniform, 0.05), 3.0);
+        assert_eq!(lambda_branching_factor(&converged(), 0.05), 2.0);
+        assert!((average_entropy(&uniform) - 1.0).abs() < 1e-12);
+        assert!(average_entropy(&converged()) < 0.75);
+    }
+
+    struct NoUpdate;
+
+    impl PheromoneUpdate<FMatrix> for NoUpdate {
+        fn apply(&mut self, _: &mut FMatrix, _: &[Solution], _: &()) {}
+    }
+
+    #[test]
+    fn reinitializing_pu_resets_stagnated_pheromone() {
+        let measure = StagnationMeasure::BranchingFactor {
+            lambda: 0.05,
+            threshold: 2.0,
+        };
+        let mut pu = ReinitializingPU::new(NoUpdate, measure, 3.0);
+
+        let mut uniform = FMatrix::repeat(4, 4, 1.0);
+        pu.apply(&mut uniform, &[], &());
+        assert_eq!(uniform, FMatrix::repeat(4, 4, 1.0));
+        assert_eq!(pu.reinitializations(), 0);
+
+        let mut pheromone = converged();
+        pu.apply(&mut pheromone, &[], &());
+        assert_eq!(pheromone, FMatrix::repeat(4, 4, 3.0));
+        assert_eq!(pu.reinitializations(), 1);
+    }
+}
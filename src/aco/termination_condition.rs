@@ -1,6 +1,7 @@
 //! Contains stuff related to algorithm termination
 use crate::aco::pheromone::Pheromone;
-use crate::aco::AdditionalArgs;
+use crate::aco::stagnation::StagnationMeasure;
+use crate::aco::{AdditionalArgs, FMatrix, Solution};
 use std::time::{Duration, Instant};
 
 /// # Termination Condition
@@ -19,6 +20,12 @@ pub trait TerminationCondition<P: Pheromone, Args: AdditionalArgs = ()> {
     /// * `pheromone` - current pheromone.
     /// * `ants` - Ants containing solutions.
     fn update_and_check(&mut self, pheromone: &P, args: &Args) -> bool;
+
+    /// Notifies the condition about the best solution of the current iteration.
+    ///
+    /// ## Arguments
+    /// * `best` - best solution of the current iteration.
+    fn on_current_best(&mut self, _best: &Solution, _args: &Args) {}
 }
 
 /// # Iteration Condition
@@ -90,3 +97,118 @@ impl<P: Pheromone, Args: AdditionalArgs> TerminationCondition<P, Args> for TimeC
         curr_duration > self.duration
     }
 }
+
+/// # Stagnation Condition
+///
+/// Implements [TerminationCondition].
+///
+/// Met when the pheromone is stagnated according to given [StagnationMeasure].
+pub struct StagnationCond {
+    measure: StagnationMeasure,
+}
+
+impl StagnationCond {
+    /// Creates a new instance of [StagnationCond]. Implements [TerminationCondition]
+    ///
+    /// ## Arguments
+    /// * `measure` - measure used to detect stagnation.
+    pub fn new(measure: StagnationMeasure) -> Self {
+        Self { measure }
+    }
+}
+
+impl<Args: AdditionalArgs> TerminationCondition<FMatrix, Args> for StagnationCond {
+    fn init(&mut self, _pheromone: &FMatrix, _: &Args) {}
+
+    fn update_and_check(&mut self, pheromone: &FMatrix, _: &Args) -> bool {
+        self.measure.is_stagnated(pheromone)
+    }
+}
+
+/// # Target Fitness Condition
+///
+/// Implements [TerminationCondition].
+///
+/// Met when a solution with fitness not smaller than the target has been found.
+pub struct TargetFitnessCond {
+    target: f64,
+    best_fitness: f64,
+}
+
+impl TargetFitnessCond {
+    /// Creates a new instance of [TargetFitnessCond]. Implements [TerminationCondition]
+    ///
+    /// ## Arguments
+    /// * `target` - fitness to reach, e.g. inverse of the optimal tour length.
+    pub fn new(target: f64) -> Self {
+        Self {
+            target,
+            best_fitness: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl<P: Pheromone, Args: AdditionalArgs> TerminationCondition<P, Args> for TargetFitnessCond {
+    fn init(&mut self, _pheromone: &P, _: &Args) {
+        self.best_fitness = f64::NEG_INFINITY;
+    }
+
+    fn update_and_check(&mut self, _pheromone: &P, _: &Args) -> bool {
+        self.best_fitness >= self.target
+    }
+
+    fn on_current_best(&mut self, best: &Solution, _: &Args) {
+        self.best_fitness = self.best_fitness.max(best.fitness);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::stagnation::StagnationMeasure;
+    use crate::aco::termination_condition::{StagnationCond, TargetFitnessCond, TerminationCondition};
+    use crate::aco::{FMatrix, Solution};
+
+    #[test]
+    fn stagnation_cond_is_met_on_converged_pheromone() {
+        let mut cond = StagnationCond::new(StagnationMeasure::Entropy { threshold: 0.5 });
+        let uniform = FMatrix::repeat(5, 5, 1.0);
+        let converged = FMatrix::from_fn(5, 5, |i, j| if (i + 1) % 5 == j { 1.0 } else { 1e-6 });
+
+        TerminationCondition::<FMatrix>::init(&mut cond, &uniform, &());
+        assert!(!TerminationCondition::<FMatrix>::update_and_check(
+            &mut cond,
+            &uniform,
+            &()
+        ));
+        assert!(TerminationCondition::<FMatrix>::update_and_check(
+            &mut cond,
+            &converged,
+            &()
+        ));
+    }
+
+    #[test]
+    fn target_fitness_cond_is_met_after_reaching_target() {
+        let mut cond = TargetFitnessCond::new(0.5);
+        let pheromone = FMatrix::repeat(2, 2, 1.0);
+        let solution = |fitness| Solution {
+            path: vec![0, 1],
+            fitness,
+        };
+
+        TerminationCondition::<FMatrix>::init(&mut cond, &pheromone, &());
+        TerminationCondition::<FMatrix>::on_current_best(&mut cond, &solution(0.25), &());
+        assert!(!TerminationCondition::<FMatrix>::update_and_check(
+            &mut cond,
+            &pheromone,
+            &()
+        ));
+        TerminationCondition::<FMatrix>::on_current_best(&mut cond, &solution(0.5), &());
+        TerminationCondition::<FMatrix>::on_current_best(&mut cond, &solution(0.1), &());
+        assert!(TerminationCondition::<FMatrix>::update_and_check(
+            &mut cond,
+            &pheromone,
+            &()
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 //! implementations of aco traits helpful in creating a TSP solver.
 
 use crate::aco::colony::Colony;
+use crate::aco::dynamic::{Dynamic, ProblemChange};
 use crate::aco::grader::Grader;
 use crate::aco::pheromone::Pheromone;
 use crate::aco::tsp::ant::Ant;
@@ -12,9 +13,13 @@ use std::marker::PhantomData;
 
 pub mod ant;
 pub mod ants_behaviour;
-pub mod goodness;
+pub mod candidate_lists;
+pub mod daemon;
+/// Goodness is not TSP specific, it is re-exported for backward compatibility
+pub use crate::aco::goodness;
 pub mod local_update;
 pub mod pheromone;
+pub mod tsplib;
 pub mod util;
 
 /// # TSP Colony
@@ -38,6 +43,20 @@ impl<P: Pheromone, AB: AntsBehaviour<A, G, P>, A: Ant, G: Goodness<P>> TspColony
     }
 }
 
+/// Resizes the ants & updates the goodness, see [dynamic](crate::aco::dynamic) module.
+impl<P, AB, A, G> Dynamic for TspColony<P, AB, A, G>
+where
+    P: Pheromone,
+    AB: AntsBehaviour<A, G, P>,
+    A: Ant + Dynamic,
+    G: Goodness<P> + Dynamic,
+{
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.goodness.apply_change(change);
+        self.ants.iter_mut().for_each(|ant| ant.apply_change(change));
+    }
+}
+
 impl<P, AB, A, G, Args> Colony<P, Args> for TspColony<P, AB, A, G>
 where
     P: Pheromone,
@@ -83,10 +102,28 @@ impl<Args: AdditionalArgs> Grader<Args> for PathLengthInverse {
     }
 }
 
+impl Dynamic for PathLengthInverse {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        change.apply_to_matrix(&mut self.weights, |w| w, 0.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::aco::tsp::PathLengthInverse;
-    use crate::aco::{FMatrix, Solution};
+    use crate::aco::dynamic::ProblemChange;
+    use crate::aco::tsp::ant::CanonicalAnt;
+    use crate::aco::tsp::ants_behaviour::AntSystemAB;
+    use crate::aco::tsp::candidate_lists::CandidateLists;
+    use crate::aco::tsp::daemon::TwoOpt;
+    use crate::aco::tsp::goodness::CanonicalGoodness;
+    use crate::aco::tsp::pheromone::MMAntSystemPU;
+    use crate::aco::tsp::util::create_heuristic_from_weights;
+    use crate::aco::tsp::{PathLengthInverse, TspColony};
+    use crate::aco::{Builder, FMatrix, Solution};
+    use itertools::Itertools;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::sync::Arc;
 
     #[test]
     fn inverse_path_length_grader_returns_correct_fitness() {
@@ -103,4 +140,50 @@ mod tests {
         grader.grade_solution(&mut sol);
         assert_eq!(sol.fitness, 1.0 / 6.0);
     }
+
+    #[test]
+    fn tsp_colony_adapts_to_problem_changes() {
+        let weights = FMatrix::from_fn(6, 6, |i, j| 1.0 + (i as f64 - j as f64).abs());
+        let candidates = Arc::new(CandidateLists::new(&weights, 2));
+        let ants = (0..4)
+            .map(|seed| {
+                CanonicalAnt::with_rng(6, StdRng::seed_from_u64(seed))
+                    .with_candidate_lists(candidates.clone())
+            })
+            .collect_vec();
+        let colony = TspColony::new(
+            AntSystemAB,
+            CanonicalGoodness::new(1.0, 2.0, create_heuristic_from_weights(&weights)),
+            ants,
+        );
+        let mut aco = Builder::new()
+            .set_colony(colony)
+            .set_pheromone_update(MMAntSystemPU::new(0.2, 0.1, 2.0))
+            .set_grader(PathLengthInverse::new(weights.clone()))
+            .set_daemon_action(TwoOpt::new(weights))
+            .set_start_pheromone(FMatrix::repeat(6, 6, 2.0))
+            .with_stdout_probe()
+            .with_iteration_termination(100)
+            .build();
+        let assert_tour = |best: Option<&Solution>, cities: usize| {
+            let best = best.unwrap();
+            assert!(best.path.iter().cloned().sorted().eq(0..cities));
+            assert!(best.fitness > 0.0);
+        };
+
+        aco.iterate_once();
+        assert_tour(aco.best_solution(), 6);
+
+        aco.change_problem(&ProblemChange::AddVertex {
+            outgoing: vec![2.0; 6],
+            incoming: vec![2.0; 6],
+        });
+        assert!(aco.best_solution().is_none());
+        aco.iterate_once();
+        assert_tour(aco.best_solution(), 7);
+
+        aco.change_problem(&ProblemChange::RemoveVertex(0));
+        aco.iterate_once();
+        assert_tour(aco.best_solution(), 6);
+    }
 }
@@ -1,8 +1,11 @@
+use crate::aco::dynamic::{Dynamic, ProblemChange};
+use crate::aco::tsp::candidate_lists::CandidateLists;
 use crate::aco::FMatrix;
 use push_trait::Push;
 use rand::prelude::ThreadRng;
 use rand::{thread_rng, Rng};
 use std::collections::HashSet;
+use std::sync::Arc;
 
 pub trait Ant {
     /// Clears iteration specific data like visited vertices or path.
@@ -44,6 +47,83 @@ macro_rules! standard_ant_impl {
     };
 }
 
+macro_rules! candidate_lists_impl {
+    () => {
+        /// Makes the ant consider candidates (nearest neighbours) of its current vertex first.
+        /// All unvisited vertices are scanned only when every candidate was already visited,
+        /// which brings construction cost close to O(n·k) for lists of length k.
+        ///
+        /// ## Arguments
+        /// * `candidates` - Candidate lists, shared between ants.
+        pub fn with_candidate_lists(mut self, candidates: Arc<CandidateLists>) -> Self {
+            assert_eq!(
+                candidates.len(),
+                self.solution_size,
+                "Candidate lists must be given for every vertex"
+            );
+            self.candidates = Some(candidates);
+            self
+        }
+    };
+}
+
+macro_rules! dynamic_ant_impl {
+    () => {
+        /// Resizes the ant & remaps its candidate lists (see [CandidateLists]),
+        /// iteration specific data is dropped.
+        fn apply_change(&mut self, change: &ProblemChange) {
+            match change {
+                ProblemChange::AddVertex { .. } => self.solution_size += 1,
+                ProblemChange::RemoveVertex(_) => self.solution_size -= 1,
+                ProblemChange::SetWeight { .. } => {}
+            }
+            if let Some(candidates) = self.candidates.as_mut() {
+                Arc::make_mut(candidates).apply_change(change);
+            }
+            self.unvisited.clear();
+            self.path.clear();
+            self.stuck = false;
+        }
+    };
+}
+
+/// Returns unvisited candidates of `vertex`, if candidate lists are used
+fn unvisited_candidates<'a>(
+    candidates: &'a Option<Arc<CandidateLists>>,
+    unvisited: &'a HashSet<usize>,
+    vertex: usize,
+) -> Option<impl Iterator<Item = usize> + Clone + 'a> {
+    let candidates = candidates.as_ref()?;
+    Some(
+        candidates
+            .get(vertex)
+            .iter()
+            .cloned()
+            .filter(|v| unvisited.contains(v)),
+    )
+}
+
+/// Chooses one of `vertices` with probability proportional to goodness of the edge leading to it.
+/// Returns [None] when there is no vertex with positive goodness.
+fn choose_proportionally<R: Rng>(
+    rng: &mut R,
+    goodness: impl Fn(usize) -> f64,
+    vertices: impl Iterator<Item = usize> + Clone,
+) -> Option<usize> {
+    let goodness_sum: f64 = vertices.clone().map(&goodness).sum();
+    if goodness_sum <= 0.0 {
+        return None;
+    }
+    let mut random: f64 = rng.gen_range(0.0..=goodness_sum);
+    for v in vertices {
+        random -= goodness(v);
+        if random <= 0.0 {
+            return Some(v);
+        }
+    }
+    None
+}
+
 /// # Canonical Ant
 ///
 /// Represent a single ant.
@@ -54,6 +134,7 @@ pub struct CanonicalAnt<R: Rng> {
     path: Vec<usize>,
     solution_size: usize,
     stuck: bool,
+    candidates: Option<Arc<CandidateLists>>,
     rng: R,
 }
 
@@ -69,9 +150,12 @@ impl<R: Rng> CanonicalAnt<R> {
             path: Vec::with_capacity(solution_size),
             stuck: false,
             solution_size,
+            candidates: None,
             rng,
         }
     }
+
+    candidate_lists_impl!();
 }
 
 impl<R: Rng> Ant for CanonicalAnt<R> {
@@ -94,18 +178,24 @@ impl<R: Rng> Ant for CanonicalAnt<R> {
             panic!("Ant had already visited every place");
         }
 
-        let mut goodness_sum = 0.0f64;
-        for v in self.unvisited.iter() {
-            goodness_sum += row[*v];
-        }
-
-        let mut random: f64 = self.rng.gen_range(0.0..=goodness_sum);
         let mut next: usize = last;
-        for v in self.unvisited.iter() {
-            random -= row[*v];
-            if random <= 0.0 {
-                next = *v;
-                break;
+        if let Some(candidate) = unvisited_candidates(&self.candidates, &self.unvisited, last)
+            .and_then(|candidates| choose_proportionally(&mut self.rng, |v| row[v], candidates))
+        {
+            next = candidate;
+        } else {
+            let mut goodness_sum = 0.0f64;
+            for v in self.unvisited.iter() {
+                goodness_sum += row[*v];
+            }
+
+            let mut random: f64 = self.rng.gen_range(0.0..=goodness_sum);
+            for v in self.unvisited.iter() {
+                random -= row[*v];
+                if random <= 0.0 {
+                    next = *v;
+                    break;
+                }
             }
         }
 
@@ -120,6 +210,10 @@ impl<R: Rng> Ant for CanonicalAnt<R> {
     }
 }
 
+impl<R: Rng> Dynamic for CanonicalAnt<R> {
+    dynamic_ant_impl!();
+}
+
 impl CanonicalAnt<ThreadRng> {
     /// Create a new instance of [CanonicalAnt] with default RNG.
     ///
@@ -144,6 +238,7 @@ pub struct ExploitingAnt<R: Rng> {
     solution_size: usize,
     stuck: bool,
     exploitation_rate: f64,
+    candidates: Option<Arc<CandidateLists>>,
     rng: R,
 }
 
@@ -165,9 +260,12 @@ impl<R: Rng> ExploitingAnt<R> {
             stuck: false,
             solution_size,
             exploitation_rate,
+            candidates: None,
             rng,
         }
     }
+
+    candidate_lists_impl!();
 }
 
 impl<R: Rng> Ant for ExploitingAnt<R> {
@@ -190,7 +288,16 @@ impl<R: Rng> Ant for ExploitingAnt<R> {
         let should_exploit = self.rng.gen::<f64>() < self.exploitation_rate;
 
         let mut next = last;
-        if should_exploit {
+        let candidates = unvisited_candidates(&self.candidates, &self.unvisited, last);
+        let from_candidates = if should_exploit {
+            candidates.and_then(|candidates| candidates.max_by(|a, b| row[*a].total_cmp(&row[*b])))
+        } else {
+            candidates.and_then(|candidates| choose_proportionally(&mut self.rng, |v| row[v], candidates))
+        };
+
+        if let Some(candidate) = from_candidates {
+            next = candidate;
+        } else if should_exploit {
             let mut value = f64::MIN;
 
             for v in self.unvisited.iter() {
@@ -226,6 +333,10 @@ impl<R: Rng> Ant for ExploitingAnt<R> {
     }
 }
 
+impl<R: Rng> Dynamic for ExploitingAnt<R> {
+    dynamic_ant_impl!();
+}
+
 impl ExploitingAnt<ThreadRng> {
     /// Create a new instance of [CanonicalAnt] with default RNG.
     ///
@@ -236,3 +347,54 @@ impl ExploitingAnt<ThreadRng> {
         Self::with_rng(solution_size, exploitation_rate, thread_rng())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::tsp::ant::{Ant, CanonicalAnt, ExploitingAnt};
+    use crate::aco::tsp::candidate_lists::CandidateLists;
+    use crate::aco::FMatrix;
+    use itertools::Itertools;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::sync::Arc;
+
+    fn build_path(ant: &mut impl Ant, size: usize, goodness: &FMatrix) -> Vec<usize> {
+        ant.clear();
+        ant.chose_staring_place();
+        for _ in 1..size {
+            ant.go_to_next_place(goodness);
+        }
+        ant.path().to_vec()
+    }
+
+    fn assert_prefers_candidates(path: &[usize], candidates: &CandidateLists) {
+        assert!(path.iter().cloned().sorted().eq(0..path.len()));
+        for (i, (from, to)) in path.iter().tuple_windows().enumerate() {
+            let has_unvisited_candidate = candidates.get(*from).iter().any(|c| !path[..=i].contains(c));
+            if has_unvisited_candidate {
+                assert!(candidates.get(*from).contains(to));
+            }
+        }
+    }
+
+    #[test]
+    fn ants_choose_candidates_first() {
+        let size = 20;
+        let weights = FMatrix::from_fn(size, size, |i, j| (i as f64 - j as f64).abs());
+        let goodness = FMatrix::from_element(size, size, 1.0);
+        let candidates = Arc::new(CandidateLists::new(&weights, 2));
+
+        let mut canonical =
+            CanonicalAnt::with_rng(size, StdRng::seed_from_u64(1)).with_candidate_lists(candidates.clone());
+        let mut exploring = ExploitingAnt::with_rng(size, 0.0, StdRng::seed_from_u64(2))
+            .with_candidate_lists(candidates.clone());
+        let mut exploiting = ExploitingAnt::with_rng(size, 0.9, StdRng::seed_from_u64(3))
+            .with_candidate_lists(candidates.clone());
+
+        for _ in 0..10 {
+            assert_prefers_candidates(&build_path(&mut canonical, size, &goodness), &candidates);
+            assert_prefers_candidates(&build_path(&mut exploring, size, &goodness), &candidates);
+            assert_prefers_candidates(&build_path(&mut exploiting, size, &goodness), &candidates);
+        }
+    }
+}
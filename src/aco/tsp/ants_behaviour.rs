@@ -7,6 +7,7 @@ use crate::aco::tsp::ant::Ant;
 use crate::aco::tsp::goodness::Goodness;
 use crate::aco::tsp::local_update::LocalUpdate;
 use crate::aco::FMatrix;
+use rayon::prelude::*;
 
 /// # Ants Behaviour
 ///
@@ -104,3 +105,146 @@ impl<L: LocalUpdate> AntColonySystemAB<L> {
         Self { local_update: rule }
     }
 }
+
+/// # Parallel Ant System ants behaviour
+///
+/// Implements [AntsBehaviour]. Parallel version of [AntSystemAB], ants build their paths concurrently.
+///
+/// Every ant uses its own RNG, therefore ants have to be [Send], e.g. [crate::aco::tsp::ant::CanonicalAnt]
+/// with [rand::rngs::StdRng] seeded separately for every ant. Paths of stuck ants are discarded.
+pub struct ParallelAntSystemAB;
+
+impl<A: Ant + Send, G: Goodness<FMatrix>> AntsBehaviour<A, G, FMatrix> for ParallelAntSystemAB {
+    fn simulate_ants(
+        &mut self,
+        ants: &mut [A],
+        pheromone: &mut FMatrix,
+        goodness_op: &mut G,
+    ) -> Vec<Vec<usize>> {
+        let goodness = goodness_op.apply(pheromone);
+        let solution_size = pheromone.nrows();
+
+        ants.par_iter_mut()
+            .filter_map(|ant| {
+                ant.clear();
+                ant.chose_staring_place();
+                for _ in 1..solution_size {
+                    ant.go_to_next_place(&goodness);
+                }
+
+                (!ant.is_stuck()).then(|| ant.path().to_vec())
+            })
+            .collect()
+    }
+}
+
+/// # Parallel Ant Colony System ants behaviour
+///
+/// Implements [AntsBehaviour]. Parallel version of [AntColonySystemAB].
+///
+/// Ants move in lockstep: in every step all of them choose their next vertex concurrently, using goodness
+/// computed from pheromone at the beginning of the step. Local pheromone update is then applied once,
+/// for edges traversed by all the ants in the step. This is the same batching [AntColonySystemAB] uses,
+/// so pheromone seen by an ant does not depend on the order in which ants are processed.
+pub struct ParallelAntColonySystemAB<L: LocalUpdate> {
+    local_update: L,
+}
+
+impl<A: Ant + Send, G: Goodness<FMatrix>, L: LocalUpdate> AntsBehaviour<A, G, FMatrix>
+    for ParallelAntColonySystemAB<L>
+{
+    fn simulate_ants(
+        &mut self,
+        ants: &mut [A],
+        pheromone: &mut FMatrix,
+        goodness_op: &mut G,
+    ) -> Vec<Vec<usize>> {
+        let solution_size = pheromone.nrows();
+
+        ants.par_iter_mut().for_each(|a| {
+            a.clear();
+            a.chose_staring_place()
+        });
+
+        let mut paths: Vec<Vec<usize>> = Vec::with_capacity(ants.len());
+
+        for _ in 1..solution_size {
+            let goodness = goodness_op.apply(pheromone);
+            paths = ants
+                .par_iter_mut()
+                .filter(|ant| !ant.is_stuck())
+                .map(|ant| {
+                    ant.go_to_next_place(&goodness);
+                    ant.path().to_vec()
+                })
+                .collect();
+            self.local_update.apply(pheromone, &paths)
+        }
+        paths
+    }
+}
+
+impl<L: LocalUpdate> ParallelAntColonySystemAB<L> {
+    /// Creates a new [ParallelAntColonySystemAB] instance with specified update rule
+    pub fn with_rule(rule: L) -> Self {
+        Self { local_update: rule }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::tsp::ant::CanonicalAnt;
+    use crate::aco::tsp::ants_behaviour::{AntsBehaviour, ParallelAntColonySystemAB, ParallelAntSystemAB};
+    use crate::aco::tsp::goodness::CanonicalGoodness;
+    use crate::aco::tsp::local_update::Decay;
+    use crate::aco::tsp::util::create_heuristic_from_weights;
+    use crate::aco::FMatrix;
+    use itertools::Itertools;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn seeded_ants(size: usize, count: u64) -> Vec<CanonicalAnt<StdRng>> {
+        (0..count)
+            .map(|seed| CanonicalAnt::with_rng(size, StdRng::seed_from_u64(seed)))
+            .collect()
+    }
+
+    fn simulate<AB: AntsBehaviour<CanonicalAnt<StdRng>, CanonicalGoodness, FMatrix>>(
+        mut behaviour: AB,
+    ) -> (Vec<Vec<usize>>, FMatrix) {
+        let size = 15;
+        let weights = FMatrix::from_fn(size, size, |i, j| 1.0 + (i as f64 - j as f64).abs());
+        let mut goodness = CanonicalGoodness::new(1.0, 2.0, create_heuristic_from_weights(&weights));
+        let mut pheromone = FMatrix::from_element(size, size, 1.0);
+        let mut ants = seeded_ants(size, 8);
+
+        let paths = behaviour.simulate_ants(&mut ants, &mut pheromone, &mut goodness);
+        (paths, pheromone)
+    }
+
+    fn assert_valid(paths: &[Vec<usize>]) {
+        assert_eq!(paths.len(), 8);
+        for path in paths {
+            assert!(path.iter().cloned().sorted().eq(0..path.len()));
+        }
+    }
+
+    #[test]
+    fn parallel_ant_system_builds_tours() {
+        let (paths, pheromone) = simulate(ParallelAntSystemAB);
+
+        assert_valid(&paths);
+        assert_eq!(pheromone, FMatrix::from_element(15, 15, 1.0));
+    }
+
+    #[test]
+    fn parallel_ant_colony_system_applies_local_update_per_step() {
+        let (paths, pheromone) = simulate(ParallelAntColonySystemAB::with_rule(Decay::new(0.5)));
+
+        assert_valid(&paths);
+        let decayed = pheromone.iter().filter(|p| **p < 1.0).count();
+        let min_pheromone = pheromone.min();
+        assert!(decayed >= 14);
+        assert!(min_pheromone >= 0.5f64.powi(8 * 14));
+    }
+}
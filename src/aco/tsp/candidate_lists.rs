@@ -0,0 +1,119 @@
+//! Candidate (nearest neighbour) lists.
+//!
+//! In good TSP tours cities are usually connected with their close neighbours, therefore
+//! restricting considered edges to `k` nearest neighbours of every city speeds up both
+//! tour construction & local search significantly, at the cost of little quality.
+use crate::aco::dynamic::{Dynamic, ProblemChange};
+use crate::aco::FMatrix;
+use itertools::Itertools;
+
+/// # Candidate Lists
+///
+/// Stores `k` nearest neighbours of every vertex, sorted by increasing edge weight.
+/// Missing edges (with infinite weight) are never candidates.
+#[derive(Clone, Debug)]
+pub struct CandidateLists {
+    lists: Vec<Vec<usize>>,
+}
+
+impl CandidateLists {
+    /// Creates candidate lists of `k` nearest neighbours for every vertex
+    ///
+    /// ## Arguments
+    /// * `weights` - Weighted graph in matrix representation.
+    /// * `k` - length of every list, must be > 0.
+    pub fn new(weights: &FMatrix, k: usize) -> Self {
+        assert!(k > 0, "Candidate lists must not be empty");
+        let lists = (0..weights.nrows())
+            .map(|i| {
+                (0..weights.ncols())
+                    .filter(|&j| j != i && weights[(i, j)].is_finite())
+                    .sorted_by(|&a, &b| weights[(i, a)].total_cmp(&weights[(i, b)]))
+                    .take(k)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        Self { lists }
+    }
+
+    /// Returns candidates of `vertex`, the nearest first
+    ///
+    /// ## Arguments
+    /// * `vertex` - vertex to get candidates of
+    pub fn get(&self, vertex: usize) -> &[usize] {
+        &self.lists[vertex]
+    }
+
+    /// Returns number of vertices
+    pub fn len(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns true iff there are no vertices
+    pub fn is_empty(&self) -> bool {
+        self.lists.is_empty()
+    }
+}
+
+/// Lists are remapped, not recomputed: removed vertex is dropped from every list & new vertex gets an empty
+/// list, so ants consider all unvisited vertices when leaving it. Create new lists from changed weights
+/// to restore their quality.
+impl Dynamic for CandidateLists {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        match change {
+            ProblemChange::AddVertex { .. } => self.lists.push(Vec::new()),
+            ProblemChange::RemoveVertex(vertex) => {
+                self.lists.remove(*vertex);
+                self.lists.iter_mut().for_each(|list| change.apply_to_path(list));
+            }
+            ProblemChange::SetWeight { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::dynamic::{Dynamic, ProblemChange};
+    use crate::aco::tsp::candidate_lists::CandidateLists;
+    use crate::aco::FMatrix;
+
+    #[test]
+    fn candidates_are_sorted_by_weight() {
+        let inf = f64::INFINITY;
+        let weights = FMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                0.0, 3.0, 1.0, 2.0, //
+                3.0, 0.0, 5.0, inf, //
+                1.0, 5.0, 0.0, 4.0, //
+                2.0, inf, 4.0, 0.0,
+            ],
+        );
+        let lists = CandidateLists::new(&weights, 2);
+
+        assert_eq!(lists.len(), 4);
+        assert_eq!(lists.get(0), &[2, 3]);
+        assert_eq!(lists.get(1), &[0, 2]);
+        assert_eq!(lists.get(3), &[0, 2]);
+    }
+
+    #[test]
+    fn candidates_are_remapped_on_problem_change() {
+        let weights = FMatrix::from_fn(4, 4, |i, j| (i as f64 - j as f64).abs());
+        let mut lists = CandidateLists::new(&weights, 2);
+
+        lists.apply_change(&ProblemChange::RemoveVertex(1));
+        assert_eq!(lists.len(), 3);
+        assert_eq!(lists.get(0), &[1]);
+        assert_eq!(lists.get(2), &[1]);
+
+        lists.apply_change(&ProblemChange::AddVertex {
+            outgoing: vec![1.0; 3],
+            incoming: vec![1.0; 3],
+        });
+        assert_eq!(lists.len(), 4);
+        assert!(lists.get(3).is_empty());
+    }
+}
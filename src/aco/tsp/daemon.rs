@@ -0,0 +1,499 @@
+//! Local search daemon actions for TSP.
+//!
+//! Ant Colony System & MAX-MIN Ant System are competitive with state-of-the-art only when tours
+//! built by ants are improved with local search. This module provides following [DaemonAction]
+//! implementations, each improving every tour until local optimum is reached:
+//!
+//! * [TwoOpt] - replaces two edges with two other edges (reverses part of the tour),
+//! * [TwoHalfOpt] - 2-opt extended with moving single city to other place in the tour,
+//! * [OrOpt] - moves segments of up to three consecutive cities (possibly reversed) to other place in the tour.
+//!
+//! Every local search considers only moves introducing an edge between a city & one of its
+//! [candidates](CandidateLists) (its nearest neighbours). By default candidate lists contain all the cities,
+//! use `with_candidate_lists` to restrict them & speed up the search on large instances.
+//!
+//! Weights are assumed to be symmetric. Paths containing only subset of cities are improved as well,
+//! as long as they are tours (the last city is connected with the first one).
+use crate::aco::daemon::DaemonAction;
+use crate::aco::dynamic::ProblemChange;
+use crate::aco::tsp::candidate_lists::CandidateLists;
+use crate::aco::{AdditionalArgs, FMatrix};
+
+/// Minimal improvement of tour length for a move to be applied
+const EPSILON: f64 = 1e-9;
+
+/// Position of cities absent in improved tour
+const ABSENT: usize = usize::MAX;
+
+/// Implementation of moves shared by local search daemon actions
+struct TourOptimizer<'a> {
+    weights: &'a FMatrix,
+    candidates: &'a CandidateLists,
+}
+
+impl<'a> TourOptimizer<'a> {
+    #[inline]
+    fn d(&self, a: usize, b: usize) -> f64 {
+        self.weights[(a, b)]
+    }
+
+    /// Applies moves until local optimum is reached
+    ///
+    /// ## Arguments
+    /// * `tour` - tour to improve
+    /// * `two_opt` - whether to apply 2-opt moves
+    /// * `max_segment_len` - maximal length of moved segments, 0 disables segment moves
+    fn optimize(&self, tour: &mut Vec<usize>, two_opt: bool, max_segment_len: usize) {
+        if tour.len() < 4 {
+            return;
+        }
+        let mut pos = vec![ABSENT; self.weights.nrows()];
+        tour.iter().enumerate().for_each(|(i, v)| pos[*v] = i);
+
+        loop {
+            let mut improved = false;
+            if two_opt {
+                improved |= self.two_opt(tour, &mut pos);
+            }
+            if max_segment_len > 0 {
+                improved |= self.or_opt(tour, &mut pos, max_segment_len);
+            }
+            if !improved {
+                break;
+            }
+        }
+    }
+
+    /// Applies all improving 2-opt moves found in a single pass. Returns true iff any was applied.
+    fn two_opt(&self, tour: &mut [usize], pos: &mut [usize]) -> bool {
+        let n = tour.len();
+        let mut improved = false;
+        for i in 0..n {
+            let a = tour[i];
+            // Both neighbours of `a` have to be considered to make the neighbourhood complete
+            for forward in [true, false] {
+                let step = |p: usize| if forward { (p + 1) % n } else { (p + n - 1) % n };
+                let b = tour[step(pos[a])];
+                let d_ab = self.d(a, b);
+
+                for &c in self.candidates.get(a) {
+                    let d_ac = self.d(a, c);
+                    if d_ac >= d_ab {
+                        break;
+                    }
+                    if pos[c] == ABSENT {
+                        continue;
+                    }
+                    let d = tour[step(pos[c])];
+                    if c == b || d == a {
+                        continue;
+                    }
+
+                    if d_ac + self.d(b, d) - d_ab - self.d(c, d) < -EPSILON {
+                        if forward {
+                            reverse(tour, pos, pos[b], pos[c]);
+                        } else {
+                            reverse(tour, pos, pos[a], pos[d]);
+                        }
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+        }
+        improved
+    }
+
+    /// Applies all improving segment moves found in a single pass. Returns true iff any was applied.
+    fn or_opt(&self, tour: &mut Vec<usize>, pos: &mut [usize], max_segment_len: usize) -> bool {
+        let mut improved = false;
+        for len in 1..=max_segment_len {
+            if tour.len() < len + 3 {
+                break;
+            }
+            for start in 0..tour.len() {
+                if let Some(new_tour) = self.move_segment(tour, pos, start, len) {
+                    *tour = new_tour;
+                    tour.iter().enumerate().for_each(|(i, v)| pos[*v] = i);
+                    improved = true;
+                }
+            }
+        }
+        improved
+    }
+
+    /// Returns tour with segment of length `len` starting at position `start` moved to the best place
+    /// among ones next to candidates of segment ends, if it improves the tour.
+    fn move_segment(&self, tour: &[usize], pos: &[usize], start: usize, len: usize) -> Option<Vec<usize>> {
+        let n = tour.len();
+        let at = |p: usize| tour[p % n];
+        let (first, last) = (at(start), at(start + len - 1));
+        let (prev, next) = (at(start + n - 1), at(start + len));
+        let gain = self.d(prev, first) + self.d(last, next) - self.d(prev, next);
+        if gain <= EPSILON {
+            return None;
+        }
+        let in_segment = |v: usize| (pos[v] + n - start) % n < len;
+
+        for end in [first, last] {
+            for &c in self.candidates.get(end) {
+                if self.d(end, c) >= gain {
+                    break;
+                }
+                if pos[c] == ABSENT || in_segment(c) {
+                    continue;
+                }
+                // Segment can be inserted on either side of `c`
+                for (x, y) in [(c, at(pos[c] + 1)), (at(pos[c] + n - 1), c)] {
+                    if in_segment(x) || in_segment(y) {
+                        continue;
+                    }
+                    let removed = self.d(x, y);
+                    let forward = self.d(x, first) + self.d(last, y) - removed;
+                    let reversed = self.d(x, last) + self.d(first, y) - removed;
+                    if forward.min(reversed) - gain < -EPSILON {
+                        let mut segment = (0..len).map(|k| at(start + k)).collect::<Vec<_>>();
+                        if reversed < forward {
+                            segment.reverse();
+                        }
+                        let mut new_tour = (0..n - len).map(|k| at(start + len + k)).collect::<Vec<_>>();
+                        let x_idx = new_tour.iter().position(|v| *v == x).unwrap();
+                        new_tour.splice(x_idx + 1..x_idx + 1, segment);
+                        return Some(new_tour);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Reverses cyclic part of the tour between positions `from` & `to` (inclusive)
+fn reverse(tour: &mut [usize], pos: &mut [usize], from: usize, to: usize) {
+    let n = tour.len();
+    let len = (to + n - from) % n + 1;
+    let (mut i, mut j) = (from, to);
+    for _ in 0..len / 2 {
+        tour.swap(i, j);
+        pos[tour[i]] = i;
+        pos[tour[j]] = j;
+        i = (i + 1) % n;
+        j = (j + n - 1) % n;
+    }
+}
+
+fn full_candidate_lists(weights: &FMatrix) -> CandidateLists {
+    CandidateLists::new(weights, weights.nrows().max(2) - 1)
+}
+
+/// Updates weights and rebuilds candidate lists, keeping their length
+fn apply_change(weights: &mut FMatrix, candidates: &mut CandidateLists, change: &ProblemChange) {
+    let k = (0..candidates.len())
+        .map(|v| candidates.get(v).len())
+        .max()
+        .unwrap_or(0);
+    let all_cities = k + 1 >= weights.nrows();
+    change.apply_to_matrix(weights, |w| w, 0.0);
+    *candidates = if all_cities {
+        full_candidate_lists(weights)
+    } else {
+        CandidateLists::new(weights, k.max(1))
+    };
+}
+
+/// # 2-opt
+///
+/// Implements [DaemonAction]. Improves every tour with 2-opt local search, see [module](self) docs.
+pub struct TwoOpt {
+    weights: FMatrix,
+    candidates: CandidateLists,
+}
+
+impl TwoOpt {
+    /// Creates a new instance of [TwoOpt] considering all the cities as candidates.
+    ///
+    /// ## Arguments
+    /// * `weights` - Weighted graph in matrix representation.
+    pub fn new(weights: FMatrix) -> Self {
+        let candidates = full_candidate_lists(&weights);
+        Self { weights, candidates }
+    }
+
+    /// Restricts moves to given candidate lists.
+    ///
+    /// ## Arguments
+    /// * `candidates` - candidate lists, e.g. 10 nearest neighbours of every city.
+    pub fn with_candidate_lists(mut self, candidates: CandidateLists) -> Self {
+        self.candidates = candidates;
+        self
+    }
+}
+
+impl<Args: AdditionalArgs> DaemonAction<Args> for TwoOpt {
+    fn apply(&mut self, paths: &mut [Vec<usize>], _: &Args) {
+        let optimizer = TourOptimizer {
+            weights: &self.weights,
+            candidates: &self.candidates,
+        };
+        paths
+            .iter_mut()
+            .for_each(|path| optimizer.optimize(path, true, 0));
+    }
+
+    fn apply_change(&mut self, change: &ProblemChange) {
+        apply_change(&mut self.weights, &mut self.candidates, change);
+    }
+}
+
+/// # 2.5-opt
+///
+/// Implements [DaemonAction]. Improves every tour with 2-opt & single city insertion moves,
+/// see [module](self) docs.
+pub struct TwoHalfOpt {
+    weights: FMatrix,
+    candidates: CandidateLists,
+}
+
+impl TwoHalfOpt {
+    /// Creates a new instance of [TwoHalfOpt] considering all the cities as candidates.
+    ///
+    /// ## Arguments
+    /// * `weights` - Weighted graph in matrix representation.
+    pub fn new(weights: FMatrix) -> Self {
+        let candidates = full_candidate_lists(&weights);
+        Self { weights, candidates }
+    }
+
+    /// Restricts moves to given candidate lists.
+    ///
+    /// ## Arguments
+    /// * `candidates` - candidate lists, e.g. 10 nearest neighbours of every city.
+    pub fn with_candidate_lists(mut self, candidates: CandidateLists) -> Self {
+        self.candidates = candidates;
+        self
+    }
+}
+
+impl<Args: AdditionalArgs> DaemonAction<Args> for TwoHalfOpt {
+    fn apply(&mut self, paths: &mut [Vec<usize>], _: &Args) {
+        let optimizer = TourOptimizer {
+            weights: &self.weights,
+            candidates: &self.candidates,
+        };
+        paths
+            .iter_mut()
+            .for_each(|path| optimizer.optimize(path, true, 1));
+    }
+
+    fn apply_change(&mut self, change: &ProblemChange) {
+        apply_change(&mut self.weights, &mut self.candidates, change);
+    }
+}
+
+/// # Or-opt
+///
+/// Implements [DaemonAction]. Improves every tour by moving segments of consecutive cities,
+/// see [module](self) docs.
+pub struct OrOpt {
+    weights: FMatrix,
+    candidates: CandidateLists,
+    max_segment_len: usize,
+}
+
+impl OrOpt {
+    /// Creates a new instance of [OrOpt] moving segments of up to 3 cities & considering all
+    /// the cities as candidates.
+    ///
+    /// ## Arguments
+    /// * `weights` - Weighted graph in matrix representation.
+    pub fn new(weights: FMatrix) -> Self {
+        let candidates = full_candidate_lists(&weights);
+        Self {
+            weights,
+            candidates,
+            max_segment_len: 3,
+        }
+    }
+
+    /// Restricts moves to given candidate lists.
+    ///
+    /// ## Arguments
+    /// * `candidates` - candidate lists, e.g. 10 nearest neighbours of every city.
+    pub fn with_candidate_lists(mut self, candidates: CandidateLists) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    /// Sets maximal length of moved segments.
+    ///
+    /// ## Arguments
+    /// * `max_segment_len` - must be > 0.
+    pub fn with_max_segment_len(mut self, max_segment_len: usize) -> Self {
+        assert!(max_segment_len > 0, "Segment length must be > 0");
+        self.max_segment_len = max_segment_len;
+        self
+    }
+}
+
+impl<Args: AdditionalArgs> DaemonAction<Args> for OrOpt {
+    fn apply(&mut self, paths: &mut [Vec<usize>], _: &Args) {
+        let optimizer = TourOptimizer {
+            weights: &self.weights,
+            candidates: &self.candidates,
+        };
+        paths
+            .iter_mut()
+            .for_each(|path| optimizer.optimize(path, false, self.max_segment_len));
+    }
+
+    fn apply_change(&mut self, change: &ProblemChange) {
+        apply_change(&mut self.weights, &mut self.candidates, change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::daemon::DaemonAction;
+    use crate::aco::dynamic::ProblemChange;
+    use crate::aco::tsp::candidate_lists::CandidateLists;
+    use crate::aco::tsp::daemon::{OrOpt, TwoHalfOpt, TwoOpt};
+    use crate::aco::FMatrix;
+    use itertools::Itertools;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    fn euclidean(points: &[(f64, f64)]) -> FMatrix {
+        let n = points.len();
+        FMatrix::from_fn(n, n, |i, j| {
+            ((points[i].0 - points[j].0).powi(2) + (points[i].1 - points[j].1).powi(2)).sqrt()
+        })
+    }
+
+    fn circle(n: usize) -> FMatrix {
+        let points = (0..n)
+            .map(|i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                (angle.cos(), angle.sin())
+            })
+            .collect_vec();
+        euclidean(&points)
+    }
+
+    fn cost(weights: &FMatrix, tour: &[usize]) -> f64 {
+        tour.iter()
+            .cloned()
+            .circular_tuple_windows::<(usize, usize)>()
+            .map(|e| weights[e])
+            .sum()
+    }
+
+    fn is_permutation(tour: &[usize], n: usize) -> bool {
+        tour.iter().cloned().sorted().eq(0..n)
+    }
+
+    #[test]
+    fn two_opt_removes_crossing() {
+        let weights = euclidean(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let mut paths = vec![vec![0, 2, 1, 3]];
+
+        TwoOpt::new(weights.clone()).apply(&mut paths, &());
+
+        assert!((cost(&weights, &paths[0]) - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn local_searches_find_optimum_on_circle() {
+        let n = 12;
+        let weights = circle(n);
+        let optimum = cost(&weights, &(0..n).collect_vec());
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut tour = (0..n).collect_vec();
+        tour.shuffle(&mut rng);
+
+        let mut two_opt = vec![tour.clone()];
+        TwoOpt::new(weights.clone()).apply(&mut two_opt, &());
+        let mut two_half_opt = vec![tour];
+        TwoHalfOpt::new(weights.clone()).apply(&mut two_half_opt, &());
+
+        for paths in [two_opt, two_half_opt] {
+            assert!(is_permutation(&paths[0], n));
+            assert!((cost(&weights, &paths[0]) - optimum).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn or_opt_moves_misplaced_segment() {
+        let n = 10;
+        let weights = circle(n);
+        let optimum = cost(&weights, &(0..n).collect_vec());
+        let mut paths = vec![
+            vec![0, 1, 2, 6, 7, 3, 4, 5, 8, 9],
+            vec![0, 1, 2, 4, 3, 5, 6, 7, 8, 9],
+        ];
+
+        OrOpt::new(weights.clone()).apply(&mut paths, &());
+
+        for path in paths {
+            assert!(is_permutation(&path, n));
+            assert!((cost(&weights, &path) - optimum).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn candidate_lists_restrict_search_without_breaking_tours() {
+        let n = 60;
+        let mut rng = StdRng::seed_from_u64(5);
+        let points = (0..n)
+            .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+            .collect_vec();
+        let weights = euclidean(&points);
+        let candidates = CandidateLists::new(&weights, 8);
+        let mut tour = (0..n).collect_vec();
+        tour.shuffle(&mut rng);
+        let initial = cost(&weights, &tour);
+
+        let mut paths = vec![tour.clone(), tour.clone(), tour];
+        TwoOpt::new(weights.clone())
+            .with_candidate_lists(candidates.clone())
+            .apply(&mut paths[0..1], &());
+        TwoHalfOpt::new(weights.clone())
+            .with_candidate_lists(candidates.clone())
+            .apply(&mut paths[1..2], &());
+        OrOpt::new(weights.clone())
+            .with_candidate_lists(candidates)
+            .apply(&mut paths[2..3], &());
+
+        for path in paths {
+            assert!(is_permutation(&path, n));
+            assert!(cost(&weights, &path) < 0.5 * initial);
+        }
+    }
+
+    #[test]
+    fn tours_over_subset_of_cities_are_improved() {
+        let weights = circle(8);
+        let mut paths = vec![vec![6, 0, 4, 2]];
+
+        TwoOpt::new(weights.clone()).apply(&mut paths, &());
+
+        let optimum = cost(&weights, &[0, 2, 4, 6]);
+        assert!((cost(&weights, &paths[0]) - optimum).abs() < 1e-9);
+    }
+
+    #[test]
+    fn problem_changes_update_weights_and_candidates() {
+        let mut two_opt = TwoOpt::new(circle(6)).with_candidate_lists(CandidateLists::new(&circle(6), 3));
+        DaemonAction::<()>::apply_change(&mut two_opt, &ProblemChange::RemoveVertex(5));
+        DaemonAction::<()>::apply_change(&mut two_opt, &ProblemChange::RemoveVertex(4));
+
+        assert_eq!(two_opt.weights.nrows(), 4);
+        assert_eq!(two_opt.candidates.len(), 4);
+        assert_eq!(two_opt.candidates.get(0).len(), 3);
+
+        let mut paths = vec![vec![0, 2, 1, 3]];
+        two_opt.apply(&mut paths, &());
+        let weights = two_opt.weights.clone();
+        assert!(cost(&weights, &paths[0]) < cost(&weights, &[0, 2, 1, 3]));
+    }
+}
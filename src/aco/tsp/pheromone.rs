@@ -1,8 +1,13 @@
+use crate::aco::dynamic::{Dynamic, ProblemChange};
 use crate::aco::pheromone::PheromoneUpdate;
+use crate::aco::stagnation::{ReinitializingPU, StagnationMeasure};
 use crate::aco::tsp::pheromone::best_policy::{BestPolicy, OverallBest};
 use crate::aco::{AdditionalArgs, FMatrix, Solution};
 use itertools::Itertools;
 use nalgebra::clamp;
+use rand::prelude::ThreadRng;
+use rand::{thread_rng, Rng};
+use std::collections::HashSet;
 
 pub mod best_policy;
 
@@ -107,6 +112,16 @@ impl<B: BestPolicy> MMAntSystemPU<B> {
             evaporation_rate,
         }
     }
+
+    /// Wraps the update in [ReinitializingPU], which resets every trail to the upper bound
+    /// when stagnation is detected.
+    ///
+    /// ## Arguments
+    /// * `measure` - measure used to detect stagnation.
+    pub fn with_reinitialization(self, measure: StagnationMeasure) -> ReinitializingPU<Self> {
+        let upper_bound = self.upper_bound;
+        ReinitializingPU::new(self, measure, upper_bound)
+    }
 }
 
 impl MMAntSystemPU<OverallBest> {
@@ -187,6 +202,220 @@ impl<B: BestPolicy, Args: AdditionalArgs> PheromoneUpdate<FMatrix, Args> for Ant
     }
 }
 
+/// # Rank-Based Ant System Pheromone Update
+///
+/// Implements [PheromoneUpdate]. After evaporation only `weight - 1` best ants of the iteration leave
+/// pheromone trail, trail of the ant of rank `r` is scaled by `weight - r`. Additionally the ant chosen
+/// by [BestPolicy] leaves trail scaled by `weight`.
+pub struct RankBasedAntSystemPU<B: BestPolicy> {
+    evaporation_rate: f64,
+    weight: usize,
+    best_policy: B,
+}
+
+impl<B: BestPolicy> RankBasedAntSystemPU<B> {
+    /// Creates a [RankBasedAntSystemPU] with user provided implementation of [BestPolicy].
+    ///
+    /// ## Arguments
+    /// * `evaporation_rate` - rate of old pheromone evaporation
+    /// * `weight` - number of ants leaving pheromone trail (including the best one), must be > 0.
+    /// * `best_policy` - Implementation of [BestPolicy]
+    pub fn with_best_policy(evaporation_rate: f64, weight: usize, best_policy: B) -> Self {
+        assert!(weight > 0, "Weight must be greater than 0");
+        Self {
+            evaporation_rate,
+            weight,
+            best_policy,
+        }
+    }
+}
+
+impl RankBasedAntSystemPU<OverallBest> {
+    /// Creates a [RankBasedAntSystemPU] with [OverallBest] best ant choosing policy
+    ///
+    /// ## Arguments
+    /// * `evaporation_rate` - rate of old pheromone evaporation
+    /// * `weight` - number of ants leaving pheromone trail (including the best one), must be > 0.
+    pub fn new(evaporation_rate: f64, weight: usize) -> Self {
+        Self::with_best_policy(evaporation_rate, weight, OverallBest::new())
+    }
+}
+
+impl<B: BestPolicy, Args: AdditionalArgs> PheromoneUpdate<FMatrix, Args> for RankBasedAntSystemPU<B> {
+    fn apply(&mut self, pheromone: &mut FMatrix, solutions: &[Solution], _: &Args) {
+        self.best_policy.update_best(solutions);
+
+        pheromone.scale_mut(1.0 - self.evaporation_rate);
+        let ranked = solutions
+            .iter()
+            .sorted_by(|a, b| b.fitness.total_cmp(&a.fitness))
+            .take(self.weight - 1);
+        for (rank, s) in ranked.enumerate() {
+            leave_scaled_trail(s, pheromone, (self.weight - rank - 1) as f64);
+        }
+        leave_scaled_trail(self.best_policy.get_best(), pheromone, self.weight as f64);
+    }
+}
+
+/// # Best-Worst Ant System Pheromone Update
+///
+/// Implements [PheromoneUpdate]. After evaporation only the ant chosen by [BestPolicy] leaves pheromone trail.
+/// Edges of the worst tour of the iteration, which are not in the best tour, are evaporated once more.
+/// Finally, with given probability pheromone on every edge is mutated by adding or subtracting
+/// `mutation_strength` times average pheromone on the best tour.
+///
+/// Best-Worst Ant System restarts the search on stagnation, to get this behaviour wrap the update in
+/// [ReinitializingPU].
+pub struct BestWorstAntSystemPU<B: BestPolicy, R: Rng = ThreadRng> {
+    evaporation_rate: f64,
+    mutation_probability: f64,
+    mutation_strength: f64,
+    best_policy: B,
+    rng: R,
+}
+
+impl<B: BestPolicy> BestWorstAntSystemPU<B, ThreadRng> {
+    /// Creates a [BestWorstAntSystemPU] with user provided implementation of [BestPolicy].
+    ///
+    /// ## Arguments
+    /// * `evaporation_rate` - rate of old pheromone evaporation
+    /// * `mutation_probability` - Number between 0.0 and 1.0. Probability of mutating pheromone on an edge.
+    /// * `mutation_strength` - Mutation scale, relative to average pheromone on the best tour.
+    /// * `best_policy` - Implementation of [BestPolicy]
+    pub fn with_best_policy(
+        evaporation_rate: f64,
+        mutation_probability: f64,
+        mutation_strength: f64,
+        best_policy: B,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&mutation_probability),
+            "Mutation probability must be in range [0.0, 1.0]"
+        );
+        Self {
+            evaporation_rate,
+            mutation_probability,
+            mutation_strength,
+            best_policy,
+            rng: thread_rng(),
+        }
+    }
+}
+
+impl<B: BestPolicy, R: Rng> BestWorstAntSystemPU<B, R> {
+    /// Sets random numbers generator used for pheromone mutation.
+    ///
+    /// ## Arguments
+    /// * `rng` - Random numbers generator.
+    pub fn with_rng<R2: Rng>(self, rng: R2) -> BestWorstAntSystemPU<B, R2> {
+        BestWorstAntSystemPU {
+            evaporation_rate: self.evaporation_rate,
+            mutation_probability: self.mutation_probability,
+            mutation_strength: self.mutation_strength,
+            best_policy: self.best_policy,
+            rng,
+        }
+    }
+}
+
+impl BestWorstAntSystemPU<OverallBest, ThreadRng> {
+    /// Creates a [BestWorstAntSystemPU] with [OverallBest] best ant choosing policy
+    ///
+    /// ## Arguments
+    /// * `evaporation_rate` - rate of old pheromone evaporation
+    /// * `mutation_probability` - Number between 0.0 and 1.0. Probability of mutating pheromone on an edge.
+    /// * `mutation_strength` - Mutation scale, relative to average pheromone on the best tour.
+    pub fn new(evaporation_rate: f64, mutation_probability: f64, mutation_strength: f64) -> Self {
+        Self::with_best_policy(
+            evaporation_rate,
+            mutation_probability,
+            mutation_strength,
+            OverallBest::new(),
+        )
+    }
+}
+
+impl<B: BestPolicy, R: Rng, Args: AdditionalArgs> PheromoneUpdate<FMatrix, Args>
+    for BestWorstAntSystemPU<B, R>
+{
+    fn apply(&mut self, pheromone: &mut FMatrix, solutions: &[Solution], _: &Args) {
+        self.best_policy.update_best(solutions);
+
+        pheromone.scale_mut(1.0 - self.evaporation_rate);
+        let best = self.best_policy.get_best();
+        leave_single_trail(best, pheromone);
+
+        let best_edges = undirected_edges(best).collect::<HashSet<_>>();
+        if let Some(worst) = solutions.iter().min_by(|a, b| a.fitness.total_cmp(&b.fitness)) {
+            for (i, j) in undirected_edges(worst).filter(|e| !best_edges.contains(e)) {
+                pheromone[(i, j)] *= 1.0 - self.evaporation_rate;
+                pheromone[(j, i)] = pheromone[(i, j)];
+            }
+        }
+
+        if best_edges.is_empty() || self.mutation_probability == 0.0 {
+            return;
+        }
+        let best_average = best_edges.iter().map(|e| pheromone[*e]).sum::<f64>() / best_edges.len() as f64;
+        let mutation = self.mutation_strength * best_average;
+        for i in 0..pheromone.nrows() {
+            for j in i + 1..pheromone.ncols() {
+                if self.rng.gen::<f64>() < self.mutation_probability {
+                    let sign = if self.rng.gen::<bool>() { 1.0 } else { -1.0 };
+                    pheromone[(i, j)] = (pheromone[(i, j)] + sign * mutation).max(0.0);
+                    pheromone[(j, i)] = pheromone[(i, j)];
+                }
+            }
+        }
+    }
+}
+
+// On problem change pheromone updates forget stored best solution (if any),
+// the pheromone matrix itself is resized by its own implementation of Dynamic.
+
+impl Dynamic for AntSystemPU {
+    fn apply_change(&mut self, _change: &ProblemChange) {}
+}
+
+impl Dynamic for ElitistAntSystemPU {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.overall_best.apply_change(change);
+    }
+}
+
+impl<B: BestPolicy + Dynamic> Dynamic for MMAntSystemPU<B> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.best_policy.apply_change(change);
+    }
+}
+
+impl<B: BestPolicy + Dynamic> Dynamic for AntColonySystemPU<B> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.best_policy.apply_change(change);
+    }
+}
+
+impl<B: BestPolicy + Dynamic> Dynamic for RankBasedAntSystemPU<B> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.best_policy.apply_change(change);
+    }
+}
+
+impl<B: BestPolicy + Dynamic, R: Rng> Dynamic for BestWorstAntSystemPU<B, R> {
+    fn apply_change(&mut self, change: &ProblemChange) {
+        self.best_policy.apply_change(change);
+    }
+}
+
+/// Returns edges of the tour, with smaller vertex first
+#[inline]
+fn undirected_edges(s: &Solution) -> impl Iterator<Item = (usize, usize)> + '_ {
+    s.path
+        .iter()
+        .circular_tuple_windows::<(&usize, &usize)>()
+        .map(|(i, j)| (*i.min(j), *i.max(j)))
+}
+
 #[inline]
 fn leave_trail(solutions: &[Solution], pheromone: &mut FMatrix) {
     for s in solutions.iter() {
@@ -194,20 +423,31 @@ fn leave_trail(solutions: &[Solution], pheromone: &mut FMatrix) {
     }
 }
 
-// THINK: add scale parameter
 #[inline]
 fn leave_single_trail(s: &Solution, pheromone: &mut FMatrix) {
+    leave_scaled_trail(s, pheromone, 1.0);
+}
+
+#[inline]
+fn leave_scaled_trail(s: &Solution, pheromone: &mut FMatrix, scale: f64) {
+    let trail = s.fitness * scale;
     for (i, j) in s.path.iter().circular_tuple_windows::<(&usize, &usize)>() {
-        pheromone[(*i, *j)] += s.fitness;
-        pheromone[(*j, *i)] += s.fitness;
+        pheromone[(*i, *j)] += trail;
+        pheromone[(*j, *i)] += trail;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::aco::pheromone::PheromoneUpdate;
-    use crate::aco::tsp::pheromone::{AntColonySystemPU, AntSystemPU, ElitistAntSystemPU, MMAntSystemPU};
+    use crate::aco::stagnation::StagnationMeasure;
+    use crate::aco::tsp::pheromone::{
+        AntColonySystemPU, AntSystemPU, BestWorstAntSystemPU, ElitistAntSystemPU, MMAntSystemPU,
+        RankBasedAntSystemPU,
+    };
     use crate::aco::{FMatrix, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     fn get_test_data() -> (FMatrix, [Solution; 2]) {
         let pheromone = FMatrix::from_column_slice(3, 3, &[0.0, 1.0, 2.0, 1.0, 0.0, 4.0, 2.0, 4.0, 0.0]);
@@ -273,4 +513,93 @@ mod tests {
         pu.apply(&mut pher, &sols, &());
         are_same(pher, &pher_expt);
     }
+
+    #[test]
+    fn max_min_ant_system_pu_reinitializes_stagnated_pheromone() {
+        let mut pher = FMatrix::from_fn(4, 4, |i, j| {
+            if (i + 1) % 4 == j || (j + 1) % 4 == i {
+                5.0
+            } else {
+                0.1
+            }
+        });
+        let sols = [Solution {
+            path: vec![0, 1, 2, 3],
+            fitness: 1.0,
+        }];
+        let measure = StagnationMeasure::BranchingFactor {
+            lambda: 0.05,
+            threshold: 2.0,
+        };
+
+        let mut pu = MMAntSystemPU::new(0.25, 0.1, 5.0).with_reinitialization(measure);
+        pu.apply(&mut pher, &sols, &());
+
+        assert_eq!(pher, FMatrix::repeat(4, 4, 5.0));
+        assert_eq!(pu.reinitializations(), 1);
+    }
+
+    #[test]
+    fn check_rank_based_ant_system_pu_with_example() {
+        let pher_expt = vec![0.0, 1.5, 2.25, 1.5, 0.0, 3.75, 2.25, 3.75, 0.0];
+        let (mut pher, sols) = get_test_data();
+
+        let mut pu = RankBasedAntSystemPU::new(0.25, 2);
+        pu.apply(&mut pher, &sols, &());
+        are_same(pher, &pher_expt);
+    }
+
+    fn best_worst_test_data() -> (FMatrix, [Solution; 2]) {
+        let pheromone = FMatrix::from_fn(4, 4, |i, j| if i == j { 0.0 } else { 1.0 });
+        let sols = [
+            Solution {
+                path: vec![0, 1, 2, 3],
+                fitness: 0.5,
+            },
+            Solution {
+                path: vec![0, 2, 1, 3],
+                fitness: 0.25,
+            },
+        ];
+        (pheromone, sols)
+    }
+
+    #[test]
+    fn best_worst_ant_system_pu_penalizes_worst_edges() {
+        let (mut pher, sols) = best_worst_test_data();
+
+        let mut pu = BestWorstAntSystemPU::new(0.5, 0.0, 0.5);
+        pu.apply(&mut pher, &sols, &());
+
+        let pher_expt = FMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                0.0, 1.0, 0.25, 1.0, //
+                1.0, 0.0, 1.0, 0.25, //
+                0.25, 1.0, 0.0, 1.0, //
+                1.0, 0.25, 1.0, 0.0,
+            ],
+        );
+        assert_eq!(pher, pher_expt);
+    }
+
+    #[test]
+    fn best_worst_ant_system_pu_mutates_pheromone_symmetrically() {
+        let (mut pher, sols) = best_worst_test_data();
+        let (mut not_mutated, _) = best_worst_test_data();
+
+        BestWorstAntSystemPU::new(0.5, 0.0, 0.5).apply(&mut not_mutated, &sols, &());
+        BestWorstAntSystemPU::new(0.5, 1.0, 0.5)
+            .with_rng(StdRng::seed_from_u64(3))
+            .apply(&mut pher, &sols, &());
+
+        assert_eq!(pher, pher.transpose());
+        for i in 0..4 {
+            for j in (0..4).filter(|j| *j != i) {
+                let change = pher[(i, j)] - not_mutated[(i, j)];
+                assert!((change.abs() - 0.5).abs() < 1e-12 || pher[(i, j)] == 0.0);
+            }
+        }
+    }
 }
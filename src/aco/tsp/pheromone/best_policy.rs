@@ -5,6 +5,7 @@
 //! * [OverallBest] - chooses the best in all iterations
 //! * [Iteration] - chooses the best in current iteration
 //!
+use crate::aco::dynamic::{Dynamic, ProblemChange};
 use crate::aco::Solution;
 
 /// # Best Choosing Policy
@@ -84,6 +85,20 @@ impl BestPolicy for OverallBest {
     }
 }
 
+/// Stored solution solves the old problem, thus it is forgotten.
+impl Dynamic for IterationBest {
+    fn apply_change(&mut self, _change: &ProblemChange) {
+        self.best_solution = Solution::default();
+    }
+}
+
+/// Stored solution solves the old problem, thus it is forgotten.
+impl Dynamic for OverallBest {
+    fn apply_change(&mut self, _change: &ProblemChange) {
+        self.best_solution = Solution::default();
+    }
+}
+
 #[inline]
 fn find_best(solutions: &[Solution]) -> &Solution {
     solutions
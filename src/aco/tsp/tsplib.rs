@@ -0,0 +1,493 @@
+//! Reader of TSPLIB95 (<http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/>) instances
+//! & reader / writer of `.tour` files.
+//!
+//! Supported edge weight types are `EUC_2D`, `CEIL_2D`, `ATT`, `GEO` & `EXPLICIT` (in any of matrix formats).
+//! Distances are computed as described in TSPLIB95 documentation, so that tour costs are comparable
+//! with published optima. Nodes are numbered from 1 in files & from 0 in the library.
+//!
+//! ```no_run
+//! use ecrs::aco::tsp::tsplib;
+//!
+//! let instance = tsplib::read_instance("berlin52.tsp").unwrap();
+//! let heuristic = instance.heuristic();
+//! // ... run the algorithm
+//! # let tour: Vec<usize> = (0..instance.dimension).collect();
+//! let optimal = tsplib::read_tour("berlin52.opt.tour").unwrap();
+//! println!("Gap: {:.2}%", 100.0 * instance.gap(&tour, &optimal));
+//! tsplib::write_tour("berlin52.tour", &instance.name, &tour).unwrap();
+//! ```
+
+use crate::aco::tsp::util::create_heuristic_from_weights;
+use crate::aco::FMatrix;
+use itertools::Itertools;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+
+/// Error returned when instance or tour can not be read or written
+#[derive(Debug)]
+pub enum TsplibError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl Display for TsplibError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to access file: {err}"),
+            Self::Parse(msg) => write!(f, "Invalid TSPLIB data: {msg}"),
+        }
+    }
+}
+
+impl Error for TsplibError {}
+
+impl From<std::io::Error> for TsplibError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+fn parse_err(msg: impl Into<String>) -> TsplibError {
+    TsplibError::Parse(msg.into())
+}
+
+/// Way of computing edge weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeWeightType {
+    /// Euclidean distance rounded to the nearest integer
+    Euc2d,
+    /// Euclidean distance rounded up
+    Ceil2d,
+    /// Pseudo-Euclidean distance
+    Att,
+    /// Geographical distance, coordinates are latitude & longitude in DDD.MM format
+    Geo,
+    /// Weights are listed in `EDGE_WEIGHT_SECTION`
+    Explicit,
+}
+
+impl EdgeWeightType {
+    fn parse(value: &str) -> Result<Self, TsplibError> {
+        match value {
+            "EUC_2D" => Ok(Self::Euc2d),
+            "CEIL_2D" => Ok(Self::Ceil2d),
+            "ATT" => Ok(Self::Att),
+            "GEO" => Ok(Self::Geo),
+            "EXPLICIT" => Ok(Self::Explicit),
+            _ => Err(parse_err(format!("unsupported edge weight type {value}"))),
+        }
+    }
+
+    /// Returns distance between two nodes. Panics for [EdgeWeightType::Explicit].
+    ///
+    /// ## Arguments
+    /// * `a` - coordinates of the first node
+    /// * `b` - coordinates of the second node
+    pub fn distance(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+        match self {
+            Self::Euc2d => nint((dx * dx + dy * dy).sqrt()),
+            Self::Ceil2d => (dx * dx + dy * dy).sqrt().ceil(),
+            Self::Att => {
+                let r = ((dx * dx + dy * dy) / 10.0).sqrt();
+                let t = nint(r);
+                if t < r {
+                    t + 1.0
+                } else {
+                    t
+                }
+            }
+            Self::Geo => {
+                const RRR: f64 = 6378.388;
+                let (lat_a, lon_a) = (geo_radians(a.0), geo_radians(a.1));
+                let (lat_b, lon_b) = (geo_radians(b.0), geo_radians(b.1));
+                let q1 = (lon_a - lon_b).cos();
+                let q2 = (lat_a - lat_b).cos();
+                let q3 = (lat_a + lat_b).cos();
+                (RRR * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0).trunc()
+            }
+            Self::Explicit => panic!("Explicit weights can not be computed from coordinates"),
+        }
+    }
+}
+
+#[inline]
+fn nint(x: f64) -> f64 {
+    (x + 0.5).floor()
+}
+
+/// Converts DDD.MM coordinate to radians (with TSPLIB approximation of PI)
+fn geo_radians(x: f64) -> f64 {
+    // TSPLIB uses truncated value, which affects rounded distances
+    #[allow(clippy::approx_constant)]
+    const PI: f64 = 3.141592;
+    let deg = x.trunc();
+    let min = x - deg;
+    PI * (deg + 5.0 * min / 3.0) / 180.0
+}
+
+/// Returns matrix of distances between every pair of nodes
+///
+/// ## Arguments
+/// * `coordinates` - coordinates of nodes
+/// * `weight_type` - way of computing distances, must not be [EdgeWeightType::Explicit]
+pub fn distance_matrix(coordinates: &[(f64, f64)], weight_type: EdgeWeightType) -> FMatrix {
+    let n = coordinates.len();
+    FMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            0.0
+        } else {
+            weight_type.distance(coordinates[i], coordinates[j])
+        }
+    })
+}
+
+/// TSP (or ATSP) instance
+#[derive(Debug, Clone)]
+pub struct TsplibInstance {
+    pub name: String,
+    pub comment: Option<String>,
+    pub dimension: usize,
+    /// False for ATSP instances
+    pub symmetric: bool,
+    pub edge_weight_type: EdgeWeightType,
+    /// Empty for instances with explicit weights
+    pub coordinates: Vec<(f64, f64)>,
+    pub weights: FMatrix,
+}
+
+impl TsplibInstance {
+    /// Returns heuristic for [CanonicalGoodness](crate::aco::goodness::CanonicalGoodness)
+    pub fn heuristic(&self) -> FMatrix {
+        create_heuristic_from_weights(&self.weights)
+    }
+
+    /// Returns length of closed tour
+    ///
+    /// ## Arguments
+    /// * `tour` - nodes in order of visiting
+    pub fn tour_cost(&self, tour: &[usize]) -> f64 {
+        tour.iter()
+            .cloned()
+            .circular_tuple_windows::<(usize, usize)>()
+            .map(|edge| self.weights[edge])
+            .sum()
+    }
+
+    /// Returns relative gap between cost of `tour` & cost of `optimal` tour, e.g. 0.05 for tour 5% longer
+    /// than the optimal one
+    ///
+    /// ## Arguments
+    /// * `tour` - evaluated tour
+    /// * `optimal` - optimal tour, e.g. read from `.opt.tour` file
+    pub fn gap(&self, tour: &[usize], optimal: &[usize]) -> f64 {
+        let optimal_cost = self.tour_cost(optimal);
+        (self.tour_cost(tour) - optimal_cost) / optimal_cost
+    }
+}
+
+/// Iterator over whitespace separated tokens of data sections
+struct Tokens<'a> {
+    lines: std::str::Lines<'a>,
+    current: std::str::SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            lines: content.lines(),
+            current: "".split_whitespace(),
+        }
+    }
+
+    /// Returns next line, skipping remaining tokens of the current one
+    fn next_line(&mut self) -> Option<&'a str> {
+        self.current = "".split_whitespace();
+        self.lines.next()
+    }
+
+    fn next_number<T: std::str::FromStr>(&mut self, what: &str) -> Result<T, TsplibError> {
+        loop {
+            if let Some(token) = self.current.next() {
+                return token
+                    .parse()
+                    .map_err(|_| parse_err(format!("invalid {what}: {token}")));
+            }
+            match self.lines.next() {
+                Some(line) => self.current = line.split_whitespace(),
+                None => return Err(parse_err(format!("expected {what}"))),
+            }
+        }
+    }
+}
+
+/// Reads instance from `.tsp` or `.atsp` file
+///
+/// ## Arguments
+/// * `path` - path to the file
+pub fn read_instance<P: AsRef<Path>>(path: P) -> Result<TsplibInstance, TsplibError> {
+    parse_instance(&std::fs::read_to_string(path)?)
+}
+
+/// Parses instance in TSPLIB95 format
+///
+/// ## Arguments
+/// * `content` - content of `.tsp` or `.atsp` file
+pub fn parse_instance(content: &str) -> Result<TsplibInstance, TsplibError> {
+    let mut name = String::new();
+    let mut comment = None;
+    let mut symmetric = true;
+    let mut dimension: Option<usize> = None;
+    let mut edge_weight_type: Option<EdgeWeightType> = None;
+    let mut edge_weight_format = String::from("FULL_MATRIX");
+    let mut coordinates: Vec<(f64, f64)> = Vec::new();
+    let mut explicit: Vec<f64> = Vec::new();
+
+    let mut tokens = Tokens::new(content);
+    while let Some(line) = tokens.next_line() {
+        let line = line.trim();
+        if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim();
+            match key.trim() {
+                "NAME" => name = value.to_owned(),
+                "COMMENT" => comment = Some(value.to_owned()),
+                "TYPE" => match value {
+                    "TSP" => symmetric = true,
+                    "ATSP" => symmetric = false,
+                    _ => return Err(parse_err(format!("unsupported type {value}"))),
+                },
+                "DIMENSION" => dimension = Some(value.parse().map_err(|_| parse_err("invalid DIMENSION"))?),
+                "EDGE_WEIGHT_TYPE" => edge_weight_type = Some(EdgeWeightType::parse(value)?),
+                "EDGE_WEIGHT_FORMAT" => edge_weight_format = value.to_owned(),
+                _ => {}
+            }
+            continue;
+        }
+
+        let dimension = dimension.ok_or_else(|| parse_err("DIMENSION must precede data sections"))?;
+        match line {
+            "NODE_COORD_SECTION" => {
+                for _ in 0..dimension {
+                    tokens.next_number::<usize>("node number")?;
+                    let x = tokens.next_number("x coordinate")?;
+                    let y = tokens.next_number("y coordinate")?;
+                    coordinates.push((x, y));
+                }
+            }
+            "EDGE_WEIGHT_SECTION" => {
+                let count = explicit_weights_count(&edge_weight_format, dimension)?;
+                for _ in 0..count {
+                    explicit.push(tokens.next_number("edge weight")?);
+                }
+            }
+            "EOF" => break,
+            _ => {}
+        }
+    }
+
+    let dimension = dimension.ok_or_else(|| parse_err("missing DIMENSION"))?;
+    let edge_weight_type = edge_weight_type.ok_or_else(|| parse_err("missing EDGE_WEIGHT_TYPE"))?;
+    let weights = if edge_weight_type == EdgeWeightType::Explicit {
+        if explicit.is_empty() {
+            return Err(parse_err("missing EDGE_WEIGHT_SECTION"));
+        }
+        explicit_matrix(&edge_weight_format, dimension, &explicit)?
+    } else {
+        if coordinates.len() != dimension {
+            return Err(parse_err("missing NODE_COORD_SECTION"));
+        }
+        distance_matrix(&coordinates, edge_weight_type)
+    };
+
+    Ok(TsplibInstance {
+        name,
+        comment,
+        dimension,
+        symmetric,
+        edge_weight_type,
+        coordinates,
+        weights,
+    })
+}
+
+/// Returns number of weights listed in given format
+fn explicit_weights_count(format: &str, n: usize) -> Result<usize, TsplibError> {
+    match format {
+        "FULL_MATRIX" => Ok(n * n),
+        "UPPER_ROW" | "LOWER_ROW" | "UPPER_COL" | "LOWER_COL" => Ok(n * (n - 1) / 2),
+        "UPPER_DIAG_ROW" | "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" | "LOWER_DIAG_COL" => Ok(n * (n + 1) / 2),
+        _ => Err(parse_err(format!("unsupported edge weight format {format}"))),
+    }
+}
+
+/// Builds weight matrix from weights listed in given format
+fn explicit_matrix(format: &str, n: usize, weights: &[f64]) -> Result<FMatrix, TsplibError> {
+    if format == "FULL_MATRIX" {
+        return Ok(FMatrix::from_row_slice(n, n, weights));
+    }
+
+    // Column-wise formats list the same entries as row-wise formats of the opposite triangle
+    let (upper, diagonal) = match format {
+        "UPPER_ROW" | "LOWER_COL" => (true, false),
+        "LOWER_ROW" | "UPPER_COL" => (false, false),
+        "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => (true, true),
+        "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => (false, true),
+        _ => return Err(parse_err(format!("unsupported edge weight format {format}"))),
+    };
+
+    let mut matrix = FMatrix::zeros(n, n);
+    let mut values = weights.iter();
+    for i in 0..n {
+        let columns = match (upper, diagonal) {
+            (true, false) => i + 1..n,
+            (true, true) => i..n,
+            (false, false) => 0..i,
+            (false, true) => 0..i + 1,
+        };
+        for j in columns {
+            let w = *values.next().ok_or_else(|| parse_err("too few edge weights"))?;
+            matrix[(i, j)] = w;
+            matrix[(j, i)] = w;
+        }
+    }
+    Ok(matrix)
+}
+
+/// Reads tour from `.tour` (or `.opt.tour`) file
+///
+/// ## Arguments
+/// * `path` - path to the file
+pub fn read_tour<P: AsRef<Path>>(path: P) -> Result<Vec<usize>, TsplibError> {
+    parse_tour(&std::fs::read_to_string(path)?)
+}
+
+/// Parses tour in TSPLIB95 format. Returned nodes are numbered from 0.
+///
+/// ## Arguments
+/// * `content` - content of `.tour` file
+pub fn parse_tour(content: &str) -> Result<Vec<usize>, TsplibError> {
+    let section = content
+        .split_once("TOUR_SECTION")
+        .ok_or_else(|| parse_err("missing TOUR_SECTION"))?
+        .1;
+
+    let mut tour = Vec::new();
+    for token in section.split_whitespace() {
+        let node: i64 = token
+            .parse()
+            .map_err(|_| parse_err(format!("invalid node: {token}")))?;
+        if node == -1 {
+            return Ok(tour);
+        }
+        if node < 1 {
+            return Err(parse_err(format!("invalid node: {token}")));
+        }
+        tour.push(node as usize - 1);
+    }
+    Err(parse_err("TOUR_SECTION is not terminated with -1"))
+}
+
+/// Returns tour in TSPLIB95 format
+///
+/// ## Arguments
+/// * `name` - name of the tour
+/// * `tour` - nodes in order of visiting, numbered from 0
+pub fn format_tour(name: &str, tour: &[usize]) -> String {
+    let mut content = format!(
+        "NAME : {name}\nTYPE : TOUR\nDIMENSION : {}\nTOUR_SECTION\n",
+        tour.len()
+    );
+    for node in tour {
+        content.push_str(&format!("{}\n", node + 1));
+    }
+    content.push_str("-1\nEOF\n");
+    content
+}
+
+/// Writes tour to `.tour` file
+///
+/// ## Arguments
+/// * `path` - path to the file
+/// * `name` - name of the tour
+/// * `tour` - nodes in order of visiting, numbered from 0
+pub fn write_tour<P: AsRef<Path>>(path: P, name: &str, tour: &[usize]) -> Result<(), TsplibError> {
+    std::fs::write(path, format_tour(name, tour))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::tsp::tsplib::{format_tour, parse_instance, parse_tour, EdgeWeightType};
+
+    fn instance_with(header: &str, data: &str) -> String {
+        format!("NAME : test\nTYPE : TSP\nDIMENSION : 3\n{header}\n{data}\nEOF\n")
+    }
+
+    #[test]
+    fn euclidean_instance_is_parsed() {
+        let content = instance_with(
+            "EDGE_WEIGHT_TYPE : EUC_2D",
+            "NODE_COORD_SECTION\n1 0 0\n2 3 4\n3 0 4.6",
+        );
+        let instance = parse_instance(&content).unwrap();
+
+        assert_eq!(instance.dimension, 3);
+        assert_eq!(instance.weights[(0, 1)], 5.0);
+        assert_eq!(instance.weights[(0, 2)], 5.0);
+        assert_eq!(instance.weights[(2, 1)], 3.0);
+        assert_eq!(instance.tour_cost(&[0, 1, 2]), 13.0);
+        assert_eq!(instance.heuristic()[(0, 1)], 0.2);
+    }
+
+    #[test]
+    fn distance_functions_follow_tsplib() {
+        assert_eq!(EdgeWeightType::Ceil2d.distance((0.0, 0.0), (1.0, 1.0)), 2.0);
+        // sqrt(100 / 10) = 3.16.. -> 4
+        assert_eq!(EdgeWeightType::Att.distance((0.0, 0.0), (6.0, 8.0)), 4.0);
+        // Example from TSPLIB FAQ: 1 degree of latitude on the equator
+        assert_eq!(EdgeWeightType::Geo.distance((0.0, 0.0), (1.0, 0.0)), 112.0);
+    }
+
+    #[test]
+    fn explicit_formats_are_parsed() {
+        let full = "EDGE_WEIGHT_SECTION\n0 1 2\n1 0 3\n2 3 0";
+        let upper = "EDGE_WEIGHT_SECTION\n1 2\n3";
+        let lower_diag = "EDGE_WEIGHT_SECTION\n0 1 0 2 3 0";
+
+        for (format, data) in [
+            ("FULL_MATRIX", full),
+            ("UPPER_ROW", upper),
+            ("LOWER_COL", upper),
+            ("LOWER_DIAG_ROW", lower_diag),
+        ] {
+            let header = format!("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : {format}");
+            let instance = parse_instance(&instance_with(&header, data)).unwrap();
+            assert_eq!(instance.weights[(0, 1)], 1.0, "{format}");
+            assert_eq!(instance.weights[(2, 0)], 2.0, "{format}");
+            assert_eq!(instance.weights[(1, 2)], 3.0, "{format}");
+        }
+    }
+
+    #[test]
+    fn tour_is_written_and_read_back() {
+        let content = format_tour("test", &[2, 0, 1]);
+
+        assert!(content.starts_with("NAME : test\nTYPE : TOUR\nDIMENSION : 3\nTOUR_SECTION\n3\n1\n2\n-1"));
+        assert_eq!(parse_tour(&content).unwrap(), vec![2, 0, 1]);
+        assert!(parse_tour("TOUR_SECTION\n1\n2\n").is_err());
+    }
+
+    #[test]
+    fn gap_to_optimal_tour_is_reported() {
+        let content = instance_with(
+            "EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_ROW",
+            "EDGE_WEIGHT_SECTION\n1 2 3",
+        );
+        let mut instance = parse_instance(&content).unwrap();
+        instance.weights[(0, 2)] = 10.0;
+
+        // 0 -> 2 costs 10 in this direction only
+        assert_eq!(instance.gap(&[0, 2, 1], &[0, 1, 2]), 8.0 / 6.0);
+    }
+}
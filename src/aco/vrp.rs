@@ -0,0 +1,249 @@
+//! Implementations of aco traits helpful in creating a Capacitated Vehicle Routing Problem (CVRP) solver.
+//!
+//! Solutions are built by [GraphColony](crate::aco::graph::GraphColony) on [CvrpGraph]. Solution path
+//! starts in the depot & every subsequent visit of the depot starts a new route, e.g. `[0, 3, 1, 0, 2]`
+//! with depot `0` consists of routes `[3, 1]` & `[2]`. Return from the last customer to the depot is implicit,
+//! as the path is closed (see [Topology::TOUR]).
+//!
+//! Instances in CVRPLIB format can be read with [cvrplib] module.
+//!
+//! ```
+//! use ecrs::aco::goodness::CanonicalGoodness;
+//! use ecrs::aco::graph::{ConstructionGraph, GraphAntSystemPU, GraphColony};
+//! use ecrs::aco::tsp::util::create_heuristic_from_weights;
+//! use ecrs::aco::vrp::{CvrpGrader, CvrpGraph};
+//! use ecrs::aco::{Builder, FMatrix};
+//!
+//! let distances = FMatrix::from_row_slice(4, 4, &[
+//!     0.0, 2.0, 2.0, 3.0,
+//!     2.0, 0.0, 1.0, 3.0,
+//!     2.0, 1.0, 0.0, 2.0,
+//!     3.0, 3.0, 2.0, 0.0,
+//! ]);
+//! let graph = CvrpGraph::new(vec![0.0, 4.0, 3.0, 5.0], 8.0, 0);
+//! let topology = graph.topology();
+//! let goodness = CanonicalGoodness::new(1.0, 2.0, create_heuristic_from_weights(&distances));
+//!
+//! let aco = Builder::new()
+//!     .set_colony(GraphColony::new(graph, goodness, 10))
+//!     .set_pheromone_update(GraphAntSystemPU::new(0.1, topology))
+//!     .set_grader(CvrpGrader::new(distances, 0).with_vehicle_penalty(10.0))
+//!     .set_start_pheromone(FMatrix::repeat(4, 4, 1.0))
+//!     .with_iteration_termination(20)
+//!     .with_stdout_probe()
+//!     .build();
+//!
+//! aco.run();
+//! ```
+
+pub mod cvrplib;
+
+use crate::aco::grader::Grader;
+use crate::aco::graph::{ConstructionGraph, Topology};
+use crate::aco::{AdditionalArgs, FMatrix, Solution};
+
+/// Splits solution path into routes, i.e. sequences of customers served by single vehicle.
+/// Empty routes are skipped.
+///
+/// ## Arguments
+/// * `path` - solution path
+/// * `depot` - depot node
+pub fn routes(path: &[usize], depot: usize) -> Vec<Vec<usize>> {
+    path.split(|v| *v == depot)
+        .filter(|route| !route.is_empty())
+        .map(|route| route.to_vec())
+        .collect()
+}
+
+/// # CVRP Graph
+///
+/// Implements [ConstructionGraph].
+///
+/// Every ant starts in the depot. Ant may go to an unvisited customer as long as the load of the
+/// current route together with customer demand does not exceed vehicle capacity, or return to the depot
+/// (which starts a new route) unless it is already there. Solution is complete when every customer is served.
+pub struct CvrpGraph {
+    demands: Vec<f64>,
+    capacity: f64,
+    depot: usize,
+}
+
+impl CvrpGraph {
+    /// Creates a new instance of [CvrpGraph].
+    ///
+    /// ## Arguments
+    /// * `demands` - demand of every node, demand of the depot is ignored.
+    /// * `capacity` - vehicle capacity, must not be smaller than any demand.
+    /// * `depot` - depot node.
+    pub fn new(demands: Vec<f64>, capacity: f64, depot: usize) -> Self {
+        assert!(depot < demands.len(), "Depot out of range");
+        assert!(
+            demands
+                .iter()
+                .enumerate()
+                .all(|(v, d)| v == depot || (0.0..=capacity).contains(d)),
+            "Every demand must be in range [0, capacity]"
+        );
+        Self {
+            demands,
+            capacity,
+            depot,
+        }
+    }
+
+    /// Returns load of the last route of `path`
+    fn route_load(&self, path: &[usize]) -> f64 {
+        path.iter()
+            .rev()
+            .take_while(|v| **v != self.depot)
+            .map(|v| self.demands[*v])
+            .sum()
+    }
+}
+
+impl ConstructionGraph for CvrpGraph {
+    fn size(&self) -> usize {
+        self.demands.len()
+    }
+
+    fn start_node(&self) -> Option<usize> {
+        Some(self.depot)
+    }
+
+    fn is_feasible(&self, path: &[usize], visited: &[bool], next: usize) -> bool {
+        if next == self.depot {
+            return path.last() != Some(&self.depot);
+        }
+        !visited[next] && self.route_load(path) + self.demands[next] <= self.capacity
+    }
+
+    fn is_complete(&self, path: &[usize]) -> bool {
+        path.iter().filter(|v| **v != self.depot).count() == self.demands.len() - 1
+    }
+
+    fn topology(&self) -> Topology {
+        Topology::TOUR
+    }
+}
+
+/// # CVRP Grader
+///
+/// Implements [Grader]. Calculates fitness as 1.0 / (total_distance + vehicle_penalty * vehicles_count).
+pub struct CvrpGrader {
+    distances: FMatrix,
+    depot: usize,
+    vehicle_penalty: f64,
+}
+
+impl CvrpGrader {
+    /// Creates a new instance of [CvrpGrader] without vehicle penalty.
+    ///
+    /// ## Arguments
+    /// * `distances` - distances between nodes
+    /// * `depot` - depot node
+    pub fn new(distances: FMatrix, depot: usize) -> Self {
+        Self {
+            distances,
+            depot,
+            vehicle_penalty: 0.0,
+        }
+    }
+
+    /// Sets cost of using single vehicle.
+    ///
+    /// ## Arguments
+    /// * `vehicle_penalty` - cost added for every route
+    pub fn with_vehicle_penalty(mut self, vehicle_penalty: f64) -> Self {
+        self.vehicle_penalty = vehicle_penalty;
+        self
+    }
+
+    /// Returns total distance travelled by all vehicles
+    ///
+    /// ## Arguments
+    /// * `path` - solution path
+    pub fn total_distance(&self, path: &[usize]) -> f64 {
+        Topology::TOUR
+            .edges(path)
+            .into_iter()
+            .map(|edge| self.distances[edge])
+            .sum()
+    }
+
+    fn grade_solution(&self, sol: &mut Solution) {
+        let vehicles = routes(&sol.path, self.depot).len() as f64;
+        sol.fitness = 1.0 / (self.total_distance(&sol.path) + self.vehicle_penalty * vehicles);
+    }
+}
+
+impl<Args: AdditionalArgs> Grader<Args> for CvrpGrader {
+    fn apply(&mut self, sols: &mut [Solution], _: &Args) {
+        sols.iter_mut().for_each(|sol| self.grade_solution(sol))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::colony::Colony;
+    use crate::aco::goodness::CanonicalGoodness;
+    use crate::aco::grader::Grader;
+    use crate::aco::graph::{ConstructionGraph, GraphColony};
+    use crate::aco::vrp::{routes, CvrpGrader, CvrpGraph};
+    use crate::aco::{FMatrix, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn routes_are_split_on_depot() {
+        assert_eq!(routes(&[0, 3, 1, 0, 2], 0), vec![vec![3, 1], vec![2]]);
+        assert_eq!(routes(&[2, 1, 2, 0], 2), vec![vec![1], vec![0]]);
+    }
+
+    #[test]
+    fn cvrp_graph_respects_capacity() {
+        let graph = CvrpGraph::new(vec![0.0, 4.0, 3.0, 5.0], 8.0, 0);
+        let mut visited = vec![true, true, false, false];
+
+        assert!(graph.is_feasible(&[0, 1], &visited, 2));
+        assert!(!graph.is_feasible(&[0, 1], &visited, 3));
+        assert!(graph.is_feasible(&[0, 1], &visited, 0));
+        assert!(!graph.is_feasible(&[0], &visited, 0));
+
+        visited[2] = true;
+        assert!(graph.is_feasible(&[0, 1, 2, 0], &visited, 3));
+        assert!(!graph.is_complete(&[0, 1, 2, 0]));
+        assert!(graph.is_complete(&[0, 1, 2, 0, 3]));
+    }
+
+    #[test]
+    fn colony_builds_feasible_multi_route_solutions() {
+        let demands = vec![0.0, 4.0, 3.0, 5.0, 6.0, 2.0];
+        let graph = CvrpGraph::new(demands.clone(), 8.0, 0);
+        let goodness = CanonicalGoodness::default(6);
+        let mut colony = GraphColony::with_rng(graph, goodness, 20, StdRng::seed_from_u64(3));
+
+        let paths = colony.build_solutions(&mut FMatrix::repeat(6, 6, 1.0), &());
+
+        assert_eq!(paths.len(), 20);
+        for path in paths {
+            let routes = routes(&path, 0);
+            assert!(routes.len() >= 3);
+            assert_eq!(routes.iter().map(Vec::len).sum::<usize>(), 5);
+            for route in routes {
+                assert!(route.iter().map(|v| demands[*v]).sum::<f64>() <= 8.0);
+            }
+        }
+    }
+
+    #[test]
+    fn grader_adds_vehicle_penalty() {
+        let distances = FMatrix::from_row_slice(3, 3, &[0.0, 1.0, 2.0, 1.0, 0.0, 4.0, 2.0, 4.0, 0.0]);
+        let mut grader = CvrpGrader::new(distances, 0).with_vehicle_penalty(0.5);
+        let mut sols = [Solution::from_path(vec![0, 1, 0, 2])];
+
+        grader.apply(&mut sols, &());
+
+        // 0 -> 1 -> 0 -> 2 -> 0
+        assert_eq!(sols[0].fitness, 1.0 / (6.0 + 1.0));
+    }
+}
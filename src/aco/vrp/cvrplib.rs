@@ -0,0 +1,262 @@
+//! Reader of CVRPLIB (<http://vrp.galgos.inf.puc-rio.br>) instances & solutions.
+//!
+//! Instances (`.vrp` files) in TSPLIB format with `EUC_2D` edge weights are supported. Distances are
+//! rounded to the nearest integer, as in CVRPLIB, so that costs are comparable with known optima.
+//! Node numbers are shifted to start from 0.
+
+use crate::aco::tsp::tsplib::{distance_matrix, EdgeWeightType};
+use crate::aco::vrp::{CvrpGrader, CvrpGraph};
+use crate::aco::FMatrix;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+
+/// Error returned when instance or solution can not be read
+#[derive(Debug)]
+pub enum CvrplibError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl Display for CvrplibError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read file: {err}"),
+            Self::Parse(msg) => write!(f, "Invalid CVRPLIB data: {msg}"),
+        }
+    }
+}
+
+impl Error for CvrplibError {}
+
+impl From<std::io::Error> for CvrplibError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// CVRP instance
+#[derive(Debug, Clone)]
+pub struct CvrpInstance {
+    pub name: String,
+    /// Usually contains number of trucks & optimal value
+    pub comment: Option<String>,
+    pub capacity: f64,
+    pub depot: usize,
+    pub coordinates: Vec<(f64, f64)>,
+    pub demands: Vec<f64>,
+    pub distances: FMatrix,
+}
+
+impl CvrpInstance {
+    /// Returns number of nodes, including depot
+    pub fn dimension(&self) -> usize {
+        self.demands.len()
+    }
+
+    /// Returns construction graph of the instance
+    pub fn graph(&self) -> CvrpGraph {
+        CvrpGraph::new(self.demands.clone(), self.capacity, self.depot)
+    }
+
+    /// Returns grader of the instance, without vehicle penalty
+    pub fn grader(&self) -> CvrpGrader {
+        CvrpGrader::new(self.distances.clone(), self.depot)
+    }
+}
+
+/// CVRP solution, as published in CVRPLIB `.sol` files
+#[derive(Debug, Clone, PartialEq)]
+pub struct CvrpSolution {
+    /// Customers served by every vehicle, depot (node 0) is omitted
+    pub routes: Vec<Vec<usize>>,
+    pub cost: f64,
+}
+
+fn parse_err(msg: impl Into<String>) -> CvrplibError {
+    CvrplibError::Parse(msg.into())
+}
+
+fn parse_number<T: std::str::FromStr>(token: Option<&str>, what: &str) -> Result<T, CvrplibError> {
+    token
+        .and_then(|t| t.parse().ok())
+        .ok_or_else(|| parse_err(format!("expected {what}")))
+}
+
+/// Reads CVRP instance from `.vrp` file
+///
+/// ## Arguments
+/// * `path` - path to the file
+pub fn read_instance<P: AsRef<Path>>(path: P) -> Result<CvrpInstance, CvrplibError> {
+    parse_instance(&std::fs::read_to_string(path)?)
+}
+
+/// Parses CVRP instance in CVRPLIB format
+///
+/// ## Arguments
+/// * `content` - content of `.vrp` file
+pub fn parse_instance(content: &str) -> Result<CvrpInstance, CvrplibError> {
+    let mut name = String::new();
+    let mut comment = None;
+    let mut dimension: Option<usize> = None;
+    let mut capacity: Option<f64> = None;
+    let mut coordinates: Vec<(f64, f64)> = Vec::new();
+    let mut demands: Vec<f64> = Vec::new();
+    let mut depot: Option<usize> = None;
+
+    let mut lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+    while let Some(line) = lines.next() {
+        if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim();
+            match key.trim() {
+                "NAME" => name = value.to_owned(),
+                "COMMENT" => comment = Some(value.to_owned()),
+                "TYPE" if value != "CVRP" => return Err(parse_err(format!("unsupported type {value}"))),
+                "DIMENSION" => dimension = Some(parse_number(Some(value), "dimension")?),
+                "CAPACITY" => capacity = Some(parse_number(Some(value), "capacity")?),
+                "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                    return Err(parse_err(format!("unsupported edge weight type {value}")))
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let dimension = dimension.ok_or_else(|| parse_err("DIMENSION must precede data sections"))?;
+        match line {
+            "NODE_COORD_SECTION" => {
+                for _ in 0..dimension {
+                    let mut tokens = lines.next().unwrap_or_default().split_whitespace().skip(1);
+                    let x = parse_number(tokens.next(), "x coordinate")?;
+                    let y = parse_number(tokens.next(), "y coordinate")?;
+                    coordinates.push((x, y));
+                }
+            }
+            "DEMAND_SECTION" => {
+                for _ in 0..dimension {
+                    let mut tokens = lines.next().unwrap_or_default().split_whitespace().skip(1);
+                    demands.push(parse_number(tokens.next(), "demand")?);
+                }
+            }
+            "DEPOT_SECTION" => {
+                let node: usize = parse_number(lines.next(), "depot")?;
+                if node == 0 || node > dimension {
+                    return Err(parse_err("depot out of range"));
+                }
+                depot = Some(node - 1);
+            }
+            "EOF" => break,
+            _ => {}
+        }
+    }
+
+    let capacity = capacity.ok_or_else(|| parse_err("missing CAPACITY"))?;
+    let depot = depot.ok_or_else(|| parse_err("missing DEPOT_SECTION"))?;
+    if coordinates.is_empty() || coordinates.len() != demands.len() {
+        return Err(parse_err("missing NODE_COORD_SECTION or DEMAND_SECTION"));
+    }
+
+    let distances = distance_matrix(&coordinates, EdgeWeightType::Euc2d);
+
+    Ok(CvrpInstance {
+        name,
+        comment,
+        capacity,
+        depot,
+        coordinates,
+        demands,
+        distances,
+    })
+}
+
+/// Reads CVRP solution from `.sol` file
+///
+/// ## Arguments
+/// * `path` - path to the file
+pub fn read_solution<P: AsRef<Path>>(path: P) -> Result<CvrpSolution, CvrplibError> {
+    parse_solution(&std::fs::read_to_string(path)?)
+}
+
+/// Parses CVRP solution in CVRPLIB format, i.e. lines `Route #k: customers...` followed by `Cost value`
+///
+/// ## Arguments
+/// * `content` - content of `.sol` file
+pub fn parse_solution(content: &str) -> Result<CvrpSolution, CvrplibError> {
+    let mut routes = Vec::new();
+    let mut cost = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(route) = line.strip_prefix("Route") {
+            let (_, customers) = route
+                .split_once(':')
+                .ok_or_else(|| parse_err(format!("invalid route {line}")))?;
+            let route = customers
+                .split_whitespace()
+                .map(|c| parse_number(Some(c), "customer"))
+                .collect::<Result<Vec<usize>, _>>()?;
+            routes.push(route);
+        } else if let Some(value) = line.strip_prefix("Cost") {
+            cost = Some(parse_number(Some(value.trim()), "cost")?);
+        }
+    }
+
+    Ok(CvrpSolution {
+        routes,
+        cost: cost.ok_or_else(|| parse_err("missing Cost"))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aco::vrp::cvrplib::{parse_instance, parse_solution, CvrplibError};
+
+    const INSTANCE: &str = "NAME : toy-n4-k2
+COMMENT : (No of trucks: 2, Optimal value: 20)
+TYPE : CVRP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+CAPACITY : 10
+NODE_COORD_SECTION
+ 1 0 0
+ 2 3 4
+ 3 0 5
+ 4 -3 0
+DEMAND_SECTION
+1 0
+2 6
+3 3
+4 7
+DEPOT_SECTION
+ 1
+ -1
+EOF
+";
+
+    #[test]
+    fn instance_is_parsed() {
+        let instance = parse_instance(INSTANCE).unwrap();
+
+        assert_eq!(instance.name, "toy-n4-k2");
+        assert_eq!(instance.dimension(), 4);
+        assert_eq!(instance.capacity, 10.0);
+        assert_eq!(instance.depot, 0);
+        assert_eq!(instance.demands, vec![0.0, 6.0, 3.0, 7.0]);
+        assert_eq!(instance.distances[(0, 1)], 5.0);
+        assert_eq!(instance.distances[(1, 2)], 3.0);
+        assert_eq!(instance.distances[(2, 1)], 3.0);
+    }
+
+    #[test]
+    fn unsupported_edge_weight_type_is_rejected() {
+        let content = INSTANCE.replace("EUC_2D", "GEO");
+        assert!(matches!(parse_instance(&content), Err(CvrplibError::Parse(_))));
+    }
+
+    #[test]
+    fn solution_is_parsed() {
+        let solution = parse_solution("Route #1: 1 2\nRoute #2: 3\nCost 20\n").unwrap();
+
+        assert_eq!(solution.routes, vec![vec![1, 2], vec![3]]);
+        assert_eq!(solution.cost, 20.0);
+    }
+}
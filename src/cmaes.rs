@@ -0,0 +1,449 @@
+//! Implementation of CMA-ES algorithm
+//!
+//! (μ/μ_w, λ)-CMA-ES (Covariance Matrix Adaptation Evolution Strategy) with cumulative step-size adaptation,
+//! as described in N. Hansen, "The CMA Evolution Strategy: A Tutorial". The objective is minimised.
+//!
+//! Single run of the strategy stops once any of local termination criteria (see [LocalTermination]) is met.
+//! The algorithm can then be restarted with increasing population size ([RestartStrategy::Ipop], A. Auger & N. Hansen)
+//! or alternating between large & small populations ([RestartStrategy::Bipop], N. Hansen). The whole
+//! optimisation stops when [TerminationCondition] is met.
+//!
+//! # Usage example:
+//! ```rust
+//! # use ecrs::cmaes::{builder::CmaesAlgorithmBuilder, probe::empty_probe::EmptyProbe, RestartStrategy};
+//! let mut algorithm = CmaesAlgorithmBuilder::new()
+//!     .set_dimensions(4)
+//!     .set_function(|x: &[f64]| x.iter().map(|xi| xi * xi).sum())
+//!     .set_restart_strategy(RestartStrategy::Ipop { increase_factor: 2.0 })
+//!     .set_evaluation_limit(10_000)
+//!     .set_seed(42)
+//!     .set_probe(Box::new(EmptyProbe::new()))
+//!     .build();
+//! algorithm.run();
+//! assert!(algorithm.state().best_fitness < 1e-8);
+//! ```
+
+pub mod builder;
+mod strategy;
+
+pub use crate::optimizer::{probe, termination_condition};
+
+use std::time::Instant;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::cmaes::probe::stdout_probe::StdoutProbe;
+use crate::cmaes::probe::Probe;
+use crate::cmaes::strategy::Strategy;
+use crate::cmaes::termination_condition::{GenerationLimit, TerminationCondition};
+use crate::optimizer::OptimizerState;
+
+/// Strategy of restarting the algorithm once a single run stops
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RestartStrategy {
+    /// Algorithm is not restarted
+    None,
+    /// Each restart multiplies population size by `increase_factor` (usually 2)
+    Ipop { increase_factor: f64 },
+    /// Restarts alternate between regime of doubling population size & regime of small populations
+    /// with smaller, randomly drawn initial step size. Regime which used less evaluations so far is chosen.
+    Bipop,
+}
+
+/// Reason of stopping a single run of the strategy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalTermination {
+    /// Range of best fitness values of recent generations is below `tol_fun`
+    TolFun,
+    /// Standard deviation & evolution path are below `tol_x` in every coordinate
+    TolX,
+    /// Condition number of covariance matrix exceeds 1e14
+    ConditionCov,
+    /// Adding 0.1 standard deviation along principal axis does not change the mean
+    NoEffectAxis,
+    /// Adding 0.2 standard deviation in any coordinate does not change the mean
+    NoEffectCoord,
+    /// Run exceeded `100 + 50 * (n + 3)^2 / sqrt(λ)` generations
+    MaxIterations,
+    /// Step size or covariance matrix degenerated
+    NumericalError,
+}
+
+/// Struct wrapping all configuration parameters of CMA-ES algorithm.
+/// # Parameters:
+///  - dimensions - number of dimension of optimized function's domain
+///  - lower_bound - lower bound of search area in every dimension; initial means are drawn uniformly from search area
+///  - upper_bound - upper bound of search area in every dimension
+///  - initial_mean - mean of the first run, drawn uniformly from search area if not given
+///  - initial_sigma - initial step size, `0.3 * (upper_bound - lower_bound)` if not given
+///  - population_size - population size of the first run, `4 + floor(3 * ln(dimensions))` if not given
+///  - restart_strategy - used for restarting the strategy once local termination criterion is met
+///  - tol_fun - tolerance of fitness values used by [LocalTermination::TolFun]
+///  - tol_x - tolerance of step size used by [LocalTermination::TolX]
+///  - seed - seed of random number generator, random if not given
+///  - termination_condition - used for determining stopping point of the algorithm
+///  - probe - used for displaying results / progress of the algorithm
+///
+/// Sampled points are not restricted to the search area.
+pub struct CmaesAlgorithmCfg {
+    dimensions: usize,
+    lower_bound: f64,
+    upper_bound: f64,
+    initial_mean: Option<Vec<f64>>,
+    initial_sigma: Option<f64>,
+    population_size: Option<usize>,
+    restart_strategy: RestartStrategy,
+    tol_fun: f64,
+    tol_x: f64,
+    seed: Option<u64>,
+    termination_condition: Box<dyn TerminationCondition<CmaesState>>,
+    probe: Box<dyn Probe<CmaesState>>,
+}
+
+impl Default for CmaesAlgorithmCfg {
+    fn default() -> Self {
+        CmaesAlgorithmCfg {
+            dimensions: 2,
+            lower_bound: -5.0,
+            upper_bound: 5.0,
+            initial_mean: None,
+            initial_sigma: None,
+            population_size: None,
+            restart_strategy: RestartStrategy::None,
+            tol_fun: 1e-12,
+            tol_x: 1e-12,
+            seed: None,
+            termination_condition: Box::new(GenerationLimit::new(1000)),
+            probe: Box::new(StdoutProbe::new()),
+        }
+    }
+}
+
+/// Observable state of CMA-ES algorithm, passed to probes & termination conditions
+#[derive(Clone, Debug)]
+pub struct CmaesState {
+    /// Mean of the current distribution
+    pub mean: Vec<f64>,
+    /// Current step size
+    pub sigma: f64,
+    /// Population size of the current run
+    pub population_size: usize,
+    /// Number of generations, summed over all runs
+    pub generation: usize,
+    /// Number of objective function evaluations, summed over all runs
+    pub evaluations: usize,
+    /// Number of restarts done so far
+    pub restarts: usize,
+    /// Reason of stopping the last run, `None` before the first restart
+    pub last_local_termination: Option<LocalTermination>,
+    /// Best fitness in the last generation
+    pub generation_best_fitness: f64,
+    /// Best solution found so far
+    pub best_solution: Vec<f64>,
+    /// Fitness of the best solution found so far
+    pub best_fitness: f64,
+    /// Start time of the algorithm
+    pub start_time: Instant,
+}
+
+impl CmaesState {
+    fn new(dimensions: usize) -> Self {
+        CmaesState {
+            mean: vec![0.0; dimensions],
+            sigma: 0.0,
+            population_size: 0,
+            generation: 0,
+            evaluations: 0,
+            restarts: 0,
+            last_local_termination: None,
+            generation_best_fitness: f64::INFINITY,
+            best_solution: vec![0.0; dimensions],
+            best_fitness: f64::INFINITY,
+            start_time: Instant::now(),
+        }
+    }
+}
+
+impl OptimizerState for CmaesState {
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    fn best_fitness(&self) -> f64 {
+        self.best_fitness
+    }
+
+    fn best_solution(&self) -> &[f64] {
+        &self.best_solution
+    }
+
+    fn start_time(&self) -> Instant {
+        self.start_time
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "Generation {}: best value: {}, sigma: {}",
+            self.generation, self.best_fitness, self.sigma
+        )
+    }
+}
+
+/// Struct used for running CMA-ES algorithm, see [module level docs](self) for usage example
+pub struct CmaesAlgorithm<F: FnMut(&[f64]) -> f64> {
+    config: CmaesAlgorithmCfg,
+    function: F,
+    state: CmaesState,
+    rng: StdRng,
+}
+
+impl<F: FnMut(&[f64]) -> f64> CmaesAlgorithm<F> {
+    pub fn new(config: CmaesAlgorithmCfg, function: F) -> Self {
+        assert!(config.dimensions > 0, "Dimensions must be > 0");
+        assert!(
+            config.lower_bound < config.upper_bound,
+            "Lower bound must be smaller than upper bound"
+        );
+        if let Some(mean) = &config.initial_mean {
+            assert_eq!(
+                mean.len(),
+                config.dimensions,
+                "Initial mean must be of given dimension"
+            );
+        }
+
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let state = CmaesState::new(config.dimensions);
+        CmaesAlgorithm {
+            config,
+            function,
+            state,
+            rng,
+        }
+    }
+
+    /// Returns state of the algorithm, e.g. the best solution found
+    pub fn state(&self) -> &CmaesState {
+        &self.state
+    }
+
+    /// Runs the algorithm until termination condition is met
+    pub fn run(&mut self) {
+        self.state = CmaesState::new(self.config.dimensions);
+        self.config.termination_condition.initialize(&self.state);
+        self.config.probe.on_begin(&self.state);
+
+        let n = self.config.dimensions as f64;
+        let default_lambda = self
+            .config
+            .population_size
+            .unwrap_or(4 + (3.0 * n.ln()).floor() as usize);
+        let default_sigma = self
+            .config
+            .initial_sigma
+            .unwrap_or(0.3 * (self.config.upper_bound - self.config.lower_bound));
+
+        // BIPOP bookkeeping: number of large regime restarts & evaluations spent in each regime
+        let mut large_runs = 0;
+        let mut large_budget = 0;
+        let mut small_budget = 0;
+
+        loop {
+            let (lambda, sigma, is_large) = if self.state.restarts == 0 {
+                (default_lambda, default_sigma, true)
+            } else {
+                match self.config.restart_strategy {
+                    RestartStrategy::None => unreachable!(),
+                    RestartStrategy::Ipop { increase_factor } => {
+                        let lambda = default_lambda as f64 * increase_factor.powi(self.state.restarts as i32);
+                        (lambda.round() as usize, default_sigma, true)
+                    }
+                    RestartStrategy::Bipop if small_budget < large_budget => {
+                        let u: f64 = self.rng.gen();
+                        let large_lambda = (default_lambda << large_runs) as f64;
+                        let lambda =
+                            default_lambda as f64 * (0.5 * large_lambda / default_lambda as f64).powf(u * u);
+                        (
+                            lambda.floor() as usize,
+                            default_sigma * 10f64.powf(-2.0 * u),
+                            false,
+                        )
+                    }
+                    RestartStrategy::Bipop => {
+                        large_runs += 1;
+                        (default_lambda << large_runs, default_sigma, true)
+                    }
+                }
+            };
+
+            let mean = match (&self.config.initial_mean, self.state.restarts) {
+                (Some(mean), 0) => mean.clone(),
+                _ => (0..self.config.dimensions)
+                    .map(|_| {
+                        self.rng
+                            .gen_range(self.config.lower_bound..self.config.upper_bound)
+                    })
+                    .collect(),
+            };
+
+            let evaluations_before = self.state.evaluations;
+            let local_termination = self.run_once(Strategy::new(mean, sigma, lambda.max(2)));
+            if is_large {
+                large_budget += self.state.evaluations - evaluations_before;
+            } else {
+                small_budget += self.state.evaluations - evaluations_before;
+            }
+
+            match local_termination {
+                Some(reason) if self.config.restart_strategy != RestartStrategy::None => {
+                    self.state.restarts += 1;
+                    self.state.last_local_termination = Some(reason);
+                    self.config.probe.on_restart(&self.state);
+                }
+                _ => break,
+            }
+        }
+
+        self.config.probe.on_end(&self.state);
+    }
+
+    /// Runs single instance of the strategy. Returns local termination criterion which stopped the run
+    /// or `None` if termination condition of the whole algorithm was met.
+    fn run_once(&mut self, mut strategy: Strategy) -> Option<LocalTermination> {
+        loop {
+            let mut population: Vec<(Vec<f64>, f64)> = strategy
+                .sample(&mut self.rng)
+                .into_iter()
+                .map(|x| {
+                    let value = (self.function)(&x);
+                    (x, value)
+                })
+                .collect();
+            population.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            strategy.update(&population);
+
+            self.state.generation += 1;
+            self.state.evaluations += population.len();
+            self.state.population_size = population.len();
+            self.state.mean = strategy.mean().to_vec();
+            self.state.sigma = strategy.sigma();
+            self.state.generation_best_fitness = population[0].1;
+            if population[0].1 < self.state.best_fitness {
+                self.state.best_fitness = population[0].1;
+                self.state.best_solution = population[0].0.clone();
+            }
+            self.config.probe.on_new_generation(&self.state);
+
+            if self.config.termination_condition.is_met(&self.state) {
+                return None;
+            }
+            if let Some(reason) = strategy.local_termination(self.config.tol_fun, self.config.tol_x) {
+                return Some(reason);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::builder::CmaesAlgorithmBuilder;
+    use super::probe::empty_probe::EmptyProbe;
+    use super::RestartStrategy;
+
+    fn ellipsoid(x: &[f64]) -> f64 {
+        x.iter()
+            .enumerate()
+            .map(|(i, xi)| 10f64.powf(6.0 * i as f64 / (x.len() - 1) as f64) * xi * xi)
+            .sum()
+    }
+
+    fn rastrigin(x: &[f64]) -> f64 {
+        10.0 * x.len() as f64
+            + x.iter()
+                .map(|xi| xi * xi - 10.0 * (2.0 * std::f64::consts::PI * xi).cos())
+                .sum::<f64>()
+    }
+
+    #[test]
+    fn solves_ill_conditioned_problem() {
+        let mut algorithm = CmaesAlgorithmBuilder::new()
+            .set_dimensions(5)
+            .set_function(ellipsoid)
+            .set_evaluation_limit(20_000)
+            .set_seed(7)
+            .set_probe(Box::new(EmptyProbe::new()))
+            .build();
+        algorithm.run();
+
+        assert!(algorithm.state().best_fitness < 1e-8);
+    }
+
+    #[test]
+    fn runs_with_same_seed_are_identical() {
+        let run = || {
+            let mut algorithm = CmaesAlgorithmBuilder::new()
+                .set_dimensions(3)
+                .set_function(rastrigin)
+                .set_generation_limit(50)
+                .set_seed(3)
+                .set_probe(Box::new(EmptyProbe::new()))
+                .build();
+            algorithm.run();
+            algorithm.state().best_solution.clone()
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn restarts_until_termination_condition_is_met() {
+        for restart_strategy in [
+            RestartStrategy::Ipop { increase_factor: 2.0 },
+            RestartStrategy::Bipop,
+        ] {
+            let mut algorithm = CmaesAlgorithmBuilder::new()
+                .set_dimensions(2)
+                .set_function(rastrigin)
+                .set_restart_strategy(restart_strategy)
+                .set_evaluation_limit(50_000)
+                .set_seed(11)
+                .set_probe(Box::new(EmptyProbe::new()))
+                .build();
+            algorithm.run();
+
+            assert!(algorithm.state().restarts > 0);
+            assert!(algorithm.state().evaluations >= 50_000);
+            if let RestartStrategy::Ipop { .. } = restart_strategy {
+                // Default population size for 2 dimensions is 6
+                assert_eq!(algorithm.state().population_size, 6 << algorithm.state().restarts);
+            }
+        }
+    }
+
+    #[test]
+    fn without_restarts_stops_on_local_termination() {
+        let mut algorithm = CmaesAlgorithmBuilder::new()
+            .set_dimensions(2)
+            .set_function(|x: &[f64]| x.iter().map(|xi| xi * xi).sum())
+            .set_evaluation_limit(1_000_000)
+            .set_seed(5)
+            .set_probe(Box::new(EmptyProbe::new()))
+            .build();
+        algorithm.run();
+
+        assert_eq!(algorithm.state().restarts, 0);
+        assert!(algorithm.state().evaluations < 1_000_000);
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::cmaes::probe::Probe;
+use crate::cmaes::termination_condition::{
+    EvaluationLimit, GenerationLimit, TerminationCondition, TimeLimit,
+};
+use crate::cmaes::{CmaesAlgorithm, CmaesAlgorithmCfg, CmaesState, RestartStrategy};
+
+pub struct CmaesAlgorithmBuilder<F: FnMut(&[f64]) -> f64> {
+    config: CmaesAlgorithmCfg,
+    function: Option<F>,
+}
+
+impl<F: FnMut(&[f64]) -> f64> CmaesAlgorithmBuilder<F> {
+    pub fn new() -> Self {
+        CmaesAlgorithmBuilder {
+            config: CmaesAlgorithmCfg::default(),
+            function: None,
+        }
+    }
+
+    pub fn set_dimensions(mut self, dimensions: usize) -> Self {
+        self.config.dimensions = dimensions;
+        self
+    }
+
+    pub fn set_lower_bound(mut self, lower_bound: f64) -> Self {
+        self.config.lower_bound = lower_bound;
+        self
+    }
+
+    pub fn set_upper_bound(mut self, upper_bound: f64) -> Self {
+        self.config.upper_bound = upper_bound;
+        self
+    }
+
+    pub fn set_initial_mean(mut self, initial_mean: Vec<f64>) -> Self {
+        self.config.initial_mean = Some(initial_mean);
+        self
+    }
+
+    pub fn set_initial_sigma(mut self, initial_sigma: f64) -> Self {
+        self.config.initial_sigma = Some(initial_sigma);
+        self
+    }
+
+    pub fn set_population_size(mut self, population_size: usize) -> Self {
+        self.config.population_size = Some(population_size);
+        self
+    }
+
+    pub fn set_restart_strategy(mut self, restart_strategy: RestartStrategy) -> Self {
+        self.config.restart_strategy = restart_strategy;
+        self
+    }
+
+    pub fn set_tol_fun(mut self, tol_fun: f64) -> Self {
+        self.config.tol_fun = tol_fun;
+        self
+    }
+
+    pub fn set_tol_x(mut self, tol_x: f64) -> Self {
+        self.config.tol_x = tol_x;
+        self
+    }
+
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn set_function(mut self, function: F) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    pub fn set_termination_condition(
+        mut self,
+        termination_condition: Box<dyn TerminationCondition<CmaesState>>,
+    ) -> Self {
+        self.config.termination_condition = termination_condition;
+        self
+    }
+
+    pub fn set_generation_limit(mut self, generation_limit: usize) -> Self {
+        self.config.termination_condition = Box::new(GenerationLimit::new(generation_limit));
+        self
+    }
+
+    pub fn set_evaluation_limit(mut self, evaluation_limit: usize) -> Self {
+        self.config.termination_condition = Box::new(EvaluationLimit::new(evaluation_limit));
+        self
+    }
+
+    pub fn set_time_limit(mut self, time_limit: Duration) -> Self {
+        self.config.termination_condition = Box::new(TimeLimit::new(time_limit));
+        self
+    }
+
+    pub fn set_probe(mut self, probe: Box<dyn Probe<CmaesState>>) -> Self {
+        self.config.probe = probe;
+        self
+    }
+
+    pub fn build(self) -> CmaesAlgorithm<F> {
+        let function = self.function.expect("Function must be set");
+        CmaesAlgorithm::new(self.config, function)
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::VecDeque;
+
+use nalgebra::{DMatrix, DVector};
+use rand::Rng;
+
+use crate::cmaes::LocalTermination;
+use crate::util::standard_normal;
+
+/// Threshold of covariance matrix condition number, above which the run is stopped
+const MAX_CONDITION: f64 = 1e14;
+
+/// State of a single (μ/μ_w, λ)-CMA-ES run, i.e. distribution parameters together with
+/// evolution paths & learning rates derived from dimension & population size.
+pub(crate) struct Strategy {
+    dimensions: usize,
+    lambda: usize,
+    weights: Vec<f64>,
+    mueff: f64,
+    cc: f64,
+    cs: f64,
+    c1: f64,
+    cmu: f64,
+    damps: f64,
+    chi_n: f64,
+    mean: DVector<f64>,
+    sigma: f64,
+    pc: DVector<f64>,
+    ps: DVector<f64>,
+    cov: DMatrix<f64>,
+    b: DMatrix<f64>,
+    d: DVector<f64>,
+    eigen_generation: usize,
+    generation: usize,
+    // Best fitness of recent generations, used by TolFun criterion
+    history: VecDeque<f64>,
+    history_len: usize,
+    // Fitness range of the last generation
+    last_range: (f64, f64),
+    numerical_error: bool,
+}
+
+impl Strategy {
+    /// Returns new strategy with default learning rates (see N. Hansen, "The CMA Evolution Strategy: A Tutorial")
+    ///
+    /// ## Arguments
+    ///
+    /// * `mean` - initial mean of the distribution
+    /// * `sigma` - initial step size
+    /// * `lambda` - population size, must be >= 2
+    pub(crate) fn new(mean: Vec<f64>, sigma: f64, lambda: usize) -> Self {
+        assert!(lambda >= 2, "Population size must be >= 2");
+        let dimensions = mean.len();
+        let n = dimensions as f64;
+        let mu = lambda / 2;
+
+        let mut weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let weights_sum: f64 = weights.iter().sum();
+        weights.iter_mut().for_each(|w| *w /= weights_sum);
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cc = (4.0 + mueff / n) / (n + 4.0 + 2.0 * mueff / n);
+        let cs = (mueff + 2.0) / (n + mueff + 5.0);
+        let c1 = 2.0 / ((n + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((n + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        Self {
+            dimensions,
+            lambda,
+            weights,
+            mueff,
+            cc,
+            cs,
+            c1,
+            cmu,
+            damps,
+            chi_n,
+            mean: DVector::from_vec(mean),
+            sigma,
+            pc: DVector::zeros(dimensions),
+            ps: DVector::zeros(dimensions),
+            cov: DMatrix::identity(dimensions, dimensions),
+            b: DMatrix::identity(dimensions, dimensions),
+            d: DVector::from_element(dimensions, 1.0),
+            eigen_generation: 0,
+            generation: 0,
+            history: VecDeque::new(),
+            history_len: 10 + (30.0 * n / lambda as f64).ceil() as usize,
+            last_range: (f64::INFINITY, f64::NEG_INFINITY),
+            numerical_error: false,
+        }
+    }
+
+    pub(crate) fn mean(&self) -> &[f64] {
+        self.mean.as_slice()
+    }
+
+    pub(crate) fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Returns `lambda` points drawn from N(mean, sigma^2 * C)
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..self.lambda)
+            .map(|_| {
+                let z = DVector::from_fn(self.dimensions, |_, _| standard_normal(rng));
+                let y = &self.b * self.d.component_mul(&z);
+                (&self.mean + y * self.sigma).iter().copied().collect()
+            })
+            .collect()
+    }
+
+    /// Updates distribution parameters
+    ///
+    /// ## Arguments
+    ///
+    /// * `population` - evaluated points sampled in this generation, sorted by fitness in ascending order
+    pub(crate) fn update(&mut self, population: &[(Vec<f64>, f64)]) {
+        debug_assert_eq!(population.len(), self.lambda);
+        self.generation += 1;
+        let n = self.dimensions as f64;
+
+        let old_mean = self.mean.clone();
+        let steps: Vec<DVector<f64>> = population
+            .iter()
+            .take(self.weights.len())
+            .map(|(x, _)| (DVector::from_column_slice(x) - &old_mean) / self.sigma)
+            .collect();
+        let y_w = steps
+            .iter()
+            .zip(self.weights.iter())
+            .fold(DVector::zeros(self.dimensions), |acc, (y, w)| acc + y * *w);
+        self.mean = &old_mean + &y_w * self.sigma;
+
+        // Step-size path uses C^(-1/2) = B * D^(-1) * B^T
+        let inv_d = DMatrix::from_diagonal(&self.d.map(|d| 1.0 / d));
+        let c_inv_sqrt = &self.b * inv_d * self.b.transpose();
+        self.ps =
+            &self.ps * (1.0 - self.cs) + c_inv_sqrt * &y_w * (self.cs * (2.0 - self.cs) * self.mueff).sqrt();
+        let ps_norm = self.ps.norm();
+
+        let hsig = ps_norm / (1.0 - (1.0 - self.cs).powf(2.0 * self.generation as f64)).sqrt() / self.chi_n
+            < 1.4 + 2.0 / (n + 1.0);
+        let hsig = if hsig { 1.0 } else { 0.0 };
+        self.pc =
+            &self.pc * (1.0 - self.cc) + &y_w * (hsig * (self.cc * (2.0 - self.cc) * self.mueff).sqrt());
+
+        let rank_mu = steps
+            .iter()
+            .zip(self.weights.iter())
+            .fold(DMatrix::zeros(self.dimensions, self.dimensions), |acc, (y, w)| {
+                acc + y * y.transpose() * *w
+            });
+        let rank_one =
+            &self.pc * self.pc.transpose() + &self.cov * ((1.0 - hsig) * self.cc * (2.0 - self.cc));
+        self.cov = &self.cov * (1.0 - self.c1 - self.cmu) + rank_one * self.c1 + rank_mu * self.cmu;
+
+        self.sigma *= ((self.cs / self.damps) * (ps_norm / self.chi_n - 1.0)).exp();
+
+        // Decomposition is O(n^3), therefore it is postponed as long as the covariance matrix
+        // changes only slightly
+        let eigen_interval = self.lambda as f64 / (self.c1 + self.cmu) / n / 10.0;
+        if (self.generation - self.eigen_generation) as f64 > eigen_interval {
+            self.decompose();
+        }
+
+        if self.history.len() == self.history_len {
+            self.history.pop_front();
+        }
+        self.history.push_back(population[0].1);
+        self.last_range = (population[0].1, population[population.len() - 1].1);
+    }
+
+    /// Recomputes B & D so that C = B * D^2 * B^T
+    fn decompose(&mut self) {
+        self.eigen_generation = self.generation;
+        self.cov = (&self.cov + self.cov.transpose()) * 0.5;
+        let eigen = self.cov.clone().symmetric_eigen();
+        if eigen.eigenvalues.iter().any(|e| *e <= 0.0 || !e.is_finite()) {
+            self.numerical_error = true;
+            return;
+        }
+        self.d = eigen.eigenvalues.map(f64::sqrt);
+        self.b = eigen.eigenvectors;
+    }
+
+    /// Returns reason of stopping the run, if any of local termination criteria is met
+    ///
+    /// ## Arguments
+    ///
+    /// * `tol_fun` - tolerance of fitness values
+    /// * `tol_x` - tolerance of step size
+    pub(crate) fn local_termination(&self, tol_fun: f64, tol_x: f64) -> Option<LocalTermination> {
+        let n = self.dimensions as f64;
+
+        if self.numerical_error || !self.sigma.is_finite() || self.sigma <= 0.0 {
+            return Some(LocalTermination::NumericalError);
+        }
+
+        if self.generation as f64 > 100.0 + 50.0 * (n + 3.0).powi(2) / (self.lambda as f64).sqrt() {
+            return Some(LocalTermination::MaxIterations);
+        }
+
+        if self.history.len() == self.history_len {
+            let (min, max) = self
+                .history
+                .iter()
+                .fold(self.last_range, |(min, max), f| (min.min(*f), max.max(*f)));
+            if max - min < tol_fun {
+                return Some(LocalTermination::TolFun);
+            }
+        }
+
+        let diagonal = self.cov.diagonal();
+        if diagonal
+            .iter()
+            .zip(self.pc.iter())
+            .all(|(c, pc)| self.sigma * c.sqrt() < tol_x && self.sigma * pc.abs() < tol_x)
+        {
+            return Some(LocalTermination::TolX);
+        }
+
+        let (d_min, d_max) = self.d.iter().fold((f64::INFINITY, 0.0f64), |(min, max), d| {
+            (min.min(*d), max.max(*d))
+        });
+        if (d_max / d_min).powi(2) > MAX_CONDITION {
+            return Some(LocalTermination::ConditionCov);
+        }
+
+        let axis = self.generation % self.dimensions;
+        let shift = self.b.column(axis) * (0.1 * self.sigma * self.d[axis]);
+        if self.mean == &self.mean + shift {
+            return Some(LocalTermination::NoEffectAxis);
+        }
+
+        if self
+            .mean
+            .iter()
+            .zip(diagonal.iter())
+            .any(|(m, c)| *m == m + 0.2 * self.sigma * c.sqrt())
+        {
+            return Some(LocalTermination::NoEffectCoord);
+        }
+
+        None
+    }
+}
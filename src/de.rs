@@ -0,0 +1,475 @@
+//! Implementation of differential evolution algorithm
+//!
+//! Differential evolution (R. Storn & K. Price) minimises the objective by perturbing population members
+//! with scaled differences of other members. Classic strategies (DE/x/y/z) use fixed differential weight `F`
+//! & crossover probability `CR`, while adaptive variants ([Strategy::Jade] & [Strategy::Shade]) draw them for each
+//! individual from distributions adapted during the run. See [Strategy] for available variants.
+//!
+//! Mutant genes leaving the search area are set halfway between the bound & the corresponding gene of the target
+//! vector.
+//!
+//! # Usage example:
+//! ```rust
+//! # use ecrs::de::{builder::DeAlgorithmBuilder, probe::empty_probe::EmptyProbe, Strategy};
+//! let mut algorithm = DeAlgorithmBuilder::new()
+//!     .set_dimensions(4)
+//!     .set_function(|x: &[f64]| x.iter().map(|xi| xi * xi).sum())
+//!     .set_strategy(Strategy::Shade { memory_size: 10 })
+//!     .set_generation_limit(300)
+//!     .set_seed(42)
+//!     .set_probe(Box::new(EmptyProbe::new()))
+//!     .build();
+//! algorithm.run();
+//! assert!(algorithm.state().best_fitness < 1e-8);
+//! ```
+
+pub mod builder;
+mod strategy;
+
+pub use crate::optimizer::{probe, termination_condition};
+pub use strategy::Strategy;
+
+use std::time::Instant;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::de::probe::stdout_probe::StdoutProbe;
+use crate::de::probe::Probe;
+use crate::de::strategy::{
+    binomial_crossover, differential, distinct_indices, exponential_crossover, ParameterMemory, Successes,
+};
+use crate::de::termination_condition::{GenerationLimit, TerminationCondition};
+use crate::optimizer::OptimizerState;
+
+/// Struct wrapping all configuration parameters of differential evolution algorithm.
+/// # Parameters:
+///  - dimensions - number of dimension of optimized function's domain
+///  - lower_bound - lower bound of search area in every dimension
+///  - upper_bound - upper bound of search area in every dimension
+///  - population_size - number of individuals, `10 * dimensions` if not given
+///  - strategy - mutation & crossover scheme, see [Strategy]
+///  - differential_weight - `F`, scale of difference vectors, ignored by adaptive strategies
+///  - crossover_probability - `CR`, probability of taking gene from mutant vector, ignored by adaptive strategies
+///  - seed - seed of random number generator, random if not given
+///  - termination_condition - used for determining stopping point of the algorithm
+///  - probe - used for displaying results / progress of the algorithm
+/// # Example coefficient values:
+///  - differential_weight: 0.5
+///  - crossover_probability: 0.9
+pub struct DeAlgorithmCfg {
+    dimensions: usize,
+    lower_bound: f64,
+    upper_bound: f64,
+    population_size: Option<usize>,
+    strategy: Strategy,
+    differential_weight: f64,
+    crossover_probability: f64,
+    seed: Option<u64>,
+    termination_condition: Box<dyn TerminationCondition<DeState>>,
+    probe: Box<dyn Probe<DeState>>,
+}
+
+impl Default for DeAlgorithmCfg {
+    fn default() -> Self {
+        DeAlgorithmCfg {
+            dimensions: 2,
+            lower_bound: -5.0,
+            upper_bound: 5.0,
+            population_size: None,
+            strategy: Strategy::RandOneBin,
+            differential_weight: 0.5,
+            crossover_probability: 0.9,
+            seed: None,
+            termination_condition: Box::new(GenerationLimit::new(1000)),
+            probe: Box::new(StdoutProbe::new()),
+        }
+    }
+}
+
+/// Observable state of differential evolution algorithm, passed to probes & termination conditions
+#[derive(Clone, Debug)]
+pub struct DeState {
+    /// Current population
+    pub population: Vec<Vec<f64>>,
+    /// Fitness of consecutive individuals of the population
+    pub fitness: Vec<f64>,
+    /// Number of generations done
+    pub generation: usize,
+    /// Number of objective function evaluations
+    pub evaluations: usize,
+    /// Differential weight, for adaptive strategies mean of its distribution
+    pub differential_weight: f64,
+    /// Crossover probability, for adaptive strategies mean of its distribution
+    pub crossover_probability: f64,
+    /// Best solution found so far
+    pub best_solution: Vec<f64>,
+    /// Fitness of the best solution found so far
+    pub best_fitness: f64,
+    /// Start time of the algorithm
+    pub start_time: Instant,
+}
+
+impl OptimizerState for DeState {
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    fn best_fitness(&self) -> f64 {
+        self.best_fitness
+    }
+
+    fn best_solution(&self) -> &[f64] {
+        &self.best_solution
+    }
+
+    fn start_time(&self) -> Instant {
+        self.start_time
+    }
+}
+
+/// Struct used for running differential evolution algorithm, see [module level docs](self) for usage example
+pub struct DeAlgorithm<F: FnMut(&[f64]) -> f64> {
+    config: DeAlgorithmCfg,
+    function: F,
+    population_size: usize,
+    state: DeState,
+    rng: StdRng,
+}
+
+impl<F: FnMut(&[f64]) -> f64> DeAlgorithm<F> {
+    pub fn new(config: DeAlgorithmCfg, function: F) -> Self {
+        assert!(config.dimensions > 0, "Dimensions must be > 0");
+        assert!(
+            config.lower_bound < config.upper_bound,
+            "Lower bound must be smaller than upper bound"
+        );
+        assert!(
+            (0.0..=1.0).contains(&config.crossover_probability),
+            "Crossover probability must be in range [0, 1]"
+        );
+        if let Strategy::Shade { memory_size } = config.strategy {
+            assert!(memory_size > 0, "Memory size must be > 0");
+        }
+
+        let population_size = config.population_size.unwrap_or(10 * config.dimensions);
+        assert!(
+            population_size >= config.strategy.min_population_size(),
+            "Population size must be >= {} for {:?} strategy",
+            config.strategy.min_population_size(),
+            config.strategy
+        );
+
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let state = DeState {
+            population: Vec::new(),
+            fitness: Vec::new(),
+            generation: 0,
+            evaluations: 0,
+            differential_weight: config.differential_weight,
+            crossover_probability: config.crossover_probability,
+            best_solution: vec![0.0; config.dimensions],
+            best_fitness: f64::INFINITY,
+            start_time: Instant::now(),
+        };
+        DeAlgorithm {
+            config,
+            function,
+            population_size,
+            state,
+            rng,
+        }
+    }
+
+    /// Returns state of the algorithm, e.g. the best solution found
+    pub fn state(&self) -> &DeState {
+        &self.state
+    }
+
+    /// Runs the algorithm until termination condition is met
+    pub fn run(&mut self) {
+        self.state.start_time = Instant::now();
+        self.state.generation = 0;
+        self.state.evaluations = 0;
+        self.state.best_fitness = f64::INFINITY;
+        self.state.population = (0..self.population_size)
+            .map(|_| {
+                (0..self.config.dimensions)
+                    .map(|_| {
+                        self.rng
+                            .gen_range(self.config.lower_bound..self.config.upper_bound)
+                    })
+                    .collect()
+            })
+            .collect();
+        self.state.fitness = Vec::with_capacity(self.population_size);
+        for idx in 0..self.population_size {
+            let value = self.evaluate(idx, None);
+            self.state.fitness.push(value);
+        }
+
+        let memory_size = match self.config.strategy {
+            Strategy::Shade { memory_size } => memory_size,
+            _ => 1,
+        };
+        let mut memory = ParameterMemory::new(memory_size);
+        let mut archive: Vec<Vec<f64>> = Vec::new();
+        if self.config.strategy.is_adaptive() {
+            self.state.differential_weight = memory.mean_f();
+            self.state.crossover_probability = memory.mean_cr();
+        }
+
+        self.config.termination_condition.initialize(&self.state);
+        self.config.probe.on_begin(&self.state);
+
+        while !self.config.termination_condition.is_met(&self.state) {
+            let successes = self.generation(&memory, &mut archive);
+
+            match self.config.strategy {
+                Strategy::Jade { c, .. } => memory.update_jade(&successes, c),
+                Strategy::Shade { .. } => memory.update_shade(&successes),
+                _ => {}
+            }
+            if self.config.strategy.is_adaptive() {
+                self.state.differential_weight = memory.mean_f();
+                self.state.crossover_probability = memory.mean_cr();
+            }
+
+            self.state.generation += 1;
+            self.config.probe.on_new_generation(&self.state);
+        }
+
+        self.config.probe.on_end(&self.state);
+    }
+
+    /// Evaluates individual at `idx` (or `trial` vector if given) & updates the best solution
+    fn evaluate(&mut self, idx: usize, trial: Option<&[f64]>) -> f64 {
+        let solution = trial.unwrap_or(&self.state.population[idx]);
+        let value = (self.function)(solution);
+        self.state.evaluations += 1;
+        if value < self.state.best_fitness {
+            self.state.best_fitness = value;
+            self.state.best_solution = solution.to_vec();
+        }
+        value
+    }
+
+    /// Performs single generation, i.e. creates trial vector for every individual & replaces individuals
+    /// which are not better than their trial vectors. Returns control parameters which led to improvement.
+    fn generation(&mut self, memory: &ParameterMemory, archive: &mut Vec<Vec<f64>>) -> Successes {
+        let np = self.population_size;
+        let strategy = self.config.strategy;
+
+        let best = (0..np)
+            .min_by(|a, b| self.state.fitness[*a].total_cmp(&self.state.fitness[*b]))
+            .unwrap();
+        let mut ranking: Vec<usize> = (0..np).collect();
+        if strategy.is_adaptive() {
+            ranking.sort_by(|a, b| self.state.fitness[*a].total_cmp(&self.state.fitness[*b]));
+        }
+
+        let mut successes = Successes::default();
+        let mut next_population = self.state.population.clone();
+        let mut next_fitness = self.state.fitness.clone();
+        let mut replaced = Vec::new();
+
+        for i in 0..np {
+            let (f, cr) = if strategy.is_adaptive() {
+                memory.sample(&mut self.rng)
+            } else {
+                (self.config.differential_weight, self.config.crossover_probability)
+            };
+
+            let population = &self.state.population;
+            let target = &population[i];
+            let mut mutant = match strategy {
+                Strategy::RandOneBin => {
+                    let r = distinct_indices(&mut self.rng, np, 3, i);
+                    differential(&population[r[0]], &population[r[1]], &population[r[2]], f)
+                }
+                Strategy::BestOneBin => {
+                    let r = distinct_indices(&mut self.rng, np, 2, i);
+                    differential(&population[best], &population[r[0]], &population[r[1]], f)
+                }
+                Strategy::CurrentToBestOneBin => {
+                    let r = distinct_indices(&mut self.rng, np, 2, i);
+                    let towards_best = differential(target, &population[best], target, f);
+                    differential(&towards_best, &population[r[0]], &population[r[1]], f)
+                }
+                Strategy::RandTwoExp => {
+                    let r = distinct_indices(&mut self.rng, np, 5, i);
+                    let mutant = differential(&population[r[0]], &population[r[1]], &population[r[2]], f);
+                    differential(&mutant, &population[r[3]], &population[r[4]], f)
+                }
+                Strategy::Jade { .. } | Strategy::Shade { .. } => {
+                    let p = match strategy {
+                        Strategy::Jade { p, .. } => p,
+                        _ => self.rng.gen_range((2.0 / np as f64).min(0.2)..=0.2),
+                    };
+                    let top = ((p * np as f64).round() as usize).clamp(1, np);
+                    let pbest = ranking[self.rng.gen_range(0..top)];
+
+                    let r1 = distinct_indices(&mut self.rng, np, 1, i)[0];
+                    // x_r2 is drawn from union of population & archive
+                    let r2 = loop {
+                        let r2 = self.rng.gen_range(0..np + archive.len());
+                        if r2 != i && r2 != r1 {
+                            break r2;
+                        }
+                    };
+                    let x_r2 = if r2 < np {
+                        &population[r2]
+                    } else {
+                        &archive[r2 - np]
+                    };
+
+                    let towards_best = differential(target, &population[pbest], target, f);
+                    differential(&towards_best, &population[r1], x_r2, f)
+                }
+            };
+
+            for (gene, target_gene) in mutant.iter_mut().zip(target.iter()) {
+                if *gene < self.config.lower_bound {
+                    *gene = (self.config.lower_bound + target_gene) / 2.0;
+                } else if *gene > self.config.upper_bound {
+                    *gene = (self.config.upper_bound + target_gene) / 2.0;
+                }
+            }
+
+            let trial = match strategy {
+                Strategy::RandTwoExp => exponential_crossover(&mut self.rng, target, &mutant, cr),
+                _ => binomial_crossover(&mut self.rng, target, &mutant, cr),
+            };
+
+            let value = self.evaluate(i, Some(&trial));
+            if value <= self.state.fitness[i] {
+                if value < self.state.fitness[i] && strategy.is_adaptive() {
+                    successes.f.push(f);
+                    successes.cr.push(cr);
+                    successes.improvement.push(self.state.fitness[i] - value);
+                    replaced.push(i);
+                }
+                next_population[i] = trial;
+                next_fitness[i] = value;
+            }
+        }
+
+        if strategy.is_adaptive() {
+            archive.extend(replaced.into_iter().map(|i| self.state.population[i].clone()));
+            while archive.len() > np {
+                let idx = self.rng.gen_range(0..archive.len());
+                archive.swap_remove(idx);
+            }
+        }
+
+        self.state.population = next_population;
+        self.state.fitness = next_fitness;
+        successes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::builder::DeAlgorithmBuilder;
+    use super::probe::empty_probe::EmptyProbe;
+    use super::Strategy;
+
+    fn sphere(x: &[f64]) -> f64 {
+        x.iter().map(|xi| xi * xi).sum()
+    }
+
+    fn rosenbrock(x: &[f64]) -> f64 {
+        x.windows(2)
+            .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+            .sum()
+    }
+
+    #[test]
+    fn every_strategy_solves_sphere() {
+        for strategy in [
+            Strategy::RandOneBin,
+            Strategy::BestOneBin,
+            Strategy::CurrentToBestOneBin,
+            Strategy::RandTwoExp,
+            Strategy::Jade { p: 0.05, c: 0.1 },
+            Strategy::Shade { memory_size: 20 },
+        ] {
+            let mut algorithm = DeAlgorithmBuilder::new()
+                .set_dimensions(3)
+                .set_function(sphere)
+                .set_strategy(strategy)
+                .set_generation_limit(500)
+                .set_seed(1)
+                .set_probe(Box::new(EmptyProbe::new()))
+                .build();
+            algorithm.run();
+
+            assert!(
+                algorithm.state().best_fitness < 1e-6,
+                "{strategy:?} reached {}",
+                algorithm.state().best_fitness
+            );
+        }
+    }
+
+    #[test]
+    fn adaptive_strategy_solves_rosenbrock() {
+        let mut algorithm = DeAlgorithmBuilder::new()
+            .set_dimensions(4)
+            .set_function(rosenbrock)
+            .set_strategy(Strategy::Jade { p: 0.05, c: 0.1 })
+            .set_evaluation_limit(80_000)
+            .set_seed(2)
+            .set_probe(Box::new(EmptyProbe::new()))
+            .build();
+        algorithm.run();
+
+        assert!(algorithm.state().best_fitness < 1e-4);
+    }
+
+    #[test]
+    fn runs_with_same_seed_are_identical() {
+        let run = || {
+            let mut algorithm = DeAlgorithmBuilder::new()
+                .set_dimensions(3)
+                .set_function(rosenbrock)
+                .set_strategy(Strategy::Shade { memory_size: 10 })
+                .set_generation_limit(20)
+                .set_seed(3)
+                .set_probe(Box::new(EmptyProbe::new()))
+                .build();
+            algorithm.run();
+            algorithm.state().population.clone()
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn population_stays_within_bounds() {
+        let mut algorithm = DeAlgorithmBuilder::new()
+            .set_dimensions(2)
+            .set_lower_bound(1.0)
+            .set_upper_bound(2.0)
+            .set_function(sphere)
+            .set_strategy(Strategy::RandTwoExp)
+            .set_differential_weight(1.5)
+            .set_generation_limit(50)
+            .set_probe(Box::new(EmptyProbe::new()))
+            .build();
+        algorithm.run();
+
+        assert!(algorithm
+            .state()
+            .population
+            .iter()
+            .flatten()
+            .all(|gene| (1.0..=2.0).contains(gene)));
+    }
+}
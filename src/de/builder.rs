@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use crate::de::probe::Probe;
+use crate::de::termination_condition::{EvaluationLimit, GenerationLimit, TerminationCondition, TimeLimit};
+use crate::de::{DeAlgorithm, DeAlgorithmCfg, DeState, Strategy};
+
+pub struct DeAlgorithmBuilder<F: FnMut(&[f64]) -> f64> {
+    config: DeAlgorithmCfg,
+    function: Option<F>,
+}
+
+impl<F: FnMut(&[f64]) -> f64> DeAlgorithmBuilder<F> {
+    pub fn new() -> Self {
+        DeAlgorithmBuilder {
+            config: DeAlgorithmCfg::default(),
+            function: None,
+        }
+    }
+
+    pub fn set_dimensions(mut self, dimensions: usize) -> Self {
+        self.config.dimensions = dimensions;
+        self
+    }
+
+    pub fn set_lower_bound(mut self, lower_bound: f64) -> Self {
+        self.config.lower_bound = lower_bound;
+        self
+    }
+
+    pub fn set_upper_bound(mut self, upper_bound: f64) -> Self {
+        self.config.upper_bound = upper_bound;
+        self
+    }
+
+    pub fn set_population_size(mut self, population_size: usize) -> Self {
+        self.config.population_size = Some(population_size);
+        self
+    }
+
+    pub fn set_strategy(mut self, strategy: Strategy) -> Self {
+        self.config.strategy = strategy;
+        self
+    }
+
+    pub fn set_differential_weight(mut self, differential_weight: f64) -> Self {
+        self.config.differential_weight = differential_weight;
+        self
+    }
+
+    pub fn set_crossover_probability(mut self, crossover_probability: f64) -> Self {
+        self.config.crossover_probability = crossover_probability;
+        self
+    }
+
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn set_function(mut self, function: F) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    pub fn set_termination_condition(
+        mut self,
+        termination_condition: Box<dyn TerminationCondition<DeState>>,
+    ) -> Self {
+        self.config.termination_condition = termination_condition;
+        self
+    }
+
+    pub fn set_generation_limit(mut self, generation_limit: usize) -> Self {
+        self.config.termination_condition = Box::new(GenerationLimit::new(generation_limit));
+        self
+    }
+
+    pub fn set_evaluation_limit(mut self, evaluation_limit: usize) -> Self {
+        self.config.termination_condition = Box::new(EvaluationLimit::new(evaluation_limit));
+        self
+    }
+
+    pub fn set_time_limit(mut self, time_limit: Duration) -> Self {
+        self.config.termination_condition = Box::new(TimeLimit::new(time_limit));
+        self
+    }
+
+    pub fn set_probe(mut self, probe: Box<dyn Probe<DeState>>) -> Self {
+        self.config.probe = probe;
+        self
+    }
+
+    pub fn build(self) -> DeAlgorithm<F> {
+        let function = self.function.expect("Function must be set");
+        DeAlgorithm::new(self.config, function)
+    }
+}
@@ -0,0 +1,192 @@
+use rand::{seq::index, Rng};
+
+use crate::util::standard_normal;
+
+/// Mutation & crossover scheme of differential evolution
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy {
+    /// DE/rand/1/bin: `v = x_r1 + F * (x_r2 - x_r3)`, binomial crossover
+    RandOneBin,
+    /// DE/best/1/bin: `v = x_best + F * (x_r1 - x_r2)`, binomial crossover
+    BestOneBin,
+    /// DE/current-to-best/1/bin: `v = x_i + F * (x_best - x_i) + F * (x_r1 - x_r2)`, binomial crossover
+    CurrentToBestOneBin,
+    /// DE/rand/2/exp: `v = x_r1 + F * (x_r2 - x_r3) + F * (x_r4 - x_r5)`, exponential crossover
+    RandTwoExp,
+    /// JADE (J. Zhang & A. C. Sanderson): DE/current-to-pbest/1/bin with external archive. `F` & `CR` are drawn for
+    /// each individual from distributions adapted towards successful values with learning rate `c` (usually 0.1).
+    /// `p` is the fraction of the best individuals to choose `x_pbest` from (usually 0.05).
+    Jade { p: f64, c: f64 },
+    /// SHADE (R. Tanabe & A. Fukunaga): JADE with historical memory of `memory_size` successful `F` & `CR` means
+    /// (usually equal to population size) & `p` drawn from [2 / NP, 0.2] for each individual.
+    Shade { memory_size: usize },
+}
+
+impl Strategy {
+    /// Returns minimal population size strategy can work with
+    pub(crate) fn min_population_size(&self) -> usize {
+        match self {
+            Strategy::RandOneBin | Strategy::Jade { .. } | Strategy::Shade { .. } => 4,
+            Strategy::BestOneBin | Strategy::CurrentToBestOneBin => 3,
+            Strategy::RandTwoExp => 6,
+        }
+    }
+
+    /// Returns `true` iff strategy adapts control parameters & uses external archive
+    pub(crate) fn is_adaptive(&self) -> bool {
+        matches!(self, Strategy::Jade { .. } | Strategy::Shade { .. })
+    }
+}
+
+/// Returns `count` distinct indices from `0..len`, different from `excluded`
+pub(crate) fn distinct_indices<R: Rng>(rng: &mut R, len: usize, count: usize, excluded: usize) -> Vec<usize> {
+    index::sample(rng, len - 1, count)
+        .into_iter()
+        .map(|idx| if idx >= excluded { idx + 1 } else { idx })
+        .collect()
+}
+
+/// Returns `base + weight * (plus - minus)` computed component-wise
+pub(crate) fn differential(base: &[f64], plus: &[f64], minus: &[f64], weight: f64) -> Vec<f64> {
+    base.iter()
+        .zip(plus.iter().zip(minus.iter()))
+        .map(|(b, (p, m))| b + weight * (p - m))
+        .collect()
+}
+
+/// Binomial crossover: each gene is taken from `mutant` with probability `cr`, at least one gene always is
+pub(crate) fn binomial_crossover<R: Rng>(rng: &mut R, target: &[f64], mutant: &[f64], cr: f64) -> Vec<f64> {
+    let forced = rng.gen_range(0..target.len());
+    target
+        .iter()
+        .zip(mutant.iter())
+        .enumerate()
+        .map(|(j, (t, m))| {
+            if j == forced || rng.gen::<f64>() < cr {
+                *m
+            } else {
+                *t
+            }
+        })
+        .collect()
+}
+
+/// Exponential crossover: consecutive (cyclically) genes, starting from random one, are taken from `mutant`
+/// as long as random number is smaller than `cr`
+pub(crate) fn exponential_crossover<R: Rng>(
+    rng: &mut R,
+    target: &[f64],
+    mutant: &[f64],
+    cr: f64,
+) -> Vec<f64> {
+    let n = target.len();
+    let mut trial = target.to_vec();
+    let start = rng.gen_range(0..n);
+    let mut length = 0;
+    loop {
+        let j = (start + length) % n;
+        trial[j] = mutant[j];
+        length += 1;
+        if length == n || rng.gen::<f64>() >= cr {
+            break;
+        }
+    }
+    trial
+}
+
+/// Successful control parameters of a single generation
+#[derive(Default)]
+pub(crate) struct Successes {
+    pub(crate) f: Vec<f64>,
+    pub(crate) cr: Vec<f64>,
+    /// Fitness improvements, used as weights by SHADE
+    pub(crate) improvement: Vec<f64>,
+}
+
+impl Successes {
+    fn weights(&self) -> Vec<f64> {
+        let total: f64 = self.improvement.iter().sum();
+        if total > 0.0 {
+            self.improvement.iter().map(|d| d / total).collect()
+        } else {
+            vec![1.0 / self.improvement.len() as f64; self.improvement.len()]
+        }
+    }
+}
+
+/// Returns `sum(w * x^2) / sum(w * x)`
+fn lehmer_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let (numerator, denominator) = values
+        .iter()
+        .zip(weights.iter())
+        .fold((0.0, 0.0), |(num, den), (x, w)| (num + w * x * x, den + w * x));
+    numerator / denominator
+}
+
+/// Adapted means of `F` & `CR` distributions used by JADE & SHADE
+pub(crate) struct ParameterMemory {
+    f: Vec<f64>,
+    cr: Vec<f64>,
+    position: usize,
+}
+
+impl ParameterMemory {
+    pub(crate) fn new(size: usize) -> Self {
+        Self {
+            f: vec![0.5; size],
+            cr: vec![0.5; size],
+            position: 0,
+        }
+    }
+
+    /// Returns mean of `F` distribution averaged over memory
+    pub(crate) fn mean_f(&self) -> f64 {
+        self.f.iter().sum::<f64>() / self.f.len() as f64
+    }
+
+    /// Returns mean of `CR` distribution averaged over memory
+    pub(crate) fn mean_cr(&self) -> f64 {
+        self.cr.iter().sum::<f64>() / self.cr.len() as f64
+    }
+
+    /// Returns `(F, CR)` drawn from Cauchy(mu_F, 0.1) & N(mu_CR, 0.1) with means taken from random memory cell.
+    /// `F` is redrawn while non-positive & truncated to 1, `CR` is clamped to [0, 1].
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> (f64, f64) {
+        let cell = rng.gen_range(0..self.f.len());
+        let cr = (self.cr[cell] + 0.1 * standard_normal(rng)).clamp(0.0, 1.0);
+        let f = loop {
+            let f = self.f[cell] + 0.1 * (std::f64::consts::PI * (rng.gen::<f64>() - 0.5)).tan();
+            if f > 0.0 {
+                break f.min(1.0);
+            }
+        };
+        (f, cr)
+    }
+
+    /// JADE update: means move towards arithmetic mean of successful `CR` & Lehmer mean of successful `F`
+    pub(crate) fn update_jade(&mut self, successes: &Successes, c: f64) {
+        if successes.f.is_empty() {
+            return;
+        }
+        let weights = vec![1.0; successes.f.len()];
+        let mean_cr = successes.cr.iter().sum::<f64>() / successes.cr.len() as f64;
+        self.cr[0] = (1.0 - c) * self.cr[0] + c * mean_cr;
+        self.f[0] = (1.0 - c) * self.f[0] + c * lehmer_mean(&successes.f, &weights);
+    }
+
+    /// SHADE update: next memory cell is overwritten with improvement-weighted means of successful parameters
+    pub(crate) fn update_shade(&mut self, successes: &Successes) {
+        if successes.f.is_empty() {
+            return;
+        }
+        let weights = successes.weights();
+        self.cr[self.position] = successes
+            .cr
+            .iter()
+            .zip(weights.iter())
+            .map(|(cr, w)| cr * w)
+            .sum();
+        self.f[self.position] = lehmer_mean(&successes.f, &weights);
+        self.position = (self.position + 1) % self.f.len();
+    }
+}
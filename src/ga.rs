@@ -33,8 +33,9 @@
 //! 3. Apply selection operator
 //! 4. Apply crossover operator
 //! 5. Apply mutation operator
-//! 6. Apply replacement operator
-//! 7. Termination condition satisfied? Yes -> END, no -> go to 2
+//! 6. Apply local search (optional, see [local search stage](crate::ga::operators::local_search))
+//! 7. Apply replacement operator
+//! 8. Termination condition satisfied? Yes -> END, no -> go to 2
 //!
 //! The `population` is a set of feasible solutions to the problem (individuals). Usually initial
 //! `population` is created by random generation (see our [population generators](crate::ga::population)).
@@ -115,12 +116,25 @@
 //! * See [population generators](crate::ga::population)
 //! * See [fitness & configuration](crate::ga::operators::fitness)
 //! * See [available params](self::GAParams)
-
+//! * See [termination conditions](crate::ga::termination_condition)
+//! * See [bounded search spaces](crate::ga::bounds)
+//! * See [constraint handling](crate::ga::constraints)
+//! * See [checkpoints](crate::ga::checkpoint)
+//! * See [island model](crate::ga::island)
+//! * See [multi-objective optimisation](crate::ga::multiobjective)
+
+pub mod bounds;
 pub mod builder;
+pub mod checkpoint;
+pub mod constraints;
 pub mod individual;
+pub mod island;
+pub mod multiobjective;
 pub mod operators;
 pub mod population;
 pub mod probe;
+pub mod seed;
+pub mod termination_condition;
 pub(crate) mod timer;
 pub mod value_provider;
 
@@ -131,9 +145,12 @@ pub use probe::CsvProbe;
 pub use probe::JsonProbe;
 pub use probe::Probe;
 pub use probe::StdoutProbe;
+use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
 use self::individual::IndividualTrait;
+use self::operators::local_search::LocalSearchStage;
+use self::termination_condition::BoxedTerminationCondition;
 use self::timer::Timer;
 use self::{
     operators::{
@@ -170,15 +187,65 @@ where
     pub replacement_operator: ReplOpT,
     pub population_factory: PopGenT,
     pub probe: ProbeT,
+    /// Additional termination condition, checked after every generation
+    pub termination_condition: Option<BoxedTerminationCondition<IndividualT>>,
+    /// Local search applied to children between mutation & replacement (memetic algorithm)
+    pub local_search: Option<LocalSearchStage<IndividualT>>,
+    /// Seed operators & population generator were seeded with (see [seed] module)
+    pub seed: Option<u64>,
+    reseeder: Option<fn(&mut Self, u64)>,
     _phantom: PhantomData<IndividualT::ChromosomeT>,
 }
 
-#[derive(Default)]
+impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+    GAConfig<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+where
+    IndividualT: IndividualTrait,
+    MutOpT: MutationOperator<IndividualT>,
+    CrossOpT: CrossoverOperator<IndividualT>,
+    SelOpT: SelectionOperator<IndividualT>,
+    ReplOpT: ReplacementOperator<IndividualT>,
+    PopGenT: PopulationGenerator<IndividualT>,
+    FitnessT: Fitness<IndividualT>,
+    ProbeT: Probe<IndividualT>,
+{
+    /// Reseeds operators & population generator with streams derived from `seed` & stores the seed.
+    /// Returns `false` (doing nothing) iff the operators do not support reseeding,
+    /// i.e. the config was not created by builder with seed set.
+    pub(crate) fn reseed(&mut self, seed: u64) -> bool {
+        if self.reseed_operators(seed) {
+            self.seed = Some(seed);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Same as [GAConfig::reseed], but leaves stored seed untouched
+    pub(crate) fn reseed_operators(&mut self, seed: u64) -> bool {
+        match self.reseeder {
+            Some(reseeder) => {
+                reseeder(self, seed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Metrics {
     pub generation: usize,
     pub population_size: usize,
+    /// Not serialized, as [Instant](std::time::Instant) is meaningful only within single process.
+    /// See [checkpoint] module on how it is restored.
+    #[serde(skip)]
     pub start_time: Option<std::time::Instant>,
 
+    /// Number of fitness evaluations performed so far. Individuals that do not require
+    /// evaluation (see [IndividualTrait::requires_evaluation]) are not counted.
+    pub evaluation_count: usize,
+
     /// This field can not be relied upon. It is updated only in the begining
     /// of each generation (iteration) & in the very end, just before `on_end`
     /// probe callback. To get more accurate timing please use `start_time.elapsed()`.
@@ -188,6 +255,8 @@ pub struct Metrics {
     pub selection_dur: Option<std::time::Duration>,
     pub crossover_dur: Option<std::time::Duration>,
     pub mutation_dur: Option<std::time::Duration>,
+    /// `None` unless local search stage is configured
+    pub local_search_dur: Option<std::time::Duration>,
     pub replacement_dur: Option<std::time::Duration>,
     pub iteration_dur: Option<std::time::Duration>,
 }
@@ -203,12 +272,14 @@ impl Metrics {
             generation,
             population_size,
             start_time,
+            evaluation_count: 0,
             total_dur: duration,
             pop_gen_dur: None,
             pop_eval_dur: None,
             selection_dur: None,
             crossover_dur: None,
             mutation_dur: None,
+            local_search_dur: None,
             replacement_dur: None,
             iteration_dur: None,
         }
@@ -229,6 +300,10 @@ where
     config: GAConfig<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>,
     metrics: Metrics,
     timer: Timer,
+    iteration_timer: Timer,
+    population: Vec<IndividualT>,
+    best_individual_all_time: Option<IndividualT>,
+    termination_condition_met: bool,
 }
 
 impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
@@ -251,20 +326,25 @@ where
             config,
             metrics: Metrics::new(None, None, 0, population_size),
             timer: Timer::new(),
+            iteration_timer: Timer::new(),
+            population: Vec::new(),
+            best_individual_all_time: None,
+            termination_condition_met: false,
         }
     }
 
     #[inline]
     fn find_best_individual(population: &[IndividualT]) -> &IndividualT {
-        population.iter().min().unwrap()
+        population
+            .iter()
+            .reduce(|best, idv| if idv.is_better_than(best) { idv } else { best })
+            .unwrap()
     }
 
     #[inline]
     fn eval_pop(&mut self, population: &mut [IndividualT]) {
-        population
-            .iter_mut()
-            .filter(|idv| idv.requires_evaluation())
-            .for_each(|idv| *idv.fitness_mut() = (self.config.fitness_fn).apply(idv));
+        self.metrics.evaluation_count += population.iter().filter(|idv| idv.requires_evaluation()).count();
+        self.config.fitness_fn.apply_batch(population);
     }
 
     #[inline(always)]
@@ -274,7 +354,46 @@ where
             .generate(self.config.params.population_size)
     }
 
-    pub fn run(&mut self) -> Option<IndividualT> {
+    /// Returns metrics of the solver
+    #[inline]
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Returns current population. Empty before the solver is initialized.
+    #[inline]
+    pub fn population(&self) -> &[IndividualT] {
+        &self.population
+    }
+
+    /// Returns best individual found so far (if any)
+    #[inline]
+    pub fn best_individual(&self) -> Option<&IndividualT> {
+        self.best_individual_all_time.as_ref()
+    }
+
+    /// Returns `true` iff generation limit, time limit or termination condition has been reached
+    pub(crate) fn is_finished(&self) -> bool {
+        self.termination_condition_met
+            || self.metrics.generation >= self.config.params.generation_limit
+            || matches!(self.metrics.start_time,
+                Some(start_time) if start_time.elapsed() >= self.config.params.max_duration)
+    }
+
+    /// Takes current population out of the solver, leaving it empty until [GeneticSolver::set_population]
+    /// is called.
+    pub(crate) fn take_population(&mut self) -> Vec<IndividualT> {
+        std::mem::take(&mut self.population)
+    }
+
+    /// Sets current population. Individuals that require evaluation are evaluated.
+    pub(crate) fn set_population(&mut self, mut population: Vec<IndividualT>) {
+        self.eval_pop(&mut population);
+        self.population = population;
+    }
+
+    /// Generates & evaluates initial population. First phase of the [GeneticSolver::run] method.
+    pub(crate) fn initialize(&mut self) {
         self.metrics.start_time = Some(std::time::Instant::now());
         self.config.probe.on_start(&self.metrics);
 
@@ -292,96 +411,140 @@ where
             .probe
             .on_initial_population_created(&self.metrics, &population);
 
-        let mut best_individual_all_time = Self::find_best_individual(&population).clone();
+        let best_individual = Self::find_best_individual(&population).clone();
+
+        self.termination_condition_met = false;
+        if let Some(condition) = self.config.termination_condition.as_mut() {
+            condition.init(&self.metrics, &population);
+        }
 
         self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
-        self.config
-            .probe
-            .on_new_best(&self.metrics, &best_individual_all_time);
+        self.config.probe.on_new_best(&self.metrics, &best_individual);
 
-        let mut iteration_timer = Timer::new();
-        for generation_no in 1..=self.config.params.generation_limit {
-            self.metrics.generation = generation_no;
-            self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
-            iteration_timer.start();
+        self.population = population;
+        self.best_individual_all_time = Some(best_individual);
+    }
 
-            self.config.probe.on_iteration_start(&self.metrics);
+    /// Performs single iteration (creates single generation) of the algorithm.
+    /// Requires [GeneticSolver::initialize] to be called first.
+    pub(crate) fn evolve(&mut self) {
+        let generation_no = self.metrics.generation + 1;
+        self.metrics.generation = generation_no;
+        if let Some(seed) = self.config.seed {
+            self.config
+                .reseed_operators(seed::generation_seed(seed, generation_no));
+        }
+        self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
+        self.iteration_timer.start();
 
-            // 2. Evaluate fitness for each individual.
-            self.timer.start();
-            self.eval_pop(&mut population);
-            self.metrics.pop_eval_dur = Some(self.timer.elapsed());
+        self.config.probe.on_iteration_start(&self.metrics);
 
-            // 4. Create mating pool by applying selection operator.
-            self.timer.start();
-            let mating_pool: Vec<&IndividualT> =
-                self.config.selection_operator.apply(&self.metrics, &population);
-            self.metrics.selection_dur = Some(self.timer.elapsed());
+        let mut population = std::mem::take(&mut self.population);
 
-            // 5. From mating pool create new generation (apply crossover & mutation).
+        // 2. Evaluate fitness for each individual.
+        self.timer.start();
+        self.eval_pop(&mut population);
+        self.metrics.pop_eval_dur = Some(self.timer.elapsed());
 
-            self.timer.start();
-            let mut children = self.config.crossover_operator.apply(&self.metrics, &mating_pool);
-            self.metrics.crossover_dur = Some(self.timer.elapsed());
+        // 4. Create mating pool by applying selection operator.
+        self.timer.start();
+        let mating_pool: Vec<&IndividualT> = self.config.selection_operator.apply(&self.metrics, &population);
+        self.metrics.selection_dur = Some(self.timer.elapsed());
 
-            self.timer.start();
-            children
-                .iter_mut()
-                .for_each(|child| self.config.mutation_operator.apply(&self.metrics, child));
-            self.metrics.mutation_dur = Some(self.timer.elapsed());
+        // 5. From mating pool create new generation (apply crossover & mutation).
 
-            if self.config.replacement_operator.requires_children_fitness() {
-                self.eval_pop(&mut children);
-            }
+        self.timer.start();
+        let mut children = self.config.crossover_operator.apply(&self.metrics, &mating_pool);
+        self.metrics.crossover_dur = Some(self.timer.elapsed());
+
+        self.timer.start();
+        children
+            .iter_mut()
+            .for_each(|child| self.config.mutation_operator.apply(&self.metrics, child));
+        self.metrics.mutation_dur = Some(self.timer.elapsed());
 
-            // 6. Replacement - merge new generation with old one
+        if self.config.local_search.is_some() || self.config.replacement_operator.requires_children_fitness()
+        {
+            self.eval_pop(&mut children);
+        }
+
+        // 6. Improve children with local search (memetic algorithm).
+        if let Some(local_search) = self.config.local_search.as_mut() {
             self.timer.start();
-            population = self
-                .config
-                .replacement_operator
-                .apply(&self.metrics, population, children);
-            self.metrics.replacement_dur = Some(self.timer.elapsed());
+            self.metrics.evaluation_count +=
+                local_search.apply(&self.metrics, &mut children, &mut self.config.fitness_fn);
+            self.metrics.local_search_dur = Some(self.timer.elapsed());
+        }
 
-            self.metrics.population_size = population.len();
+        // 7. Replacement - merge new generation with old one
+        self.timer.start();
+        population = self
+            .config
+            .replacement_operator
+            .apply(&self.metrics, population, children);
+        self.metrics.replacement_dur = Some(self.timer.elapsed());
 
-            assert_eq!(population.len(), self.config.params.population_size,
-                "There was change in population size from {} to {} in generation {}. Dynamic population size is currently not supported.",
-                self.config.params.population_size,
-                population.len(),
-                generation_no);
+        self.metrics.population_size = population.len();
 
-            // 7. Check for stop condition (Is good enough individual found)? If not goto 2.
-            self.timer.start();
-            self.eval_pop(&mut population);
-            self.metrics.pop_eval_dur = Some(self.timer.elapsed());
+        assert_eq!(population.len(), self.config.params.population_size,
+            "There was change in population size from {} to {} in generation {}. Dynamic population size is currently not supported.",
+            self.config.params.population_size,
+            population.len(),
+            generation_no);
 
-            self.config.probe.on_new_generation(&self.metrics, &population);
+        // 8. Check for stop condition (Is good enough individual found)? If not goto 2.
+        self.timer.start();
+        self.eval_pop(&mut population);
+        self.metrics.pop_eval_dur = Some(self.timer.elapsed());
 
-            let best_individual = Self::find_best_individual(&population);
-            self.config
-                .probe
-                .on_best_fit_in_generation(&self.metrics, best_individual);
+        self.config.probe.on_new_generation(&self.metrics, &population);
 
-            if *best_individual < best_individual_all_time {
-                best_individual_all_time = best_individual.clone();
-                self.config
-                    .probe
-                    .on_new_best(&self.metrics, &best_individual_all_time);
-            }
+        let best_individual = Self::find_best_individual(&population);
+        self.config
+            .probe
+            .on_best_fit_in_generation(&self.metrics, best_individual);
+
+        let is_new_best = match self.best_individual_all_time.as_ref() {
+            Some(best_individual_all_time) => best_individual.is_better_than(best_individual_all_time),
+            None => true,
+        };
+        if is_new_best {
+            self.best_individual_all_time = Some(best_individual.clone());
+            self.config.probe.on_new_best(&self.metrics, best_individual);
+        }
 
-            self.metrics.iteration_dur = Some(iteration_timer.elapsed());
-            self.config.probe.on_iteration_end(&self.metrics);
+        self.metrics.iteration_dur = Some(self.iteration_timer.elapsed());
+        self.config.probe.on_iteration_end(&self.metrics);
 
-            if self.metrics.start_time.unwrap().elapsed() >= self.config.params.max_duration {
-                break;
-            }
+        if let (Some(condition), Some(best_individual_all_time)) = (
+            self.config.termination_condition.as_mut(),
+            self.best_individual_all_time.as_ref(),
+        ) {
+            self.termination_condition_met =
+                condition.update_and_check(&self.metrics, &population, best_individual_all_time);
         }
 
+        self.population = population;
+    }
+
+    /// Notifies the probe about the end of computations & returns best individual found.
+    /// Last phase of the [GeneticSolver::run] method.
+    pub(crate) fn finalize(&mut self) -> Option<IndividualT> {
         self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
-        self.config
-            .probe
-            .on_end(&self.metrics, &population, &best_individual_all_time);
-        Some(best_individual_all_time)
+        if let Some(best_individual_all_time) = self.best_individual_all_time.as_ref() {
+            self.config
+                .probe
+                .on_end(&self.metrics, &self.population, best_individual_all_time);
+        }
+        self.best_individual_all_time.clone()
+    }
+
+    pub fn run(&mut self) -> Option<IndividualT> {
+        self.initialize();
+        while !self.is_finished() {
+            self.evolve();
+        }
+        self.finalize()
     }
 }
 
@@ -0,0 +1,289 @@
+//! Bounded search spaces for real valued chromosomes
+//!
+//! [Bounds] describes per-gene domain of the problem together with [Repair] strategy applied
+//! whenever an operator moves a gene outside of its domain. The same instance can be shared
+//! between population generator (see [RandomPoints::with_bounds](crate::ga::population::RandomPoints::with_bounds)),
+//! real valued mutation & crossover operators and the builder (see
+//! [RealValuedBuilder::set_bounds](crate::ga::builder::RealValuedBuilder::set_bounds)).
+//!
+//! Ranges are treated as closed intervals, i.e. both `start` and `end` are feasible values.
+//!
+//! [Repair::DeathPenalty] does not modify genes at all. Wrap your fitness function with [BoundedFitness]
+//! to assign penalty value to infeasible individuals instead.
+
+use std::ops::Range;
+
+use rand::Rng;
+
+use super::{individual::IndividualTrait, operators::fitness::Fitness};
+
+/// Strategy of bringing out-of-bounds genes back to their domain
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Repair {
+    /// Gene is set to the nearest bound
+    #[default]
+    Clamp,
+    /// Gene is mirrored back from the exceeded bound (repeatedly, if needed)
+    Reflect,
+    /// Domain is treated as periodic, gene reenters it from the opposite bound
+    Wrap,
+    /// Gene is replaced with value drawn uniformly from its domain
+    Resample,
+    /// Gene is left untouched, see [BoundedFitness]
+    DeathPenalty,
+}
+
+/// Per-gene domain of real valued problem with strategy of repairing infeasible genes
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bounds {
+    ranges: Vec<Range<f64>>,
+    repair: Repair,
+}
+
+impl Bounds {
+    /// Returns new instance of [Bounds] with [Repair::Clamp] strategy
+    ///
+    /// ## Arguments
+    ///
+    /// * `ranges` - domains of consecutive genes
+    ///
+    /// ## Panics
+    ///
+    /// Iff `ranges` is empty or any of the ranges has `start` > `end`
+    pub fn new(ranges: Vec<Range<f64>>) -> Self {
+        assert!(!ranges.is_empty(), "At least one range must be given");
+        assert!(
+            ranges.iter().all(|range| range.start <= range.end),
+            "Range start must not be greater than its end"
+        );
+        Self {
+            ranges,
+            repair: Repair::default(),
+        }
+    }
+
+    /// Returns new instance of [Bounds] with the same domain for each of `dim` genes
+    ///
+    /// ## Arguments
+    ///
+    /// * `dim` - dimension of the problem
+    /// * `range` - domain of every gene
+    pub fn uniform(dim: usize, range: Range<f64>) -> Self {
+        Self::new(vec![range; dim])
+    }
+
+    /// Sets repair strategy
+    ///
+    /// ## Arguments
+    ///
+    /// * `repair` - strategy of repairing out-of-bounds genes
+    pub fn with_repair(mut self, repair: Repair) -> Self {
+        self.repair = repair;
+        self
+    }
+
+    /// Returns dimension of the bounded space
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns domains of consecutive genes
+    #[inline]
+    pub fn ranges(&self) -> &[Range<f64>] {
+        &self.ranges
+    }
+
+    /// Returns domain of gene at given locus
+    #[inline]
+    pub fn range(&self, locus: usize) -> &Range<f64> {
+        &self.ranges[locus]
+    }
+
+    /// Returns repair strategy
+    #[inline]
+    pub fn repair_strategy(&self) -> Repair {
+        self.repair
+    }
+
+    /// Returns `true` iff every gene of `chromosome` lies within its domain
+    pub fn contains(&self, chromosome: &[f64]) -> bool {
+        chromosome.len() == self.ranges.len()
+            && chromosome
+                .iter()
+                .zip(self.ranges.iter())
+                .all(|(gene, range)| range.start <= *gene && *gene <= range.end)
+    }
+
+    /// Returns point drawn uniformly from the bounded space
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        self.ranges
+            .iter()
+            .map(|range| range.start + rng.gen::<f64>() * (range.end - range.start))
+            .collect()
+    }
+
+    /// Returns `value` of gene at `locus` repaired according to the repair strategy.
+    /// Values within the domain are returned unchanged.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locus` - position of the gene
+    /// * `value` - value of the gene
+    /// * `rng` - random number generator, used by [Repair::Resample] only
+    pub fn repair_gene<R: Rng + ?Sized>(&self, locus: usize, value: f64, rng: &mut R) -> f64 {
+        let range = &self.ranges[locus];
+        if range.start <= value && value <= range.end {
+            return value;
+        }
+
+        let width = range.end - range.start;
+        match self.repair {
+            Repair::Clamp => value.max(range.start).min(range.end),
+            Repair::Reflect if width > 0.0 => {
+                let offset = (value - range.start).rem_euclid(2.0 * width);
+                if offset <= width {
+                    range.start + offset
+                } else {
+                    range.end - (offset - width)
+                }
+            }
+            Repair::Wrap if width > 0.0 => range.start + (value - range.start).rem_euclid(width),
+            Repair::Reflect | Repair::Wrap => range.start,
+            Repair::Resample => range.start + rng.gen::<f64>() * width,
+            Repair::DeathPenalty => value,
+        }
+    }
+
+    /// Repairs every out-of-bounds gene of `chromosome` in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `chromosome` - chromosome to repair, must be of bounds' dimension
+    /// * `rng` - random number generator, used by [Repair::Resample] only
+    pub fn repair<R: Rng + ?Sized>(&self, chromosome: &mut [f64], rng: &mut R) {
+        debug_assert_eq!(
+            chromosome.len(),
+            self.ranges.len(),
+            "Bounds must be given for every gene"
+        );
+        for (locus, gene) in chromosome.iter_mut().enumerate() {
+            *gene = self.repair_gene(locus, *gene, rng);
+        }
+    }
+}
+
+impl From<Vec<Range<f64>>> for Bounds {
+    fn from(ranges: Vec<Range<f64>>) -> Self {
+        Self::new(ranges)
+    }
+}
+
+/// # Bounded fitness
+///
+/// Wrapper around any [Fitness] implementation, which assigns `penalty` value to individuals
+/// lying outside of bounds instead of evaluating them. Use it together with [Repair::DeathPenalty]
+/// strategy, choosing `penalty` worse than any feasible fitness value.
+pub struct BoundedFitness<F> {
+    fitness: F,
+    bounds: Bounds,
+    penalty: f64,
+}
+
+impl<F> BoundedFitness<F> {
+    /// Returns new instance of [BoundedFitness]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - fitness function to evaluate feasible individuals with
+    /// * `bounds` - domain of the problem
+    /// * `penalty` - fitness value of infeasible individuals
+    pub fn new(fitness: F, bounds: Bounds, penalty: f64) -> Self {
+        Self {
+            fitness,
+            bounds,
+            penalty,
+        }
+    }
+}
+
+impl<F: Clone> Clone for BoundedFitness<F> {
+    fn clone(&self) -> Self {
+        Self {
+            fitness: self.fitness.clone(),
+            bounds: self.bounds.clone(),
+            penalty: self.penalty,
+        }
+    }
+}
+
+impl<IndividualT, F> Fitness<IndividualT> for BoundedFitness<F>
+where
+    IndividualT: IndividualTrait<ChromosomeT = Vec<f64>, FitnessValueT = f64>,
+    F: Fitness<IndividualT>,
+{
+    fn apply(&mut self, individual: &mut IndividualT) -> f64 {
+        if self.bounds.contains(individual.chromosome()) {
+            self.fitness.apply(individual)
+        } else {
+            self.penalty
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundedFitness, Bounds, Repair};
+    use crate::ga::{
+        individual::RealValueIndividual,
+        operators::fitness::{Fitness, FnBasedFitness},
+    };
+
+    fn repaired(repair: Repair, value: f64) -> f64 {
+        Bounds::uniform(1, -1.0..3.0)
+            .with_repair(repair)
+            .repair_gene(0, value, &mut rand::thread_rng())
+    }
+
+    #[test]
+    fn feasible_genes_are_not_repaired() {
+        for repair in [Repair::Clamp, Repair::Reflect, Repair::Wrap, Repair::Resample] {
+            assert_eq!(repaired(repair, 2.5), 2.5);
+            assert_eq!(repaired(repair, 3.0), 3.0);
+        }
+    }
+
+    #[test]
+    fn repair_strategies_give_expected_values() {
+        assert_eq!(repaired(Repair::Clamp, 4.0), 3.0);
+        assert_eq!(repaired(Repair::Clamp, -7.0), -1.0);
+        assert_eq!(repaired(Repair::Reflect, 4.0), 2.0);
+        assert_eq!(repaired(Repair::Reflect, -2.0), 0.0);
+        assert_eq!(repaired(Repair::Reflect, 8.0), 0.0);
+        assert_eq!(repaired(Repair::Wrap, 4.0), 0.0);
+        assert_eq!(repaired(Repair::Wrap, -2.0), 2.0);
+        assert_eq!(repaired(Repair::DeathPenalty, 4.0), 4.0);
+
+        let resampled = repaired(Repair::Resample, 100.0);
+        assert!((-1.0..=3.0).contains(&resampled));
+    }
+
+    #[test]
+    fn bounded_fitness_penalizes_infeasible_individuals() {
+        #[allow(clippy::ptr_arg)]
+        fn sum(chromosome: &Vec<f64>) -> f64 {
+            chromosome.iter().sum()
+        }
+
+        let mut fitness = BoundedFitness::new(FnBasedFitness::new(sum), Bounds::uniform(2, 0.0..1.0), 1e9);
+
+        assert_eq!(
+            fitness.apply(&mut RealValueIndividual::from(vec![0.5, 0.25])),
+            0.75
+        );
+        assert_eq!(
+            fitness.apply(&mut RealValueIndividual::from(vec![0.5, 1.25])),
+            1e9
+        );
+    }
+}
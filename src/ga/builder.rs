@@ -15,9 +15,11 @@ use std::fmt::Display;
 use std::marker::PhantomData;
 
 use super::individual::{BitStringIndividual, IndividualTrait, RealValueIndividual};
+use super::operators::local_search::{LocalSearchStage, WriteBack};
 use super::operators::replacement::ReplacementOperator;
 use super::operators::selection::SelectionOperator;
 use super::population::PopulationGenerator;
+use super::termination_condition::BoxedTerminationCondition;
 use super::{CrossoverOperator, GAConfig, GAParams, MutationOperator, Probe};
 
 use crate::ga::operators::fitness::Fitness;
@@ -42,6 +44,7 @@ pub enum ConfigError {
     MissingPopulationFactory,
     NoProbe,
     NoParams,
+    IncompatibleOperators(String),
 }
 
 impl Display for ConfigError {
@@ -52,6 +55,7 @@ impl Display for ConfigError {
             Self::MissingPopulationFactory => write!(f, "Unspecified population factory"),
             Self::NoProbe => write!(f, "Unspecified probe"),
             Self::NoParams => write!(f, "No parameters were specified"),
+            Self::IncompatibleOperators(reason) => write!(f, "Incompatible operators: {reason}"),
         }
     }
 }
@@ -138,6 +142,13 @@ where
     pub replacement_operator: Option<ReplOpT>,
     pub population_factory: Option<PopGenT>,
     pub probe: Option<ProbeT>,
+    pub termination_condition: Option<BoxedTerminationCondition<IndividualT>>,
+    pub local_search: Option<LocalSearchStage<IndividualT>>,
+    pub seed: Option<u64>,
+    #[allow(clippy::type_complexity)]
+    pub(crate) reseeder: Option<
+        fn(&mut GAConfig<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>, u64),
+    >,
     _phantom: PhantomData<IndividualT>,
 }
 
@@ -164,6 +175,10 @@ where
             replacement_operator: None,
             population_factory: None,
             probe: None,
+            termination_condition: None,
+            local_search: None,
+            seed: None,
+            reseeder: None,
             _phantom: Default::default(),
         }
     }
@@ -219,6 +234,16 @@ where
             ));
         };
 
+        if let Some(local_search) = config_opt.local_search.as_ref() {
+            if local_search.write_back() == WriteBack::Baldwinian
+                && !replacement_operator.requires_children_fitness()
+            {
+                return Err(ConfigError::IncompatibleOperators(
+                    "Baldwinian local search requires replacement operator using children fitness".to_owned(),
+                ));
+            }
+        }
+
         let Some(population_factory) = config_opt.population_factory else {
             return Err(ConfigError::MissingPopulationFactory);
         };
@@ -227,7 +252,7 @@ where
             return Err(ConfigError::NoProbe);
         };
 
-        Ok(GAConfig {
+        let mut config = GAConfig {
             params,
             fitness_fn,
             mutation_operator,
@@ -236,8 +261,22 @@ where
             replacement_operator,
             population_factory,
             probe,
+            termination_condition: config_opt.termination_condition,
+            local_search: config_opt.local_search,
+            seed: None,
+            reseeder: config_opt.reseeder,
             _phantom: PhantomData,
-        })
+        };
+
+        if let Some(seed) = config_opt.seed {
+            if !config.reseed(seed) {
+                return Err(ConfigError::MissingParam(
+                    "Seed was specified for operators that can not be reseeded".to_owned(),
+                ));
+            }
+        }
+
+        Ok(config)
     }
 }
 
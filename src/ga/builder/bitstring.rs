@@ -3,7 +3,10 @@
 use crate::ga::builder::FitnessFn;
 use crate::ga::individual::{BitStringIndividual, IndividualTrait};
 use crate::ga::operators::fitness::{Fitness, FnBasedFitness};
+use crate::ga::operators::local_search::LocalSearchStage;
 use crate::ga::operators::replacement::BothParents;
+use crate::ga::seed::reseed_config;
+use crate::ga::termination_condition::TerminationCondition;
 use crate::ga::Individual;
 use crate::ga::{
     operators::{crossover::SinglePoint, mutation::FlipBit, selection::Tournament},
@@ -11,6 +14,7 @@ use crate::ga::{
     probe::StdoutProbe,
     GeneticSolver,
 };
+use rand::{rngs::StdRng, SeedableRng};
 
 use super::{DefaultParams, GAConfigOpt};
 
@@ -23,11 +27,11 @@ pub(super) type Bsc = Vec<bool>;
 pub struct BitStringBuilder<F: Fitness<BitStringIndividual>> {
     config: GAConfigOpt<
         Individual<Bsc>,
-        FlipBit<rand::rngs::ThreadRng>,
-        SinglePoint<rand::rngs::ThreadRng>,
-        Tournament<usize, rand::rngs::ThreadRng>,
+        FlipBit<StdRng>,
+        SinglePoint<StdRng>,
+        Tournament<usize, StdRng>,
         BothParents,
-        BitStrings<rand::rngs::ThreadRng>,
+        BitStrings<StdRng>,
         F,
         StdoutProbe,
     >,
@@ -89,6 +93,30 @@ impl<F: Fitness<BitStringIndividual>> BitStringBuilder<F> {
         self
     }
 
+    /// Sets additional termination condition. The algorithm halts when the condition is met,
+    /// even if generation limit or max duration have not been reached yet.
+    ///
+    /// ## Arguments
+    ///
+    /// * `condition` - struct implementing [TerminationCondition](crate::ga::termination_condition::TerminationCondition) trait
+    pub fn set_termination_condition<CondT>(mut self, condition: CondT) -> Self
+    where
+        CondT: TerminationCondition<BitStringIndividual> + Send + 'static,
+    {
+        self.config.termination_condition = Some(Box::new(condition));
+        self
+    }
+
+    /// Enables local search step (memetic algorithm), applied to children between mutation & replacement
+    ///
+    /// ## Arguments
+    ///
+    /// * `local_search` - local search stage, see [LocalSearchStage] for options
+    pub fn set_local_search(mut self, local_search: LocalSearchStage<BitStringIndividual>) -> Self {
+        self.config.local_search = Some(local_search);
+        self
+    }
+
     /// Sets dimension of the problem. Must be > 0.
     ///
     /// ## Arguments
@@ -110,6 +138,18 @@ impl<F: Fitness<BitStringIndividual>> BitStringBuilder<F> {
         self
     }
 
+    /// Sets seed of the run. Independent RNG streams derived from the seed are used by all
+    /// default operators & population generator, so runs with the same seed (& parameters) are identical.
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - seed of the run
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self.config.reseeder = Some(reseed_config);
+        self
+    }
+
     /// If all parameters were configured correctly then it returns ready-to-run instance
     /// of genetic algorithm. Else it panics.
     ///
@@ -124,15 +164,17 @@ impl<F: Fitness<BitStringIndividual>> BitStringBuilder<F> {
         mut self,
     ) -> GeneticSolver<
         Individual<Bsc>,
-        FlipBit<rand::rngs::ThreadRng>,
-        SinglePoint<rand::rngs::ThreadRng>,
-        Tournament<usize, rand::rngs::ThreadRng>,
+        FlipBit<StdRng>,
+        SinglePoint<StdRng>,
+        Tournament<usize, StdRng>,
         BothParents,
-        BitStrings<rand::rngs::ThreadRng>,
+        BitStrings<StdRng>,
         F,
         StdoutProbe,
     > {
         self.config.params.fill_from(&Self::DEFAULT_PARAMS);
+        // Default operators are seedable, so unseeded runs can still be reseeded (e.g. when checkpointed)
+        self.config.reseeder = Some(reseed_config);
 
         if self.config.fitness_fn.is_none() {
             panic!("Fitness function must be set");
@@ -144,19 +186,23 @@ impl<F: Fitness<BitStringIndividual>> BitStringBuilder<F> {
 
         self.config
             .crossover_operator
-            .get_or_insert_with(SinglePoint::new);
+            .get_or_insert_with(|| SinglePoint::with_rng(StdRng::from_entropy()));
         self.config
             .mutation_operator
-            .get_or_insert_with(|| FlipBit::new(0.05));
-        self.config
-            .selection_operator
-            .get_or_insert_with(|| Tournament::new(0.2, self.config.params.population_size.unwrap()));
+            .get_or_insert_with(|| FlipBit::with_rng(0.05, StdRng::from_entropy()));
+        self.config.selection_operator.get_or_insert_with(|| {
+            Tournament::with_rng(
+                0.2,
+                self.config.params.population_size.unwrap(),
+                StdRng::from_entropy(),
+            )
+        });
         self.config
             .replacement_operator
             .get_or_insert_with(BothParents::new);
         self.config
             .population_factory
-            .get_or_insert_with(|| BitStrings::new(self.dim.unwrap_or(10)));
+            .get_or_insert_with(|| BitStrings::with_rng(self.dim.unwrap_or(10), StdRng::from_entropy()));
         self.config.probe.get_or_insert_with(StdoutProbe::new);
 
         // GeneticSolver::new(self.config.into())
@@ -3,7 +3,10 @@
 use crate::ga::builder::FitnessFn;
 use crate::ga::individual::IndividualTrait;
 use crate::ga::operators::fitness::{Fitness, FnBasedFitness};
+use crate::ga::operators::local_search::LocalSearchStage;
 use crate::ga::operators::replacement::ReplacementOperator;
+use crate::ga::seed::{reseed_config, Reseed};
+use crate::ga::termination_condition::TerminationCondition;
 use crate::ga::{
     operators::{crossover::CrossoverOperator, mutation::MutationOperator, selection::SelectionOperator},
     population::PopulationGenerator,
@@ -115,6 +118,30 @@ where
         self
     }
 
+    /// Sets additional termination condition. The algorithm halts when the condition is met,
+    /// even if generation limit or max duration have not been reached yet.
+    ///
+    /// ## Arguments
+    ///
+    /// * `condition` - struct implementing [TerminationCondition](crate::ga::termination_condition::TerminationCondition) trait
+    pub fn set_termination_condition<CondT>(mut self, condition: CondT) -> Self
+    where
+        CondT: TerminationCondition<IndividualT> + Send + 'static,
+    {
+        self.config.termination_condition = Some(Box::new(condition));
+        self
+    }
+
+    /// Enables local search step (memetic algorithm), applied to children between mutation & replacement
+    ///
+    /// ## Arguments
+    ///
+    /// * `local_search` - local search stage, see [LocalSearchStage] for options
+    pub fn set_local_search(mut self, local_search: LocalSearchStage<IndividualT>) -> Self {
+        self.config.local_search = Some(local_search);
+        self
+    }
+
     /// Sets fitness
     ///
     /// ## Arguments
@@ -204,6 +231,35 @@ where
     }
 }
 
+impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+    GenericBuilder<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+where
+    IndividualT: IndividualTrait,
+    MutOpT: MutationOperator<IndividualT> + Reseed,
+    CrossOpT: CrossoverOperator<IndividualT> + Reseed,
+    SelOpT: SelectionOperator<IndividualT> + Reseed,
+    PopGenT: PopulationGenerator<IndividualT> + Reseed,
+    ReplOpT: ReplacementOperator<IndividualT> + Reseed,
+    FitnessT: Fitness<IndividualT>,
+    ProbeT: Probe<IndividualT>,
+{
+    /// Sets seed of the run. Independent RNG streams derived from the seed replace RNGs
+    /// of all operators & population generator, so runs with the same seed (& configuration)
+    /// are identical.
+    ///
+    /// Available only if all operators & population generator implement [Reseed], e.g. were created
+    /// with [StdRng](rand::rngs::StdRng). See [seed](crate::ga::seed) module for details.
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - seed of the run
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self.config.reseeder = Some(reseed_config);
+        self
+    }
+}
+
 impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT> DefaultParams
     for GenericBuilder<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
 where
@@ -1,15 +1,20 @@
 //! Builder implementation with default values for problems with real valued chromosome
 
+use crate::ga::bounds::Bounds;
 use crate::ga::builder::FitnessFn;
 use crate::ga::individual::{IndividualTrait, RealValueIndividual};
 use crate::ga::operators::fitness::{Fitness, FnBasedFitness};
+use crate::ga::operators::local_search::LocalSearchStage;
 use crate::ga::operators::replacement::BothParents;
+use crate::ga::seed::reseed_config;
+use crate::ga::termination_condition::TerminationCondition;
 use crate::ga::{
-    operators::{crossover::SinglePoint, mutation::Interchange, selection::Tournament},
+    operators::{crossover::Sbx, mutation::Polynomial, selection::Tournament},
     population::RandomPoints,
     probe::StdoutProbe,
 };
 use crate::ga::{GeneticSolver, Individual};
+use rand::{rngs::StdRng, SeedableRng};
 
 use super::{DefaultParams, GAConfigOpt};
 
@@ -18,19 +23,24 @@ pub(super) type Rvc = Vec<f64>;
 /// [RealValuedBuilder] provides all the operators by default. These can not be modified,
 /// altough all the parameters can.
 ///
+/// Default mutation ([Polynomial]) & crossover ([Sbx]) operators are bound-aware: they share
+/// [Bounds] with the population generator (see [RealValuedBuilder::set_bounds]), `[0, 1]` in every
+/// dimension when no bounds are set.
+///
 /// If more configuration options are desired please see [GenericBuilder](super::generic::GenericBuilder).
 pub struct RealValuedBuilder<F: Fitness<RealValueIndividual>> {
     config: GAConfigOpt<
         Individual<Rvc>,
-        Interchange<rand::rngs::ThreadRng>,
-        SinglePoint<rand::rngs::ThreadRng>,
-        Tournament<usize, rand::rngs::ThreadRng>,
+        Polynomial<StdRng>,
+        Sbx<StdRng>,
+        Tournament<usize, StdRng>,
         BothParents,
-        RandomPoints<rand::rngs::ThreadRng>,
+        RandomPoints<StdRng>,
         F,
         StdoutProbe,
     >,
     dim: Option<usize>,
+    bounds: Option<Bounds>,
 }
 
 impl RealValuedBuilder<FnBasedFitness<RealValueIndividual>> {
@@ -53,6 +63,7 @@ impl<F: Fitness<RealValueIndividual>> RealValuedBuilder<F> {
         RealValuedBuilder {
             config: GAConfigOpt::new(),
             dim: None,
+            bounds: None,
         }
     }
 
@@ -88,6 +99,30 @@ impl<F: Fitness<RealValueIndividual>> RealValuedBuilder<F> {
         self
     }
 
+    /// Sets additional termination condition. The algorithm halts when the condition is met,
+    /// even if generation limit or max duration have not been reached yet.
+    ///
+    /// ## Arguments
+    ///
+    /// * `condition` - struct implementing [TerminationCondition](crate::ga::termination_condition::TerminationCondition) trait
+    pub fn set_termination_condition<CondT>(mut self, condition: CondT) -> Self
+    where
+        CondT: TerminationCondition<RealValueIndividual> + Send + 'static,
+    {
+        self.config.termination_condition = Some(Box::new(condition));
+        self
+    }
+
+    /// Enables local search step (memetic algorithm), applied to children between mutation & replacement
+    ///
+    /// ## Arguments
+    ///
+    /// * `local_search` - local search stage, see [LocalSearchStage] for options
+    pub fn set_local_search(mut self, local_search: LocalSearchStage<RealValueIndividual>) -> Self {
+        self.config.local_search = Some(local_search);
+        self
+    }
+
     /// Sets dimension of the problem. Must be > 0.
     ///
     /// ## Arguments
@@ -99,6 +134,18 @@ impl<F: Fitness<RealValueIndividual>> RealValuedBuilder<F> {
         self
     }
 
+    /// Sets domain of the problem. Initial population is sampled from the bounds & default mutation
+    /// and crossover operators keep genes within them, using repair strategy of the bounds.
+    /// If problem dimension is not set explicitly, it is taken from the bounds.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` - Domain of the problem
+    pub fn set_bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
     /// Sets fitness
     ///
     /// ## Arguments
@@ -109,6 +156,18 @@ impl<F: Fitness<RealValueIndividual>> RealValuedBuilder<F> {
         self
     }
 
+    /// Sets seed of the run. Independent RNG streams derived from the seed are used by all
+    /// default operators & population generator, so runs with the same seed (& parameters) are identical.
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - seed of the run
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self.config.reseeder = Some(reseed_config);
+        self
+    }
+
     /// If all parameters were configured correctly then it returns ready-to-run instance
     /// of genetic algorithm. Else it panics.
     ///
@@ -118,44 +177,66 @@ impl<F: Fitness<RealValueIndividual>> RealValuedBuilder<F> {
     ///
     /// * fitness function is not specified
     /// * any of the params has invalid value
-    /// * problem dimension is not set
+    /// * problem dimension is neither set nor can be inferred from bounds
+    /// * problem dimension does not match dimension of bounds
     pub fn build(
         mut self,
     ) -> GeneticSolver<
         Individual<Rvc>,
-        Interchange<rand::rngs::ThreadRng>,
-        SinglePoint<rand::rngs::ThreadRng>,
-        Tournament<usize, rand::rngs::ThreadRng>,
+        Polynomial<StdRng>,
+        Sbx<StdRng>,
+        Tournament<usize, StdRng>,
         BothParents,
-        RandomPoints<rand::rngs::ThreadRng>,
+        RandomPoints<StdRng>,
         F,
         StdoutProbe,
     > {
         self.config.params.fill_from(&Self::DEFAULT_PARAMS);
+        // Default operators are seedable, so unseeded runs can still be reseeded (e.g. when checkpointed)
+        self.config.reseeder = Some(reseed_config);
 
         if self.config.fitness_fn.is_none() {
             panic!("Fitness function must be set");
         }
 
+        if let Some(bounds) = self.bounds.as_ref() {
+            let dim = *self.dim.get_or_insert(bounds.dim());
+            assert_eq!(
+                dim,
+                bounds.dim(),
+                "Problem dimension must match dimension of bounds"
+            );
+        }
+
         if self.dim.is_none() {
             panic!("Problem dimension must be set");
         }
 
+        let dim = self.dim.unwrap();
+        let bounds = self
+            .bounds
+            .take()
+            .unwrap_or_else(|| Bounds::uniform(dim, 0.0..1.0));
+
         self.config
             .crossover_operator
-            .get_or_insert_with(SinglePoint::new);
-        self.config
-            .mutation_operator
-            .get_or_insert_with(|| Interchange::new(0.05));
-        self.config
-            .selection_operator
-            .get_or_insert_with(|| Tournament::new(0.2, self.config.params.population_size.unwrap()));
+            .get_or_insert_with(|| Sbx::with_rng(20.0, bounds.clone(), StdRng::from_entropy()));
+        self.config.mutation_operator.get_or_insert_with(|| {
+            Polynomial::with_rng(1.0 / dim as f64, 20.0, bounds.clone(), StdRng::from_entropy())
+        });
+        self.config.selection_operator.get_or_insert_with(|| {
+            Tournament::with_rng(
+                0.2,
+                self.config.params.population_size.unwrap(),
+                StdRng::from_entropy(),
+            )
+        });
         self.config
             .replacement_operator
             .get_or_insert_with(BothParents::new);
         self.config
             .population_factory
-            .get_or_insert_with(|| RandomPoints::new(self.dim.unwrap()));
+            .get_or_insert_with(|| RandomPoints::with_bounds_and_rng(bounds, StdRng::from_entropy()));
         self.config.probe.get_or_insert_with(StdoutProbe::new);
 
         let config = match self.config.try_into() {
@@ -0,0 +1,394 @@
+//! Checkpointing of long running genetic algorithm
+//!
+//! [GeneticSolver::run_with_checkpoints] periodically serializes state of the solver
+//! (population, best individual found so far & [Metrics], including generation counter) to a JSON file.
+//! When the computations get interrupted, [GeneticSolver::resume_from] restores the state from such file
+//! & continues the run.
+//!
+//! Random number generators can not be serialized directly. Instead, seeded solver reseeds its operators at
+//! the beginning of every generation with a seed derived from run's seed & generation number (see [seed](crate::ga::seed)
+//! module), so the RNG state is fully described by the seed & generation counter stored in the checkpoint.
+//! When no seed is set, [GeneticSolver::run_with_checkpoints] draws one. Internal state of termination condition
+//! (see [TerminationCondition::state](crate::ga::termination_condition::TerminationCondition::state)) is stored as well.
+//! Resumed run therefore continues **exactly** where the interrupted one stopped & its result is the same as the one
+//! of [GeneticSolver::run] with the same seed.
+//!
+//! This requires operators supporting reseeding, which is the case for solvers created with
+//! [Builder::with_rvc](crate::ga::Builder::with_rvc) & [Builder::with_bsc](crate::ga::Builder::with_bsc), but for
+//! the generic builder only when [GenericBuilder::set_seed](crate::ga::builder::GenericBuilder::set_seed) was called.
+//! For other solvers both [GeneticSolver::run_with_checkpoints] & [GeneticSolver::resume_from] fail with
+//! [io::ErrorKind::Unsupported] error.
+//!
+//! **Note**: Time limit is counted from the start of the original run (elapsed time is stored in the checkpoint).
+//!
+//! ```no_run
+//! # use ecrs::ga;
+//! # #[allow(clippy::ptr_arg)]
+//! # fn sphere(x: &Vec<f64>) -> f64 { x.iter().map(|xi| xi * xi).sum() }
+//! let mut solver = ga::Builder::with_rvc()
+//!     .fitness_fn(sphere)
+//!     .dim(10)
+//!     .set_max_generation_count(100_000)
+//!     .set_seed(42)
+//!     .build();
+//!
+//! let path = std::path::Path::new("ga_checkpoint.json");
+//! let best = if path.exists() {
+//!     solver.resume_from(path)
+//! } else {
+//!     solver.run_with_checkpoints(path, 1000)
+//! }
+//! .expect("Failed to read or write checkpoint");
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use rand::Rng;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{
+    individual::IndividualTrait,
+    operators::{
+        crossover::CrossoverOperator, fitness::Fitness, mutation::MutationOperator,
+        replacement::ReplacementOperator, selection::SelectionOperator,
+    },
+    population::PopulationGenerator,
+    GeneticSolver, Metrics, Probe,
+};
+
+/// Serializable state of [GeneticSolver]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Checkpoint<IndividualT> {
+    /// Population after last finished generation
+    pub population: Vec<IndividualT>,
+    /// Best individual found so far
+    pub best_individual: Option<IndividualT>,
+    /// Metrics of the run, including generation counter
+    pub metrics: Metrics,
+    /// Seed of the run (if any)
+    pub seed: Option<u64>,
+    /// Number of generations between consecutive checkpoints
+    pub interval: usize,
+    /// Internal state of termination condition (if any)
+    #[serde(default)]
+    pub termination_state: Option<serde_json::Value>,
+    /// Whether termination condition has been met
+    #[serde(default)]
+    pub terminated: bool,
+}
+
+impl<IndividualT: Serialize + DeserializeOwned> Checkpoint<IndividualT> {
+    /// Writes the checkpoint to file under `path`.
+    ///
+    /// The checkpoint is written to a temporary file first & then moved to `path`,
+    /// so that previous checkpoint is not lost when the process gets killed while writing.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - path of the checkpoint file
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        drop(writer);
+
+        std::fs::rename(tmp_path, path)
+    }
+
+    /// Reads checkpoint from file under `path`
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - path of the checkpoint file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+    GeneticSolver<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+where
+    IndividualT: IndividualTrait + Serialize + DeserializeOwned,
+    MutOpT: MutationOperator<IndividualT>,
+    CrossOpT: CrossoverOperator<IndividualT>,
+    SelOpT: SelectionOperator<IndividualT>,
+    ReplOpT: ReplacementOperator<IndividualT>,
+    PopGenT: PopulationGenerator<IndividualT>,
+    FitnessT: Fitness<IndividualT>,
+    ProbeT: Probe<IndividualT>,
+{
+    /// Returns snapshot of current state of the solver
+    ///
+    /// ## Arguments
+    ///
+    /// * `interval` - number of generations between consecutive checkpoints, stored for resumed run
+    pub fn checkpoint(&self, interval: usize) -> Checkpoint<IndividualT> {
+        Checkpoint {
+            population: self.population.clone(),
+            best_individual: self.best_individual_all_time.clone(),
+            metrics: self.metrics.clone(),
+            seed: self.config.seed,
+            interval,
+            termination_state: self
+                .config
+                .termination_condition
+                .as_ref()
+                .and_then(|condition| condition.state()),
+            terminated: self.termination_condition_met,
+        }
+    }
+
+    /// Runs the algorithm same as [GeneticSolver::run] does, writing checkpoint to `path`
+    /// every `interval` generations.
+    ///
+    /// If the solver is not seeded, random seed is drawn & stored in checkpoints,
+    /// so that resumed run is reproducible.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - path of the checkpoint file, overwritten with every checkpoint
+    /// * `interval` - number of generations between consecutive checkpoints, must be > 0
+    ///
+    /// ## Errors
+    ///
+    /// Iff checkpoint could not be written (the run is interrupted in such case) or the operators
+    /// do not support reseeding (see [module documentation](self)), in which case the run does not start.
+    pub fn run_with_checkpoints(
+        &mut self,
+        path: impl AsRef<Path>,
+        interval: usize,
+    ) -> io::Result<Option<IndividualT>> {
+        assert!(interval > 0, "Checkpoint interval must be > 0");
+        let seed = self.config.seed.unwrap_or_else(|| rand::thread_rng().gen());
+        if !self.config.reseed(seed) {
+            return Err(not_reseedable());
+        }
+        self.initialize();
+        self.run_checkpointed(path.as_ref(), interval)
+    }
+
+    /// Restores state of the solver from checkpoint written by [GeneticSolver::run_with_checkpoints]
+    /// & continues the run, writing further checkpoints to the same file with the same interval.
+    ///
+    /// The solver must be configured the same way as the one that created the checkpoint.
+    /// Seed stored in the checkpoint takes precedence over the one set in the solver.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - path of the checkpoint file
+    ///
+    /// ## Errors
+    ///
+    /// Iff checkpoint could not be read or written, or the operators do not support reseeding
+    /// (see [module documentation](self)).
+    pub fn resume_from(&mut self, path: impl AsRef<Path>) -> io::Result<Option<IndividualT>> {
+        let path = path.as_ref();
+        let checkpoint = Checkpoint::load(path)?;
+        let interval = checkpoint.interval;
+        self.restore(checkpoint)?;
+        self.run_checkpointed(path, interval)
+    }
+
+    /// Sets state of the solver to the one from the checkpoint, so that the run can be continued.
+    fn restore(&mut self, checkpoint: Checkpoint<IndividualT>) -> io::Result<()> {
+        let Some(seed) = checkpoint.seed.or(self.config.seed) else {
+            return Err(not_reseedable());
+        };
+        if !self.config.reseed(seed) {
+            return Err(not_reseedable());
+        }
+
+        self.metrics = checkpoint.metrics;
+        let elapsed = self.metrics.total_dur.unwrap_or_default();
+        let now = std::time::Instant::now();
+        self.metrics.start_time = Some(now.checked_sub(elapsed).unwrap_or(now));
+
+        self.population = checkpoint.population;
+        self.best_individual_all_time = checkpoint.best_individual;
+
+        self.config.probe.on_start(&self.metrics);
+
+        self.termination_condition_met = checkpoint.terminated;
+        if let Some(condition) = self.config.termination_condition.as_mut() {
+            condition.init(&self.metrics, &self.population);
+            if let (Some(state), Some(best_individual)) = (
+                checkpoint.termination_state,
+                self.best_individual_all_time.as_ref(),
+            ) {
+                condition.restore_state(state, best_individual);
+            }
+        }
+        Ok(())
+    }
+
+    fn run_checkpointed(&mut self, path: &Path, interval: usize) -> io::Result<Option<IndividualT>> {
+        let mut next_checkpoint = (self.metrics.generation / interval + 1) * interval;
+        while !self.is_finished() {
+            self.evolve();
+
+            if self.metrics.generation == next_checkpoint {
+                next_checkpoint += interval;
+                self.checkpoint(interval).save(path)?;
+            }
+        }
+        Ok(self.finalize())
+    }
+}
+
+fn not_reseedable() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Checkpointed run requires operators supporting reseeding",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Checkpoint;
+    use crate::ga::{
+        individual::RealValueIndividual,
+        operators::{
+            crossover::SinglePoint, fitness::FnBasedFitness, mutation::Identity, replacement::BothParents,
+            selection::Tournament,
+        },
+        population::RandomPoints,
+        probe::EmptyProbe,
+        Builder,
+    };
+
+    #[allow(clippy::ptr_arg)]
+    fn sphere(x: &Vec<f64>) -> f64 {
+        x.iter().map(|xi| xi * xi).sum()
+    }
+
+    fn checkpoint_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ecrs_{}_{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_one() {
+        let solver = |generation_limit: usize| {
+            Builder::with_rvc()
+                .fitness_fn(sphere)
+                .dim(3)
+                .set_population_size(20)
+                .set_max_generation_count(generation_limit)
+                .set_seed(7)
+                .build()
+        };
+
+        let full_path = checkpoint_path("full_run");
+        let interrupted_path = checkpoint_path("interrupted_run");
+
+        let expected = solver(30).run_with_checkpoints(&full_path, 5).unwrap().unwrap();
+
+        solver(10).run_with_checkpoints(&interrupted_path, 5).unwrap();
+        let checkpoint: Checkpoint<RealValueIndividual> = Checkpoint::load(&interrupted_path).unwrap();
+        assert_eq!(checkpoint.metrics.generation, 10);
+
+        let mut resumed_solver = solver(30);
+        let actual = resumed_solver.resume_from(&interrupted_path).unwrap().unwrap();
+
+        std::fs::remove_file(full_path).unwrap();
+        std::fs::remove_file(interrupted_path).unwrap();
+
+        assert_eq!(resumed_solver.metrics().generation, 30);
+        assert_eq!(expected.chromosome, actual.chromosome);
+        assert_eq!(expected.fitness, actual.fitness);
+    }
+
+    #[test]
+    fn checkpointed_run_matches_plain_one() {
+        let solver = || {
+            Builder::with_rvc()
+                .fitness_fn(sphere)
+                .dim(3)
+                .set_population_size(20)
+                .set_max_generation_count(20)
+                .set_seed(11)
+                .build()
+        };
+        let path = checkpoint_path("plain_run");
+
+        let expected = solver().run().unwrap();
+        let actual = solver().run_with_checkpoints(&path, 3).unwrap().unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(expected.chromosome, actual.chromosome);
+    }
+
+    #[test]
+    fn unseeded_run_is_reproducible_after_resume() {
+        let solver = |generation_limit: usize| {
+            Builder::with_rvc()
+                .fitness_fn(sphere)
+                .dim(3)
+                .set_population_size(20)
+                .set_max_generation_count(generation_limit)
+                .build()
+        };
+        let path = checkpoint_path("unseeded_run");
+        let copy_path = checkpoint_path("unseeded_run_copy");
+
+        solver(10).run_with_checkpoints(&path, 10).unwrap();
+        let checkpoint: Checkpoint<RealValueIndividual> = Checkpoint::load(&path).unwrap();
+        assert!(checkpoint.seed.is_some());
+        std::fs::copy(&path, &copy_path).unwrap();
+
+        let first = solver(20).resume_from(&path).unwrap().unwrap();
+        let second = solver(20).resume_from(&copy_path).unwrap().unwrap();
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(copy_path).unwrap();
+
+        assert_eq!(first.chromosome, second.chromosome);
+    }
+
+    #[test]
+    fn checkpointing_fails_when_operators_can_not_be_reseeded() {
+        let path = checkpoint_path("not_reseedable_run");
+        Builder::with_rvc()
+            .fitness_fn(sphere)
+            .dim(3)
+            .set_population_size(20)
+            .set_max_generation_count(10)
+            .set_seed(3)
+            .build()
+            .run_with_checkpoints(&path, 5)
+            .unwrap();
+
+        let mut solver = Builder::new::<
+            RealValueIndividual,
+            Identity,
+            SinglePoint,
+            Tournament<usize>,
+            BothParents,
+            RandomPoints,
+            FnBasedFitness<RealValueIndividual>,
+            EmptyProbe,
+        >()
+        .set_fitness_fn(sphere)
+        .set_population_generator(RandomPoints::with_constraints(3, vec![-1.0..1.0; 3]))
+        .set_selection_operator(Tournament::new(0.2, 20))
+        .set_crossover_operator(SinglePoint::new())
+        .set_mutation_operator(Identity::new())
+        .set_replacement_operator(BothParents::new())
+        .set_probe(EmptyProbe::new())
+        .set_population_size(20)
+        .set_max_generation_count(20)
+        .build();
+
+        let resume_error = solver.resume_from(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        let run_error = solver.run_with_checkpoints(&path, 5).unwrap_err();
+
+        assert_eq!(resume_error.kind(), std::io::ErrorKind::Unsupported);
+        assert_eq!(run_error.kind(), std::io::ErrorKind::Unsupported);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,658 @@
+//! Constraint handling for genetic algorithm
+//!
+//! Constrained problems are described by objective ([Fitness]) & constraints ([Constraints]), the latter
+//! returning aggregated measure of violation of given solution (0 for feasible solutions, positive value otherwise;
+//! see [total_violation] helper).
+//!
+//! The crate provides following techniques:
+//!
+//! * [StaticPenalty] & [AdaptivePenalty] - fitness wrappers adding penalty proportional to violation
+//!   to the objective value; they follow the convention of the solver for plain individuals
+//!   (e.g. [RealValueIndividual](crate::ga::individual::RealValueIndividual)), where smaller fitness is better,
+//!   so they are meant for minimisation problems,
+//! * Deb's feasibility rules - [ConstrainedIndividual] evaluated with [ConstrainedFitness]
+//!   stores violation alongside the objective value & is ordered with [feasibility_first],
+//!   thus every operator relying on `Ord` (e.g. [Tournament](crate::ga::operators::selection::Tournament)) prefers
+//!   feasible individuals,
+//! * [StochasticRanking] - selection operator balancing objective & violation as proposed by T. P. Runarsson & X. Yao.
+//!
+//! Contrary to plain individuals, greater fitness is considered better for [ConstrainedIndividual].
+//! Consistently with [feasibility_first], the solver reports the greatest [ConstrainedIndividual] as the best one
+//! (see [IndividualTrait::is_better_than]).
+//!
+//! Violation is read with [IndividualTrait::constraint_violation].
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use super::{
+    individual::{Chromosome, ConstrainedIndividual, IndividualTrait},
+    operators::{fitness::Fitness, selection::SelectionOperator},
+    seed::Reseed,
+    value_provider::ValueProvider,
+    Metrics,
+};
+
+/// # Constraints
+///
+/// This trait defines common behaviour for constraints of optimisation problem.
+/// You can implement this trait to provide your custom (possibly stateful) constraints.
+pub trait Constraints<ChromosomeT> {
+    /// Returns measure of constraint violation of given chromosome: 0 if all constraints are satisfied,
+    /// positive value otherwise
+    ///
+    /// ## Arguments
+    ///
+    /// * `chromosome` - chromosome to evaluate
+    fn violation(&mut self, chromosome: &ChromosomeT) -> f64;
+}
+
+/// Constraints defined by pointer to a function returning violation measure
+pub struct FnBasedConstraints<ChromosomeT> {
+    fn_ptr: fn(&ChromosomeT) -> f64,
+}
+
+impl<ChromosomeT> FnBasedConstraints<ChromosomeT> {
+    pub fn new(fn_ptr: fn(&ChromosomeT) -> f64) -> Self {
+        FnBasedConstraints { fn_ptr }
+    }
+}
+
+impl<ChromosomeT> Clone for FnBasedConstraints<ChromosomeT> {
+    fn clone(&self) -> Self {
+        Self { fn_ptr: self.fn_ptr }
+    }
+}
+
+impl<ChromosomeT> Constraints<ChromosomeT> for FnBasedConstraints<ChromosomeT> {
+    fn violation(&mut self, chromosome: &ChromosomeT) -> f64 {
+        (self.fn_ptr)(chromosome)
+    }
+}
+
+/// Returns total violation of constraints `g_i(x) <= 0` & `h_j(x) = 0`, i.e.
+/// `sum(max(0, g_i(x))) + sum(max(0, |h_j(x)| - tolerance))`.
+///
+/// ## Arguments
+///
+/// * `inequalities` - values `g_i(x)` of inequality constraints
+/// * `equalities` - values `h_j(x)` of equality constraints
+/// * `tolerance` - tolerance of equality constraints, usually small positive number, e.g. 1e-4
+pub fn total_violation(inequalities: &[f64], equalities: &[f64], tolerance: f64) -> f64 {
+    let inequality_violation: f64 = inequalities.iter().map(|g| g.max(0.0)).sum();
+    let equality_violation: f64 = equalities.iter().map(|h| (h.abs() - tolerance).max(0.0)).sum();
+    inequality_violation + equality_violation
+}
+
+/// Compares individuals according to Deb's feasibility rules. Greater individual is the better one:
+///
+/// 1. feasible individual is better than infeasible one,
+/// 2. out of two feasible individuals the one with greater fitness is better,
+/// 3. out of two infeasible individuals the one with smaller violation is better.
+///
+/// Incomparable fitness values (e.g. NaN) are considered equal.
+pub fn feasibility_first<IndividualT>(lhs: &IndividualT, rhs: &IndividualT) -> Ordering
+where
+    IndividualT: IndividualTrait,
+    IndividualT::FitnessValueT: PartialOrd,
+{
+    let lhs_violation = lhs.constraint_violation();
+    let rhs_violation = rhs.constraint_violation();
+
+    match (lhs_violation <= 0.0, rhs_violation <= 0.0) {
+        (true, true) => lhs
+            .fitness()
+            .partial_cmp(&rhs.fitness())
+            .unwrap_or(Ordering::Equal),
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => rhs_violation
+            .partial_cmp(&lhs_violation)
+            .unwrap_or(Ordering::Equal),
+    }
+}
+
+/// # Constrained fitness
+///
+/// Wrapper around [Fitness] & [Constraints], which evaluates [ConstrainedIndividual]. Violation is stored
+/// in the individual & raw objective value is returned as fitness, so that [feasibility_first] rules
+/// (or [StochasticRanking]) can be applied.
+pub struct ConstrainedFitness<F, C> {
+    fitness: F,
+    constraints: C,
+}
+
+impl<F, C> ConstrainedFitness<F, C> {
+    /// Returns new instance of [ConstrainedFitness]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - objective function
+    /// * `constraints` - constraints of the problem
+    pub fn new(fitness: F, constraints: C) -> Self {
+        Self { fitness, constraints }
+    }
+}
+
+impl<F: Clone, C: Clone> Clone for ConstrainedFitness<F, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.fitness.clone(), self.constraints.clone())
+    }
+}
+
+impl<T, F, C> Fitness<ConstrainedIndividual<T>> for ConstrainedFitness<F, C>
+where
+    T: Chromosome,
+    F: Fitness<ConstrainedIndividual<T>>,
+    C: Constraints<T>,
+{
+    fn apply(&mut self, individual: &mut ConstrainedIndividual<T>) -> f64 {
+        individual.violation = self.constraints.violation(individual.chromosome());
+        self.fitness.apply(individual)
+    }
+}
+
+/// # Static penalty
+///
+/// Fitness wrapper returning `objective + coefficient * violation`, i.e. meant for minimisation
+/// (see [module documentation](self)).
+///
+/// **Note**: Choosing the coefficient is problem specific - too small one leads to infeasible solutions,
+/// too large one makes exploration of infeasible regions impossible. Consider [AdaptivePenalty].
+pub struct StaticPenalty<F, C> {
+    fitness: F,
+    constraints: C,
+    coefficient: f64,
+}
+
+impl<F, C> StaticPenalty<F, C> {
+    /// Returns new instance of [StaticPenalty]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - objective function
+    /// * `constraints` - constraints of the problem
+    /// * `coefficient` - penalty per unit of violation, must be >= 0
+    pub fn new(fitness: F, constraints: C, coefficient: f64) -> Self {
+        assert!(coefficient >= 0.0, "Penalty coefficient must be >= 0");
+        Self {
+            fitness,
+            constraints,
+            coefficient,
+        }
+    }
+}
+
+impl<F: Clone, C: Clone> Clone for StaticPenalty<F, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.fitness.clone(), self.constraints.clone(), self.coefficient)
+    }
+}
+
+impl<IndividualT, F, C> Fitness<IndividualT> for StaticPenalty<F, C>
+where
+    IndividualT: IndividualTrait<FitnessValueT = f64>,
+    F: Fitness<IndividualT>,
+    C: Constraints<IndividualT::ChromosomeT>,
+{
+    fn apply(&mut self, individual: &mut IndividualT) -> f64 {
+        let violation = self.constraints.violation(individual.chromosome());
+        self.fitness.apply(individual) + self.coefficient * violation
+    }
+}
+
+/// # Adaptive penalty
+///
+/// Fitness wrapper returning `objective + coefficient * violation` (meant for minimisation, see
+/// [module documentation](self)), where the coefficient
+/// is adapted as proposed by J. C. Bean & A. B. Hadj-Alouane: if the best individual of each
+/// of the last `window` evaluated batches (see [Fitness::apply_batch]) was feasible, the coefficient is divided
+/// by `decrease_factor`; if it was infeasible in each of them, the coefficient is multiplied by `increase_factor`.
+/// Otherwise it stays unchanged.
+pub struct AdaptivePenalty<F, C> {
+    fitness: F,
+    constraints: C,
+    coefficient: f64,
+    window: usize,
+    decrease_factor: f64,
+    increase_factor: f64,
+    history: VecDeque<bool>,
+}
+
+impl<F, C> AdaptivePenalty<F, C> {
+    /// Returns new instance of [AdaptivePenalty]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - objective function
+    /// * `constraints` - constraints of the problem
+    /// * `initial_coefficient` - initial penalty per unit of violation, must be > 0
+    /// * `window` - number of batches considered when adapting the coefficient, must be > 0
+    /// * `decrease_factor` - must be > 1
+    /// * `increase_factor` - must be > 1 & should differ from `decrease_factor` to avoid cycling
+    pub fn new(
+        fitness: F,
+        constraints: C,
+        initial_coefficient: f64,
+        window: usize,
+        decrease_factor: f64,
+        increase_factor: f64,
+    ) -> Self {
+        assert!(initial_coefficient > 0.0, "Penalty coefficient must be > 0");
+        assert!(window > 0, "Window must be > 0");
+        assert!(
+            decrease_factor > 1.0 && increase_factor > 1.0,
+            "Adaptation factors must be > 1"
+        );
+        Self {
+            fitness,
+            constraints,
+            coefficient: initial_coefficient,
+            window,
+            decrease_factor,
+            increase_factor,
+            history: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// Returns current penalty coefficient
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    /// Records feasibility of the best individual of last batch & adapts the coefficient
+    fn adapt(&mut self, best_is_feasible: bool) {
+        if self.history.len() == self.window {
+            self.history.pop_front();
+        }
+        self.history.push_back(best_is_feasible);
+
+        if self.history.len() < self.window {
+            return;
+        }
+
+        if self.history.iter().all(|feasible| *feasible) {
+            self.coefficient /= self.decrease_factor;
+        } else if self.history.iter().all(|feasible| !*feasible) {
+            self.coefficient *= self.increase_factor;
+        }
+    }
+}
+
+impl<IndividualT, F, C> Fitness<IndividualT> for AdaptivePenalty<F, C>
+where
+    IndividualT: IndividualTrait<FitnessValueT = f64>,
+    F: Fitness<IndividualT>,
+    C: Constraints<IndividualT::ChromosomeT>,
+{
+    fn apply(&mut self, individual: &mut IndividualT) -> f64 {
+        let violation = self.constraints.violation(individual.chromosome());
+        self.fitness.apply(individual) + self.coefficient * violation
+    }
+
+    /// Evaluates every individual from `population` that requires evaluation & adapts
+    /// penalty coefficient based on feasibility of the best one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `population` - individuals to evaluate
+    fn apply_batch(&mut self, population: &mut [IndividualT]) {
+        // (penalized fitness, is feasible) of the best individual in the batch
+        let mut best: Option<(f64, bool)> = None;
+
+        for idv in population.iter_mut().filter(|idv| idv.requires_evaluation()) {
+            let violation = self.constraints.violation(idv.chromosome());
+            let penalized = self.fitness.apply(idv) + self.coefficient * violation;
+            *idv.fitness_mut() = penalized;
+
+            let is_better = match best {
+                Some((best_fitness, _)) => penalized < best_fitness,
+                None => true,
+            };
+            if is_better {
+                best = Some((penalized, violation <= 0.0));
+            }
+        }
+
+        if let Some((_, best_is_feasible)) = best {
+            self.adapt(best_is_feasible);
+        }
+    }
+}
+
+/// ### Stochastic ranking selection operator
+///
+/// This struct implements [SelectionOperator] trait and can be used with GA
+///
+/// Population is ranked with stochastic bubble sort proposed by T. P. Runarsson & X. Yao: adjacent
+/// individuals are compared by fitness if both are feasible or with probability `objective_probability`,
+/// by constraint violation otherwise. Mating pool is then filled by binary tournaments, each won by the
+/// individual ranked higher.
+///
+/// *Note*: The same individual can be selected multiple times
+pub struct StochasticRanking<SizeValue: ValueProvider<usize>, R: Rng = ThreadRng> {
+    objective_probability: f64,
+    selection_size: SizeValue,
+    rng: R,
+}
+
+impl<SizeValue: ValueProvider<usize>> StochasticRanking<SizeValue, ThreadRng> {
+    /// Returns new instance of [StochasticRanking] selection operator with default RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `objective_probability` - probability of comparing infeasible individuals by fitness; must be in range [0, 1],
+    ///   usually 0.45
+    /// * `selection_size` - value provider deciding how many individuals will selection operator
+    ///   produce
+    pub fn new(objective_probability: f64, selection_size: SizeValue) -> Self {
+        Self::with_rng(objective_probability, selection_size, rand::thread_rng())
+    }
+}
+
+impl<SizeValue: ValueProvider<usize>, R: Rng> StochasticRanking<SizeValue, R> {
+    /// Returns new instance of [StochasticRanking] selection operator with custom RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `objective_probability` - probability of comparing infeasible individuals by fitness; must be in range [0, 1],
+    ///   usually 0.45
+    /// * `selection_size` - value provider deciding how many individuals will selection operator
+    ///   produce
+    /// * `rng` - random number generator
+    pub fn with_rng(objective_probability: f64, selection_size: SizeValue, rng: R) -> Self {
+        assert!((0.0..=1.0).contains(&objective_probability));
+        StochasticRanking {
+            objective_probability,
+            selection_size,
+            rng,
+        }
+    }
+
+    /// Returns indices of individuals of `population` ordered from the best to the worst
+    pub fn rank<IndividualT>(&mut self, population: &[IndividualT]) -> Vec<usize>
+    where
+        IndividualT: IndividualTrait,
+        IndividualT::FitnessValueT: PartialOrd,
+    {
+        let mut ranking: Vec<usize> = (0..population.len()).collect();
+
+        for _ in 0..population.len() {
+            let mut swapped = false;
+            for j in 0..population.len().saturating_sub(1) {
+                let upper = &population[ranking[j]];
+                let lower = &population[ranking[j + 1]];
+                let (upper_violation, lower_violation) =
+                    (upper.constraint_violation(), lower.constraint_violation());
+
+                let both_feasible = upper_violation <= 0.0 && lower_violation <= 0.0;
+                let should_swap = if both_feasible || self.rng.gen::<f64>() < self.objective_probability {
+                    upper.fitness() < lower.fitness()
+                } else {
+                    upper_violation > lower_violation
+                };
+
+                if should_swap {
+                    ranking.swap(j, j + 1);
+                    swapped = true;
+                }
+            }
+            if !swapped {
+                break;
+            }
+        }
+
+        ranking
+    }
+}
+
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for StochasticRanking<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, SizeValue, R> SelectionOperator<IndividualT> for StochasticRanking<SizeValue, R>
+where
+    IndividualT: IndividualTrait,
+    IndividualT::FitnessValueT: PartialOrd,
+    SizeValue: ValueProvider<usize>,
+    R: Rng,
+{
+    /// Returns a vector of references to individuals selected to mating pool
+    ///
+    /// Population is ranked with stochastic bubble sort, then mating pool is filled by binary tournaments
+    /// won by higher ranked individuals.
+    ///
+    /// ### Arguments
+    ///
+    /// * `metrics` - [crate::ga::Metrics] information on current stage of the algorithm (iteration, elapsed time, etc.)
+    /// * `population` - individuals to choose mating pool from
+    fn apply<'a>(&mut self, metrics: &Metrics, population: &'a [IndividualT]) -> Vec<&'a IndividualT> {
+        let count = self.selection_size.get(metrics);
+        let ranking = self.rank(population);
+
+        // position[i] - position of i-th individual in the ranking
+        let mut position = vec![0; population.len()];
+        for (pos, idx) in ranking.iter().enumerate() {
+            position[*idx] = pos;
+        }
+
+        let index_distribution = rand::distributions::Uniform::from(0..population.len());
+        let mut selected: Vec<&IndividualT> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let i = self.rng.sample(index_distribution);
+            let j = self.rng.sample(index_distribution);
+            selected.push(if position[i] <= position[j] {
+                &population[i]
+            } else {
+                &population[j]
+            });
+        }
+
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{
+        feasibility_first, total_violation, AdaptivePenalty, ConstrainedFitness, FnBasedConstraints,
+        StaticPenalty, StochasticRanking,
+    };
+    use crate::ga::{
+        individual::{ConstrainedIndividual, IndividualTrait, RealValueIndividual},
+        operators::{
+            crossover::SinglePoint,
+            fitness::{Fitness, FnBasedFitness},
+            mutation::Identity,
+            replacement::BothParents,
+            selection::Tournament,
+        },
+        population::RandomPoints,
+        probe::EmptyProbe,
+    };
+
+    #[allow(clippy::ptr_arg)]
+    fn sum(chromosome: &Vec<f64>) -> f64 {
+        chromosome.iter().sum()
+    }
+
+    /// x_0 <= 1
+    #[allow(clippy::ptr_arg)]
+    fn first_gene_at_most_one(chromosome: &Vec<f64>) -> f64 {
+        total_violation(&[chromosome[0] - 1.0], &[], 0.0)
+    }
+
+    /// x_0 >= 0.5
+    #[allow(clippy::ptr_arg)]
+    fn first_gene_at_least_half(chromosome: &Vec<f64>) -> f64 {
+        total_violation(&[0.5 - chromosome[0]], &[], 0.0)
+    }
+
+    fn constrained(fitness: f64, violation: f64) -> ConstrainedIndividual<Vec<f64>> {
+        ConstrainedIndividual {
+            chromosome: Vec::new(),
+            fitness,
+            violation,
+        }
+    }
+
+    #[test]
+    fn total_violation_sums_violated_constraints_only() {
+        assert_eq!(total_violation(&[-1.0, 2.0, 0.5], &[0.05, -3.0], 0.1), 5.4);
+        assert_eq!(total_violation(&[-1.0], &[0.05], 0.1), 0.0);
+    }
+
+    #[test]
+    fn feasibility_first_follows_deb_rules() {
+        assert_eq!(
+            feasibility_first(&constrained(1.0, 0.0), &constrained(100.0, 0.1)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            feasibility_first(&constrained(1.0, 0.0), &constrained(2.0, 0.0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            feasibility_first(&constrained(1.0, 0.5), &constrained(2.0, 2.0)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            vec![
+                constrained(5.0, 1.0),
+                constrained(1.0, 0.0),
+                constrained(3.0, 0.5)
+            ]
+            .into_iter()
+            .max()
+            .unwrap()
+            .fitness,
+            1.0
+        );
+    }
+
+    #[test]
+    fn constrained_fitness_stores_violation() {
+        let mut fitness = ConstrainedFitness::new(
+            FnBasedFitness::new(sum),
+            FnBasedConstraints::new(first_gene_at_most_one),
+        );
+        let mut individual = ConstrainedIndividual::from(vec![3.0, 1.0]);
+
+        assert_eq!(fitness.apply(&mut individual), 4.0);
+        assert_eq!(individual.constraint_violation(), 2.0);
+    }
+
+    #[test]
+    fn static_penalty_is_proportional_to_violation() {
+        let mut fitness = StaticPenalty::new(
+            FnBasedFitness::new(sum),
+            FnBasedConstraints::new(first_gene_at_most_one),
+            10.0,
+        );
+
+        assert_eq!(fitness.apply(&mut RealValueIndividual::from(vec![0.5, 1.0])), 1.5);
+        assert_eq!(
+            fitness.apply(&mut RealValueIndividual::from(vec![3.0, 1.0])),
+            24.0
+        );
+    }
+
+    #[test]
+    fn adaptive_penalty_increases_when_best_individuals_are_infeasible() {
+        let mut fitness = AdaptivePenalty::new(
+            FnBasedFitness::new(sum),
+            FnBasedConstraints::new(first_gene_at_most_one),
+            1.0,
+            2,
+            2.0,
+            3.0,
+        );
+        let mut population = vec![
+            RealValueIndividual::from(vec![2.0, -2.5]),
+            RealValueIndividual::from(vec![0.0, 1.0]),
+        ];
+
+        fitness.apply_batch(&mut population);
+        assert_eq!(fitness.coefficient(), 1.0);
+        fitness.apply_batch(&mut population);
+        assert_eq!(fitness.coefficient(), 3.0);
+
+        // With penalty of 3 per unit of violation the feasible individual is the best one
+        fitness.apply_batch(&mut population);
+        fitness.apply_batch(&mut population);
+        assert_eq!(fitness.coefficient(), 1.5);
+    }
+
+    #[test]
+    fn solver_returns_feasible_individual() {
+        // maximise x_0 + x_1 subject to x_0 <= 1
+        let mut solver = crate::ga::Builder::new::<
+            ConstrainedIndividual<Vec<f64>>,
+            Identity,
+            SinglePoint,
+            Tournament<usize>,
+            BothParents,
+            RandomPoints,
+            ConstrainedFitness<FnBasedFitness<ConstrainedIndividual<Vec<f64>>>, FnBasedConstraints<Vec<f64>>>,
+            EmptyProbe,
+        >()
+        .set_fitness(ConstrainedFitness::new(
+            FnBasedFitness::new(sum),
+            FnBasedConstraints::new(first_gene_at_most_one),
+        ))
+        .set_population_generator(RandomPoints::with_constraints(2, vec![0.0..4.0, 0.0..1.0]))
+        .set_selection_operator(Tournament::new(0.2, 20))
+        .set_crossover_operator(SinglePoint::new())
+        .set_mutation_operator(Identity::new())
+        .set_replacement_operator(BothParents::new())
+        .set_probe(EmptyProbe::new())
+        .set_population_size(20)
+        .set_max_generation_count(10)
+        .build();
+
+        let best = solver.run().unwrap();
+        assert_eq!(best.violation, 0.0);
+        assert!(solver
+            .population()
+            .iter()
+            .all(|idv| idv.violation > 0.0 || idv.fitness <= best.fitness));
+    }
+
+    #[test]
+    fn solver_with_static_penalty_returns_feasible_individual() {
+        // minimise x_0 + x_1 subject to x_0 >= 0.5
+        let mut solver = crate::ga::Builder::with_rvc()
+            .set_fitness(StaticPenalty::new(
+                FnBasedFitness::new(sum),
+                FnBasedConstraints::new(first_gene_at_least_half),
+                100.0,
+            ))
+            .dim(2)
+            .set_population_size(50)
+            .set_max_generation_count(20)
+            .set_seed(7)
+            .build();
+
+        let best = solver.run().unwrap();
+        assert!(best.chromosome[0] >= 0.5);
+    }
+
+    #[test]
+    fn stochastic_ranking_without_objective_comparisons_ranks_by_violation() {
+        let population = vec![
+            constrained(10.0, 3.0),
+            constrained(1.0, 0.0),
+            constrained(5.0, 1.0),
+            constrained(2.0, 0.0),
+        ];
+
+        let ranking = StochasticRanking::new(0.0, 4usize).rank(&population);
+        assert_eq!(ranking, vec![3, 1, 2, 0]);
+    }
+}
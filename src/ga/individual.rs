@@ -1,7 +1,7 @@
 //! Tratis, structs & methods for representing individual in genetic algorithm
 
 use num_traits::Num;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
     ops::Deref,
@@ -50,6 +50,21 @@ pub trait IndividualTrait: Clone + From<Self::ChromosomeT> + Ord {
     fn requires_evaluation(&self) -> bool {
         true
     }
+
+    /// Returns measure of constraint violation of the individual - 0 for feasible individuals,
+    /// positive value otherwise. Default implementation always returns 0, i.e. the problem is unconstrained.
+    ///
+    /// See [constraints](crate::ga::constraints) module for details.
+    fn constraint_violation(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns `true` iff the individual is a better solution than `other`. Used by the solver
+    /// to pick the best individual of each generation & of the whole run. Default implementation
+    /// follows `Ord`, smaller individual being the better one.
+    fn is_better_than(&self, other: &Self) -> bool {
+        self < other
+    }
 }
 
 /// Representation of an individual for a genetic algorithm.
@@ -62,7 +77,7 @@ pub trait IndividualTrait: Clone + From<Self::ChromosomeT> + Ord {
 /// As of latest version this struct is a part of public API,
 /// however it will most likely be private in future versions,
 /// as there in reason for the end user to interact with it directly.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Individual<T: Chromosome> {
     pub chromosome: T,
     pub fitness: f64,
@@ -163,6 +178,87 @@ impl<T: Chromosome> Ord for Individual<T> {
     }
 }
 
+/// Representation of an individual for a constrained optimisation problem.
+///
+/// Compared to [Individual], it carries additionally measure of constraint violation
+/// (see [ConstrainedFitness](crate::ga::constraints::ConstrainedFitness)).
+///
+/// Individuals are ordered according to Deb's feasibility rules (see [feasibility_first](crate::ga::constraints::feasibility_first)),
+/// therefore all operators relying on `Ord` (e.g. [Tournament](crate::ga::operators::selection::Tournament) selection)
+/// prefer feasible individuals.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConstrainedIndividual<T: Chromosome> {
+    pub chromosome: T,
+    pub fitness: f64,
+    pub violation: f64,
+}
+
+impl<T: Chromosome> IndividualTrait for ConstrainedIndividual<T> {
+    type ChromosomeT = T;
+    type FitnessValueT = f64;
+
+    #[inline]
+    fn chromosome(&self) -> &Self::ChromosomeT {
+        &self.chromosome
+    }
+
+    #[inline]
+    fn chromosome_mut(&mut self) -> &mut Self::ChromosomeT {
+        &mut self.chromosome
+    }
+
+    #[inline]
+    fn fitness(&self) -> Self::FitnessValueT {
+        self.fitness
+    }
+
+    #[inline]
+    fn fitness_mut(&mut self) -> &mut Self::FitnessValueT {
+        &mut self.fitness
+    }
+
+    #[inline]
+    fn constraint_violation(&self) -> f64 {
+        self.violation
+    }
+
+    /// Greater individual (see [feasibility_first](crate::ga::constraints::feasibility_first)) is the better one
+    #[inline]
+    fn is_better_than(&self, other: &Self) -> bool {
+        self > other
+    }
+}
+
+impl<T: Chromosome> From<T> for ConstrainedIndividual<T> {
+    fn from(chromosome: T) -> Self {
+        ConstrainedIndividual {
+            chromosome,
+            fitness: f64::MIN,
+            violation: 0.0,
+        }
+    }
+}
+
+impl<T: Chromosome> PartialEq<Self> for ConstrainedIndividual<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl<T: Chromosome> Eq for ConstrainedIndividual<T> {}
+
+impl<T: Chromosome> PartialOrd<Self> for ConstrainedIndividual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Chromosome> Ord for ConstrainedIndividual<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        crate::ga::constraints::feasibility_first(self, other)
+    }
+}
+
 /// Type alias for real valued individual (gene is a f64)
 pub type RealValueIndividual = Individual<Vec<f64>>;
 
@@ -0,0 +1,446 @@
+//! Island model (multi-population) genetic algorithm
+//!
+//! #### Description
+//!
+//! Instead of evolving single, panmictic population, island model evolves a number of
+//! sub-populations (islands) independently. Every `migration_interval` generations some
+//! individuals (emigrants) leave their island & are sent to other islands as decided by
+//! [migration topology](self::topology). This helps to maintain diversity while still
+//! spreading good genetic material across islands.
+//!
+//! Every island is a separate instance of genetic algorithm (see [Island] trait, implemented
+//! for [GeneticSolver]), thus each one can be configured with its own operators & parameters.
+//! Each island also reports its own metrics through the [Probe](crate::ga::Probe) it was built with.
+//!
+//! Emigrants are chosen with any [SelectionOperator] & merged into destination population
+//! with any [ReplacementOperator] (e.g. [replacement::Random](crate::ga::operators::replacement::Random)).
+//!
+//! #### Basic usage
+//!
+//! To run islands in parallel (see [IslandModel::run_parallel]) they must be [Send], thus operators
+//! should be created with RNG other than [ThreadRng](rand::rngs::ThreadRng).
+//!
+//! ```no_run
+//! use ecrs::prelude::*;
+//! use ecrs::ga::island::{topology, IslandModel};
+//! use ecrs::ga::individual::RealValueIndividual;
+//! use rand::{rngs::StdRng, SeedableRng};
+//!
+//! # fn sphere(chromosome: &Vec<f64>) -> f64 {
+//! #   chromosome.iter().map(|x| x * x).sum()
+//! # }
+//! let islands = (0..4)
+//!     .map(|seed| {
+//!         ga::Builder::new()
+//!             .set_fitness_fn(sphere)
+//!             .set_selection_operator(selection::Tournament::with_rng(0.2, 100, StdRng::seed_from_u64(seed)))
+//!             .set_crossover_operator(crossover::SinglePoint::with_rng(StdRng::seed_from_u64(seed)))
+//!             .set_mutation_operator(mutation::Identity::new())
+//!             .set_replacement_operator(replacement::BothParents::new())
+//!             .set_population_generator(population::RandomPoints::with_rng(10, StdRng::seed_from_u64(seed)))
+//!             .set_probe(ga::probe::EmptyProbe::new())
+//!             .set_population_size(100)
+//!             .set_max_generation_count(500)
+//!             .build()
+//!     })
+//!     .collect();
+//!
+//! let best: Option<RealValueIndividual> = IslandModel::new(
+//!     islands,
+//!     topology::Ring::new(),
+//!     selection::Tournament::new(0.2, 2),
+//!     replacement::Random::new(),
+//!     20,
+//! )
+//! .run_parallel();
+//! ```
+
+pub mod topology;
+
+pub use topology::MigrationTopology;
+
+use std::marker::PhantomData;
+
+use super::{
+    individual::IndividualTrait,
+    operators::{
+        crossover::CrossoverOperator, fitness::Fitness, mutation::MutationOperator,
+        replacement::ReplacementOperator, selection::SelectionOperator,
+    },
+    population::PopulationGenerator,
+    GeneticSolver, Metrics, Probe,
+};
+
+/// # Island
+///
+/// This trait defines behaviour required from a single island (sub-population) of the [IslandModel].
+/// It is implemented for [GeneticSolver].
+pub trait Island<IndividualT: IndividualTrait> {
+    /// Generates & evaluates initial population
+    fn initialize(&mut self);
+
+    /// Creates single generation
+    fn evolve(&mut self);
+
+    /// Returns `true` iff the island reached its termination condition & should not be evolved anymore
+    fn is_finished(&self) -> bool;
+
+    /// Returns metrics of the island
+    fn metrics(&self) -> &Metrics;
+
+    /// Returns current population of the island
+    fn population(&self) -> &[IndividualT];
+
+    /// Takes current population out of the island. It must be followed by [Island::set_population] call.
+    fn take_population(&mut self) -> Vec<IndividualT>;
+
+    /// Sets current population of the island. Individuals requiring evaluation are evaluated.
+    fn set_population(&mut self, population: Vec<IndividualT>);
+
+    /// Finishes computations & returns best individual found on the island
+    fn finalize(&mut self) -> Option<IndividualT>;
+}
+
+impl<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT> Island<IndividualT>
+    for GeneticSolver<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>
+where
+    IndividualT: IndividualTrait,
+    MutOpT: MutationOperator<IndividualT>,
+    CrossOpT: CrossoverOperator<IndividualT>,
+    SelOpT: SelectionOperator<IndividualT>,
+    ReplOpT: ReplacementOperator<IndividualT>,
+    PopGenT: PopulationGenerator<IndividualT>,
+    FitnessT: Fitness<IndividualT>,
+    ProbeT: Probe<IndividualT>,
+{
+    fn initialize(&mut self) {
+        GeneticSolver::initialize(self)
+    }
+
+    fn evolve(&mut self) {
+        GeneticSolver::evolve(self)
+    }
+
+    fn is_finished(&self) -> bool {
+        GeneticSolver::is_finished(self)
+    }
+
+    fn metrics(&self) -> &Metrics {
+        GeneticSolver::metrics(self)
+    }
+
+    fn population(&self) -> &[IndividualT] {
+        GeneticSolver::population(self)
+    }
+
+    fn take_population(&mut self) -> Vec<IndividualT> {
+        GeneticSolver::take_population(self)
+    }
+
+    fn set_population(&mut self, population: Vec<IndividualT>) {
+        GeneticSolver::set_population(self, population)
+    }
+
+    fn finalize(&mut self) -> Option<IndividualT> {
+        GeneticSolver::finalize(self)
+    }
+}
+
+/// Allows for islands of different types (e.g. using different operators) in single model
+impl<IndividualT: IndividualTrait, IslandT: Island<IndividualT> + ?Sized> Island<IndividualT>
+    for Box<IslandT>
+{
+    fn initialize(&mut self) {
+        (**self).initialize()
+    }
+
+    fn evolve(&mut self) {
+        (**self).evolve()
+    }
+
+    fn is_finished(&self) -> bool {
+        (**self).is_finished()
+    }
+
+    fn metrics(&self) -> &Metrics {
+        (**self).metrics()
+    }
+
+    fn population(&self) -> &[IndividualT] {
+        (**self).population()
+    }
+
+    fn take_population(&mut self) -> Vec<IndividualT> {
+        (**self).take_population()
+    }
+
+    fn set_population(&mut self, population: Vec<IndividualT>) {
+        (**self).set_population(population)
+    }
+
+    fn finalize(&mut self) -> Option<IndividualT> {
+        (**self).finalize()
+    }
+}
+
+/// # Island model
+///
+/// Runs a number of [islands](Island) & periodically migrates individuals between them.
+/// See [module docs](self) for description.
+pub struct IslandModel<IndividualT, IslandT, TopologyT, EmigrationOpT, ImmigrationOpT>
+where
+    IndividualT: IndividualTrait,
+    IslandT: Island<IndividualT>,
+    TopologyT: MigrationTopology,
+    EmigrationOpT: SelectionOperator<IndividualT>,
+    ImmigrationOpT: ReplacementOperator<IndividualT>,
+{
+    islands: Vec<IslandT>,
+    topology: TopologyT,
+    emigration_operator: EmigrationOpT,
+    immigration_operator: ImmigrationOpT,
+    migration_interval: usize,
+    _phantom: PhantomData<IndividualT>,
+}
+
+impl<IndividualT, IslandT, TopologyT, EmigrationOpT, ImmigrationOpT>
+    IslandModel<IndividualT, IslandT, TopologyT, EmigrationOpT, ImmigrationOpT>
+where
+    IndividualT: IndividualTrait,
+    IslandT: Island<IndividualT>,
+    TopologyT: MigrationTopology,
+    EmigrationOpT: SelectionOperator<IndividualT>,
+    ImmigrationOpT: ReplacementOperator<IndividualT>,
+{
+    /// Returns new instance of [IslandModel]
+    ///
+    /// ### Arguments
+    ///
+    /// * `islands` - islands to evolve, must not be empty
+    /// * `topology` - decides where emigrants of each island are sent to
+    /// * `emigration_operator` - selects emigrants from island's population, selected individuals are cloned
+    /// * `immigration_operator` - merges immigrants (passed as `children`) into island's population.
+    ///   It must preserve population size.
+    /// * `migration_interval` - number of generations between migrations, must be > 0
+    ///
+    /// ### Panics
+    ///
+    /// Iff `islands` is empty or `migration_interval` == 0
+    pub fn new(
+        islands: Vec<IslandT>,
+        topology: TopologyT,
+        emigration_operator: EmigrationOpT,
+        immigration_operator: ImmigrationOpT,
+        migration_interval: usize,
+    ) -> Self {
+        assert!(!islands.is_empty(), "Island model requires at least one island");
+        assert!(migration_interval > 0, "Migration interval must be > 0");
+
+        Self {
+            islands,
+            topology,
+            emigration_operator,
+            immigration_operator,
+            migration_interval,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns islands of the model, e.g. to inspect their metrics or populations after the run
+    pub fn islands(&self) -> &[IslandT] {
+        &self.islands
+    }
+
+    /// Evolves island until next migration or until it finishes
+    fn evolve_island(island: &mut IslandT, generations: usize) {
+        for _ in 0..generations {
+            if island.is_finished() {
+                break;
+            }
+            island.evolve();
+        }
+    }
+
+    /// Sends emigrants of every running island to its destinations
+    fn migrate(&mut self) {
+        let island_count = self.islands.len();
+        let mut immigrants: Vec<Vec<IndividualT>> = vec![Vec::new(); island_count];
+
+        for source in 0..island_count {
+            let island = &self.islands[source];
+            if island.is_finished() {
+                continue;
+            }
+
+            let emigrants: Vec<IndividualT> = self
+                .emigration_operator
+                .apply(island.metrics(), island.population())
+                .into_iter()
+                .cloned()
+                .collect();
+
+            for dest in self.topology.destinations(source, island_count) {
+                if dest != source && dest < island_count && !self.islands[dest].is_finished() {
+                    immigrants[dest].extend(emigrants.iter().cloned());
+                }
+            }
+        }
+
+        for (island, immigrants) in self.islands.iter_mut().zip(immigrants) {
+            if immigrants.is_empty() {
+                continue;
+            }
+            let population = island.take_population();
+            let population = self
+                .immigration_operator
+                .apply(island.metrics(), population, immigrants);
+            island.set_population(population);
+        }
+    }
+
+    /// Initializes islands & runs the model, evolving islands with `evolve_islands` between migrations
+    fn run_with(&mut self, evolve_islands: impl Fn(&mut [IslandT], usize)) -> Option<IndividualT> {
+        self.islands.iter_mut().for_each(|island| island.initialize());
+
+        loop {
+            evolve_islands(&mut self.islands, self.migration_interval);
+            if self.islands.iter().all(|island| island.is_finished()) {
+                break;
+            }
+            self.migrate();
+        }
+
+        self.islands
+            .iter_mut()
+            .filter_map(|island| island.finalize())
+            .reduce(|best, idv| if idv.is_better_than(&best) { idv } else { best })
+    }
+
+    /// Runs the model, evolving islands one after another in current thread.
+    ///
+    /// Computations end when all islands reach their termination conditions.
+    /// Returns the best of individuals found by the islands.
+    pub fn run(&mut self) -> Option<IndividualT> {
+        self.run_with(|islands, generations| {
+            islands
+                .iter_mut()
+                .for_each(|island| Self::evolve_island(island, generations))
+        })
+    }
+}
+
+impl<IndividualT, IslandT, TopologyT, EmigrationOpT, ImmigrationOpT>
+    IslandModel<IndividualT, IslandT, TopologyT, EmigrationOpT, ImmigrationOpT>
+where
+    IndividualT: IndividualTrait,
+    IslandT: Island<IndividualT> + Send,
+    TopologyT: MigrationTopology,
+    EmigrationOpT: SelectionOperator<IndividualT>,
+    ImmigrationOpT: ReplacementOperator<IndividualT>,
+{
+    /// Runs the model, evolving each island in separate thread. Migration is performed
+    /// after all threads complete given number of generations.
+    ///
+    /// Computations end when all islands reach their termination conditions.
+    /// Returns the best of individuals found by the islands.
+    pub fn run_parallel(&mut self) -> Option<IndividualT> {
+        self.run_with(|islands, generations| {
+            std::thread::scope(|scope| {
+                for island in islands.iter_mut() {
+                    scope.spawn(move || Self::evolve_island(island, generations));
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{topology, Island, IslandModel};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::ga::{
+        individual::RealValueIndividual,
+        operators::{crossover, mutation, replacement, selection},
+        population::RandomPoints,
+        probe::EmptyProbe,
+        Builder,
+    };
+
+    #[allow(clippy::ptr_arg)]
+    fn sphere(chromosome: &Vec<f64>) -> f64 {
+        chromosome.iter().map(|x| x * x).sum()
+    }
+
+    fn islands(count: usize, generations: usize) -> Vec<impl Island<RealValueIndividual> + Send> {
+        (0..count as u64)
+            .map(|seed| {
+                Builder::new()
+                    .set_fitness_fn(sphere)
+                    .set_selection_operator(selection::Tournament::with_rng(
+                        0.2,
+                        20,
+                        StdRng::seed_from_u64(seed),
+                    ))
+                    .set_crossover_operator(crossover::SinglePoint::with_rng(StdRng::seed_from_u64(seed)))
+                    .set_mutation_operator(mutation::Identity::new())
+                    .set_replacement_operator(replacement::BothParents::new())
+                    .set_population_generator(RandomPoints::with_rng(5, StdRng::seed_from_u64(seed)))
+                    .set_probe(EmptyProbe::new())
+                    .set_population_size(20)
+                    .set_max_generation_count(generations)
+                    .build()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_island_runs_until_generation_limit() {
+        let mut model = IslandModel::new(
+            islands(3, 25),
+            topology::Ring::new(),
+            selection::Random::new(2),
+            replacement::Random::new(),
+            10,
+        );
+
+        assert!(model.run().is_some());
+        for island in model.islands() {
+            assert_eq!(island.metrics().generation, 25);
+            assert_eq!(island.population().len(), 20);
+        }
+    }
+
+    #[test]
+    fn parallel_run_evolves_all_islands() {
+        let mut model = IslandModel::new(
+            islands(4, 12),
+            topology::FullyConnected::new(),
+            selection::Random::new(3),
+            replacement::Random::new(),
+            5,
+        );
+
+        assert!(model.run_parallel().is_some());
+        for island in model.islands() {
+            assert_eq!(island.metrics().generation, 12);
+            assert_eq!(island.population().len(), 20);
+        }
+    }
+
+    #[test]
+    fn best_individual_is_the_best_of_islands() {
+        let mut model = IslandModel::new(
+            islands(3, 10),
+            topology::Random::new(1),
+            selection::Random::new(2),
+            replacement::Random::new(),
+            3,
+        );
+
+        let best = model.run().unwrap();
+        for island in model.islands() {
+            assert!(island.population().iter().all(|idv| best <= *idv));
+        }
+    }
+}
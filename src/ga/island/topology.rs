@@ -0,0 +1,151 @@
+//! Migration topologies for the island model
+//!
+//! Topology decides which islands receive emigrants of given island.
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use crate::ga::seed::Reseed;
+
+/// # Migration topology
+///
+/// This trait defines common behaviour for migration topologies.
+/// You can implement this trait to provide your custom topology to the [IslandModel](super::IslandModel).
+///
+/// Following topologies are implemented in the library:
+///
+/// * [Ring]
+/// * [FullyConnected]
+/// * [Random]
+pub trait MigrationTopology {
+    /// Returns indices of islands that emigrants from island `source` are sent to.
+    ///
+    /// Indices out of range & index of `source` island itself are ignored.
+    ///
+    /// ### Arguments
+    ///
+    /// * `source` - index of the island emigrants come from
+    /// * `island_count` - total number of islands in the model
+    fn destinations(&mut self, source: usize, island_count: usize) -> Vec<usize>;
+}
+
+/// # Ring topology
+///
+/// Emigrants from island `i` are sent to island `(i + 1) mod n`.
+pub struct Ring;
+
+impl Ring {
+    /// Returns new instance of [Ring] topology
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MigrationTopology for Ring {
+    fn destinations(&mut self, source: usize, island_count: usize) -> Vec<usize> {
+        vec![(source + 1) % island_count]
+    }
+}
+
+/// # Fully connected topology
+///
+/// Emigrants from every island are sent to all other islands.
+pub struct FullyConnected;
+
+impl FullyConnected {
+    /// Returns new instance of [FullyConnected] topology
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MigrationTopology for FullyConnected {
+    fn destinations(&mut self, source: usize, island_count: usize) -> Vec<usize> {
+        (0..island_count).filter(|&i| i != source).collect()
+    }
+}
+
+/// # Random topology
+///
+/// On every migration emigrants from each island are sent to `destination_count`
+/// distinct, uniformly chosen other islands.
+pub struct Random<R: Rng = ThreadRng> {
+    destination_count: usize,
+    rng: R,
+}
+
+impl Random<ThreadRng> {
+    /// Returns new instance of [Random] topology with default RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_count` - number of islands emigrants are sent to. If it exceeds
+    ///   the number of other islands, emigrants are sent to all of them.
+    pub fn new(destination_count: usize) -> Self {
+        Self::with_rng(destination_count, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Random<R> {
+    /// Returns new instance of [Random] topology with custom RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination_count` - number of islands emigrants are sent to. If it exceeds
+    ///   the number of other islands, emigrants are sent to all of them.
+    /// * `rng` - random numbers generator
+    pub fn with_rng(destination_count: usize, rng: R) -> Self {
+        Self {
+            destination_count,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Random<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<R: Rng> MigrationTopology for Random<R> {
+    fn destinations(&mut self, source: usize, island_count: usize) -> Vec<usize> {
+        let other_count = island_count.saturating_sub(1);
+        let count = usize::min(self.destination_count, other_count);
+
+        // Sample from all islands but the source & shift indices past it
+        rand::seq::index::sample(&mut self.rng, other_count, count)
+            .into_iter()
+            .map(|i| if i >= source { i + 1 } else { i })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FullyConnected, MigrationTopology, Random, Ring};
+
+    #[test]
+    fn ring_sends_to_next_island() {
+        let mut topology = Ring::new();
+        assert_eq!(topology.destinations(0, 4), vec![1]);
+        assert_eq!(topology.destinations(3, 4), vec![0]);
+    }
+
+    #[test]
+    fn fully_connected_sends_to_every_other_island() {
+        assert_eq!(FullyConnected::new().destinations(2, 4), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn random_sends_to_distinct_other_islands() {
+        let mut topology = Random::new(3);
+        for source in 0..5 {
+            let mut destinations = topology.destinations(source, 5);
+            destinations.sort();
+            destinations.dedup();
+            assert_eq!(destinations.len(), 3);
+            assert!(destinations.iter().all(|&dest| dest != source && dest < 5));
+        }
+        assert_eq!(topology.destinations(0, 2), vec![1]);
+    }
+}
@@ -0,0 +1,341 @@
+//! Multi-objective genetic algorithm (NSGA-II)
+//!
+//! #### Description
+//!
+//! In multi-objective optimisation there is usually no single best solution, as objectives are
+//! in conflict. Solution `a` *dominates* solution `b` if it is not worse in any objective
+//! & strictly better in at least one. The set of solutions not dominated by any other one
+//! is called *Pareto front*.
+//!
+//! This module implements NSGA-II (Deb et al., 2002). Each generation:
+//!
+//! 1. Mating pool is selected with [crowded tournament](operators::CrowdedTournament)
+//! 2. Children are created with regular crossover & mutation operators
+//! 3. Population & children are merged, sorted into non-dominated fronts
+//!    & next generation is chosen [front by front](operators::Nsga2Replacement),
+//!    using crowding distance to keep the front well spread
+//!
+//! Individuals are represented by [MoIndividual], which holds vector of objectives
+//! computed by [MultiObjectiveFitness]. All objectives are minimized.
+//!
+//! Any crossover, mutation operator & population generator working with generic individual
+//! (e.g. [SinglePoint](crate::ga::operators::crossover::SinglePoint) or
+//! [RandomPoints](crate::ga::population::RandomPoints)) can be used.
+//!
+//! #### Basic usage
+//!
+//! ```no_run
+//! use ecrs::prelude::*;
+//! use ecrs::ga::multiobjective::{operators::CrowdedTournament, Nsga2Builder};
+//!
+//! // Schaffer's problem no. 1
+//! # #[allow(clippy::ptr_arg)]
+//! fn schaffer(chromosome: &Vec<f64>) -> Vec<f64> {
+//!     vec![chromosome[0].powi(2), (chromosome[0] - 2.0).powi(2)]
+//! }
+//!
+//! let front = Nsga2Builder::new()
+//!     .set_fitness_fn(schaffer)
+//!     .set_selection_operator(CrowdedTournament::new(100))
+//!     .set_crossover_operator(crossover::SinglePoint::new())
+//!     .set_mutation_operator(mutation::Identity::new())
+//!     .set_population_generator(population::RandomPoints::with_constraints(1, vec![-10.0..10.0]))
+//!     .set_probe(ga::probe::EmptyProbe::new())
+//!     .set_population_size(100)
+//!     .set_max_generation_count(200)
+//!     .build()
+//!     .run();
+//!
+//! for individual in front.iter() {
+//!     println!("{:?} -> {:?}", individual.chromosome, individual.objectives);
+//! }
+//! ```
+
+mod builder;
+pub mod fitness;
+pub mod individual;
+pub mod operators;
+pub mod sorting;
+
+pub use builder::Nsga2Builder;
+pub use fitness::{FnBasedMultiObjectiveFitness, MultiObjectiveFitness};
+pub use individual::MoIndividual;
+
+use super::{
+    individual::Chromosome,
+    operators::{
+        crossover::CrossoverOperator, mutation::MutationOperator, replacement::ReplacementOperator,
+        selection::SelectionOperator,
+    },
+    population::PopulationGenerator,
+    timer::Timer,
+    GAParams, Metrics, Probe,
+};
+use operators::Nsga2Replacement;
+use sorting::assign_rank_and_crowding;
+use std::marker::PhantomData;
+
+/// # Pareto front
+///
+/// Result of multi-objective optimisation: individuals from the final population,
+/// which are not dominated by any other individual of this population.
+#[derive(Clone, Debug)]
+pub struct ParetoFront<T: Chromosome> {
+    individuals: Vec<MoIndividual<T>>,
+}
+
+impl<T: Chromosome> ParetoFront<T> {
+    /// Returns non-dominated individuals
+    pub fn individuals(&self) -> &[MoIndividual<T>] {
+        &self.individuals
+    }
+
+    /// Returns iterator over non-dominated individuals
+    pub fn iter(&self) -> std::slice::Iter<'_, MoIndividual<T>> {
+        self.individuals.iter()
+    }
+
+    /// Returns number of individuals in the front
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    /// Returns `true` iff there are no individuals in the front
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+
+    /// Consumes the front returning non-dominated individuals
+    pub fn into_individuals(self) -> Vec<MoIndividual<T>> {
+        self.individuals
+    }
+}
+
+impl<T: Chromosome> From<Vec<MoIndividual<T>>> for ParetoFront<T> {
+    /// Creates front from individuals of rank 0
+    fn from(population: Vec<MoIndividual<T>>) -> Self {
+        Self {
+            individuals: population.into_iter().filter(|idv| idv.rank == 0).collect(),
+        }
+    }
+}
+
+pub struct Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    pub params: GAParams,
+    pub fitness_fn: FitnessT,
+    pub mutation_operator: MutOpT,
+    pub crossover_operator: CrossOpT,
+    pub selection_operator: SelOpT,
+    pub population_factory: PopGenT,
+    pub probe: ProbeT,
+    _phantom: PhantomData<T>,
+}
+
+/// # NSGA-II
+///
+/// Multi-objective genetic algorithm. Use [Nsga2Builder] to create an instance.
+/// See [module docs](self) for description.
+pub struct Nsga2<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    config: Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>,
+    replacement_operator: Nsga2Replacement,
+    metrics: Metrics,
+    timer: Timer,
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+    Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    /// Returns new instance of [Nsga2Config]
+    pub fn new(
+        params: GAParams,
+        fitness_fn: FitnessT,
+        mutation_operator: MutOpT,
+        crossover_operator: CrossOpT,
+        selection_operator: SelOpT,
+        population_factory: PopGenT,
+        probe: ProbeT,
+    ) -> Self {
+        Self {
+            params,
+            fitness_fn,
+            mutation_operator,
+            crossover_operator,
+            selection_operator,
+            population_factory,
+            probe,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+    Nsga2<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    pub fn new(config: Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>) -> Self {
+        let population_size = config.params.population_size;
+        Self {
+            config,
+            replacement_operator: Nsga2Replacement::new(),
+            metrics: Metrics::new(None, None, 0, population_size),
+            timer: Timer::new(),
+        }
+    }
+
+    /// Returns metrics of the solver
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Evaluates objectives of all given individuals
+    fn eval_pop(&mut self, population: &mut [MoIndividual<T>]) {
+        self.metrics.evaluation_count += population.len();
+        for individual in population.iter_mut() {
+            individual.objectives = self.config.fitness_fn.apply(&individual.chromosome);
+        }
+    }
+
+    /// Runs the algorithm & returns Pareto front of the final population
+    pub fn run(&mut self) -> ParetoFront<T> {
+        self.metrics.start_time = Some(std::time::Instant::now());
+        self.config.probe.on_start(&self.metrics);
+
+        self.timer.start();
+        let mut population = self
+            .config
+            .population_factory
+            .generate(self.config.params.population_size);
+        self.metrics.pop_gen_dur = Some(self.timer.elapsed());
+        self.metrics.population_size = population.len();
+
+        self.timer.start();
+        self.eval_pop(&mut population);
+        assign_rank_and_crowding(&mut population);
+        self.metrics.pop_eval_dur = Some(self.timer.elapsed());
+
+        self.config
+            .probe
+            .on_initial_population_created(&self.metrics, &population);
+
+        let mut iteration_timer = Timer::new();
+        for generation_no in 1..=self.config.params.generation_limit {
+            self.metrics.generation = generation_no;
+            self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
+            iteration_timer.start();
+
+            self.config.probe.on_iteration_start(&self.metrics);
+
+            self.timer.start();
+            let mating_pool = self.config.selection_operator.apply(&self.metrics, &population);
+            self.metrics.selection_dur = Some(self.timer.elapsed());
+
+            self.timer.start();
+            let mut children = self.config.crossover_operator.apply(&self.metrics, &mating_pool);
+            self.metrics.crossover_dur = Some(self.timer.elapsed());
+
+            self.timer.start();
+            children
+                .iter_mut()
+                .for_each(|child| self.config.mutation_operator.apply(&self.metrics, child));
+            self.metrics.mutation_dur = Some(self.timer.elapsed());
+
+            self.timer.start();
+            self.eval_pop(&mut children);
+            self.metrics.pop_eval_dur = Some(self.timer.elapsed());
+
+            self.timer.start();
+            population = self
+                .replacement_operator
+                .apply(&self.metrics, population, children);
+            self.metrics.replacement_dur = Some(self.timer.elapsed());
+
+            self.config.probe.on_new_generation(&self.metrics, &population);
+
+            self.metrics.iteration_dur = Some(iteration_timer.elapsed());
+            self.config.probe.on_iteration_end(&self.metrics);
+
+            if self.metrics.start_time.unwrap().elapsed() >= self.config.params.max_duration {
+                break;
+            }
+        }
+
+        self.metrics.total_dur = Some(self.metrics.start_time.unwrap().elapsed());
+        // There is no single best individual, the one preferred by crowded comparison is reported
+        if let Some(best_individual) = population.iter().max() {
+            self.config
+                .probe
+                .on_end(&self.metrics, &population, best_individual);
+        }
+
+        ParetoFront::from(population)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{operators::CrowdedTournament, Nsga2Builder};
+    use crate::ga::{
+        operators::{crossover::SinglePoint, mutation::Interchange},
+        population::RandomPoints,
+        probe::EmptyProbe,
+    };
+
+    #[allow(clippy::ptr_arg)]
+    fn schaffer(chromosome: &Vec<f64>) -> Vec<f64> {
+        vec![chromosome[0].powi(2), (chromosome[0] - 2.0).powi(2)]
+    }
+
+    #[test]
+    fn nsga2_returns_non_dominated_individuals() {
+        let front = Nsga2Builder::new()
+            .set_fitness_fn(schaffer)
+            .set_selection_operator(CrowdedTournament::new(40))
+            .set_crossover_operator(SinglePoint::new())
+            .set_mutation_operator(Interchange::new(0.1))
+            .set_population_generator(RandomPoints::with_constraints(2, vec![-10.0..10.0, -10.0..10.0]))
+            .set_probe(EmptyProbe::new())
+            .set_population_size(40)
+            .set_max_generation_count(30)
+            .build()
+            .run();
+
+        assert!(!front.is_empty());
+        for a in front.iter() {
+            assert_eq!(a.rank, 0);
+            assert!(front.iter().all(|b| !b.dominates(a)));
+        }
+    }
+}
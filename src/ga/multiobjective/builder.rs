@@ -0,0 +1,305 @@
+//! Builder for [Nsga2] solver
+
+use std::marker::PhantomData;
+
+use crate::ga::{
+    builder::{ConfigError, DefaultParams, GAParamsOpt},
+    individual::Chromosome,
+    operators::{crossover::CrossoverOperator, mutation::MutationOperator, selection::SelectionOperator},
+    population::PopulationGenerator,
+    seed::{derive_seed, stream, Reseed},
+    GAParams, Probe,
+};
+
+use super::{FnBasedMultiObjectiveFitness, MoIndividual, MultiObjectiveFitness, Nsga2, Nsga2Config};
+
+/// [Nsga2Builder] must be fully configured. Only defaults for params are provided,
+/// altough it is preffered to override them.
+pub struct Nsga2Builder<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    params: GAParamsOpt,
+    fitness_fn: Option<FitnessT>,
+    mutation_operator: Option<MutOpT>,
+    crossover_operator: Option<CrossOpT>,
+    selection_operator: Option<SelOpT>,
+    population_factory: Option<PopGenT>,
+    probe: Option<ProbeT>,
+    #[allow(clippy::type_complexity)]
+    seed: Option<(
+        u64,
+        fn(&mut Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>, u64),
+    )>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, ProbeT>
+    Nsga2Builder<T, MutOpT, CrossOpT, SelOpT, PopGenT, FnBasedMultiObjectiveFitness<T>, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    /// Sets fitness as pointer to a normal function returning values of all objectives
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness_fn` - pointer to function with appropriate signature
+    pub fn set_fitness_fn(self, fitness_fn: fn(&T) -> Vec<f64>) -> Self {
+        self.set_fitness(FnBasedMultiObjectiveFitness::new(fitness_fn))
+    }
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+    Nsga2Builder<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    /// Returns new instance of [Nsga2Builder]
+    pub fn new() -> Self {
+        Self {
+            params: GAParamsOpt::new(),
+            fitness_fn: None,
+            mutation_operator: None,
+            crossover_operator: None,
+            selection_operator: None,
+            population_factory: None,
+            probe: None,
+            seed: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets max duration. If exceeded, the algorithm halts.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_duration` - Maximal duration of computations
+    pub fn set_max_duration(mut self, max_duration: std::time::Duration) -> Self {
+        self.params.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Sets maximal generation count. If exceeded, the algorithm halts.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_gen_count` - Maximal number of generations to conduct computations for.
+    ///
+    /// ## Panics
+    ///
+    /// If the parameter has invalid value.
+    pub fn set_max_generation_count(mut self, max_gen_count: usize) -> Self {
+        assert!(max_gen_count >= 1);
+        self.params.generation_limit = Some(max_gen_count);
+        self
+    }
+
+    /// Sets population size. Must be positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `size` - Strength of population (number of individuals)
+    ///
+    /// ## Panics
+    ///
+    /// If the parameter has invalid value.
+    pub fn set_population_size(mut self, size: usize) -> Self {
+        assert!(size > 0);
+        self.params.population_size = Some(size);
+        self
+    }
+
+    /// Sets fitness
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - Structure implementing the [MultiObjectiveFitness] trait. See its documentation for details.
+    pub fn set_fitness(mut self, fitness: FitnessT) -> Self {
+        self.fitness_fn = Some(fitness);
+        self
+    }
+
+    /// Sets mutation operator
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_op` - struct implementing [MutationOperator](crate::ga::operators::mutation::MutationOperator) trait
+    pub fn set_mutation_operator(mut self, mutation_op: MutOpT) -> Self {
+        self.mutation_operator = Some(mutation_op);
+        self
+    }
+
+    /// Sets crossover operator
+    ///
+    /// ## Arguments
+    ///
+    /// * `crossover_op` - struct implementing [CrossoverOperator](crate::ga::operators::crossover::CrossoverOperator) trait
+    pub fn set_crossover_operator(mut self, crossover_op: CrossOpT) -> Self {
+        self.crossover_operator = Some(crossover_op);
+        self
+    }
+
+    /// Sets selection operator. NSGA-II is defined with [CrowdedTournament](super::operators::CrowdedTournament),
+    /// its selection size should be equal to population size.
+    ///
+    /// ## Arguments
+    ///
+    /// * `selection_op` - struct implementing [SelectionOperator](crate::ga::operators::selection::SelectionOperator) trait
+    pub fn set_selection_operator(mut self, selection_op: SelOpT) -> Self {
+        self.selection_operator = Some(selection_op);
+        self
+    }
+
+    /// Sets population generator
+    ///
+    /// ## Arguments
+    ///
+    /// * `generator` - struct implementing [PopulationGenerator](crate::ga::population::PopulationGenerator) trait
+    pub fn set_population_generator(mut self, generator: PopGenT) -> Self {
+        self.population_factory = Some(generator);
+        self
+    }
+
+    /// Sets probe
+    ///
+    /// ## Arguments
+    ///
+    /// * `probe` - struct implementing [Probe](crate::ga::probe::Probe) trait
+    pub fn set_probe(mut self, probe: ProbeT) -> Self {
+        self.probe = Some(probe);
+        self
+    }
+
+    fn try_into_config(
+        mut self,
+    ) -> Result<Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>, ConfigError> {
+        self.params.fill_from(&Self::DEFAULT_PARAMS);
+
+        let params = GAParams::try_from(self.params)?;
+        let fitness_fn = self.fitness_fn.ok_or(ConfigError::MissingOperator(
+            "No fitness function specified".to_owned(),
+        ))?;
+        let mutation_operator = self.mutation_operator.ok_or(ConfigError::MissingOperator(
+            "No mutation operator specified".to_owned(),
+        ))?;
+        let crossover_operator = self.crossover_operator.ok_or(ConfigError::MissingOperator(
+            "No crossover operator specified".to_owned(),
+        ))?;
+        let selection_operator = self.selection_operator.ok_or(ConfigError::MissingOperator(
+            "No selection operator specified".to_owned(),
+        ))?;
+        let population_factory = self
+            .population_factory
+            .ok_or(ConfigError::MissingPopulationFactory)?;
+        let probe = self.probe.ok_or(ConfigError::NoProbe)?;
+
+        Ok(Nsga2Config::new(
+            params,
+            fitness_fn,
+            mutation_operator,
+            crossover_operator,
+            selection_operator,
+            population_factory,
+            probe,
+        ))
+    }
+
+    /// Returns new instance of [Nsga2]
+    ///
+    /// ## Panics
+    ///
+    /// Iff any of the operators is not specified or any of the parameters has invalid value.
+    pub fn build(self) -> Nsga2<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT> {
+        let seed = self.seed;
+        let mut config = match self.try_into_config() {
+            Ok(config) => config,
+            Err(err) => panic!("Builder panicked with error: {err}"),
+        };
+
+        if let Some((seed, reseeder)) = seed {
+            reseeder(&mut config, seed);
+        }
+
+        Nsga2::new(config)
+    }
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+    Nsga2Builder<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>> + Reseed,
+    CrossOpT: CrossoverOperator<MoIndividual<T>> + Reseed,
+    SelOpT: SelectionOperator<MoIndividual<T>> + Reseed,
+    PopGenT: PopulationGenerator<MoIndividual<T>> + Reseed,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    /// Sets seed of the run. Independent RNG streams derived from the seed replace RNGs
+    /// of all operators & population generator. See [seed](crate::ga::seed) module for details.
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - seed of the run
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some((seed, reseed_nsga2_config));
+        self
+    }
+}
+
+/// Reseeds all operators & population generator of given config with streams derived from `seed`
+fn reseed_nsga2_config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>(
+    config: &mut Nsga2Config<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>,
+    seed: u64,
+) where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>> + Reseed,
+    CrossOpT: CrossoverOperator<MoIndividual<T>> + Reseed,
+    SelOpT: SelectionOperator<MoIndividual<T>> + Reseed,
+    PopGenT: PopulationGenerator<MoIndividual<T>> + Reseed,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+    config
+        .population_factory
+        .reseed(derive_seed(seed, stream::POPULATION_GENERATOR));
+    config
+        .selection_operator
+        .reseed(derive_seed(seed, stream::SELECTION));
+    config
+        .crossover_operator
+        .reseed(derive_seed(seed, stream::CROSSOVER));
+    config
+        .mutation_operator
+        .reseed(derive_seed(seed, stream::MUTATION));
+}
+
+impl<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT> DefaultParams
+    for Nsga2Builder<T, MutOpT, CrossOpT, SelOpT, PopGenT, FitnessT, ProbeT>
+where
+    T: Chromosome,
+    MutOpT: MutationOperator<MoIndividual<T>>,
+    CrossOpT: CrossoverOperator<MoIndividual<T>>,
+    SelOpT: SelectionOperator<MoIndividual<T>>,
+    PopGenT: PopulationGenerator<MoIndividual<T>>,
+    FitnessT: MultiObjectiveFitness<T>,
+    ProbeT: Probe<MoIndividual<T>>,
+{
+}
@@ -0,0 +1,35 @@
+//! Multi-objective fitness functions
+
+use crate::ga::individual::Chromosome;
+
+/// # Multi-objective fitness
+///
+/// This trait defines common behaviour for multi-objective fitness functions.
+/// You can implement this trait to provide your custom (possibly stateful) fitness function.
+///
+/// All objectives are minimized. To maximize an objective return its negation.
+pub trait MultiObjectiveFitness<T: Chromosome> {
+    /// Returns values of all objectives for given chromosome. The number of objectives
+    /// must be the same for every chromosome.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chromosome` - chromosome to evaluate
+    fn apply(&mut self, chromosome: &T) -> Vec<f64>;
+}
+
+pub struct FnBasedMultiObjectiveFitness<T: Chromosome> {
+    fn_ptr: fn(&T) -> Vec<f64>,
+}
+
+impl<T: Chromosome> FnBasedMultiObjectiveFitness<T> {
+    pub fn new(fn_ptr: fn(&T) -> Vec<f64>) -> Self {
+        Self { fn_ptr }
+    }
+}
+
+impl<T: Chromosome> MultiObjectiveFitness<T> for FnBasedMultiObjectiveFitness<T> {
+    fn apply(&mut self, chromosome: &T) -> Vec<f64> {
+        (self.fn_ptr)(chromosome)
+    }
+}
@@ -0,0 +1,120 @@
+//! Individual representation for multi-objective optimisation
+
+use serde::Serialize;
+use std::cmp::Ordering;
+
+use crate::ga::individual::{Chromosome, IndividualTrait};
+
+/// Representation of an individual for multi-objective genetic algorithm.
+///
+/// Besides chromosome it holds values of all objectives (all of them are minimized)
+/// together with non-domination rank & crowding distance computed by
+/// [assign_rank_and_crowding](super::sorting::assign_rank_and_crowding).
+///
+/// Individuals are ordered with crowded comparison operator: an individual is *greater* (better)
+/// if it has lower rank or, in case of equal ranks, greater crowding distance.
+#[derive(Clone, Debug, Serialize)]
+pub struct MoIndividual<T: Chromosome> {
+    pub chromosome: T,
+    /// Values of objectives. Empty iff the individual has not been evaluated yet.
+    pub objectives: Vec<f64>,
+    /// Index of non-dominated front the individual belongs to (0 is the Pareto front)
+    pub rank: usize,
+    pub crowding_distance: f64,
+    /// Scalar fitness required by [IndividualTrait]. It is kept equal to `-rank`,
+    /// so that greater value means better individual.
+    pub fitness: f64,
+}
+
+impl<T: Chromosome> MoIndividual<T> {
+    /// Returns `true` iff this individual dominates `other`
+    pub fn dominates(&self, other: &Self) -> bool {
+        super::sorting::dominates(&self.objectives, &other.objectives)
+    }
+}
+
+impl<T: Chromosome> From<T> for MoIndividual<T> {
+    fn from(chromosome: T) -> Self {
+        MoIndividual {
+            chromosome,
+            objectives: Vec::new(),
+            rank: usize::MAX,
+            crowding_distance: 0.0,
+            fitness: f64::MIN,
+        }
+    }
+}
+
+impl<T: Chromosome> IndividualTrait for MoIndividual<T> {
+    type ChromosomeT = T;
+    type FitnessValueT = f64;
+
+    #[inline]
+    fn chromosome(&self) -> &Self::ChromosomeT {
+        &self.chromosome
+    }
+
+    #[inline]
+    fn chromosome_mut(&mut self) -> &mut Self::ChromosomeT {
+        &mut self.chromosome
+    }
+
+    #[inline]
+    fn fitness(&self) -> Self::FitnessValueT {
+        self.fitness
+    }
+
+    #[inline]
+    fn fitness_mut(&mut self) -> &mut Self::FitnessValueT {
+        &mut self.fitness
+    }
+}
+
+impl<T: Chromosome> PartialEq<Self> for MoIndividual<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Chromosome> Eq for MoIndividual<T> {}
+
+impl<T: Chromosome> PartialOrd<Self> for MoIndividual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Crowded comparison operator
+impl<T: Chromosome> Ord for MoIndividual<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.rank.cmp(&self.rank).then_with(|| {
+            self.crowding_distance
+                .partial_cmp(&other.crowding_distance)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoIndividual;
+
+    fn individual(rank: usize, crowding_distance: f64) -> MoIndividual<f64> {
+        MoIndividual {
+            rank,
+            crowding_distance,
+            ..MoIndividual::from(0.0)
+        }
+    }
+
+    #[test]
+    fn lower_rank_is_better() {
+        assert!(individual(0, 0.0) > individual(1, f64::INFINITY));
+    }
+
+    #[test]
+    fn greater_crowding_distance_is_better_within_front() {
+        assert!(individual(1, 2.0) > individual(1, 1.0));
+        assert_eq!(individual(1, 1.0), individual(1, 1.0));
+    }
+}
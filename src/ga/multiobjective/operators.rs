@@ -0,0 +1,216 @@
+//! NSGA-II selection & replacement operators
+//!
+//! Both operators implement regular [SelectionOperator] & [ReplacementOperator] traits
+//! for [MoIndividual], so they can be also used outside of [Nsga2](super::Nsga2) solver.
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use crate::ga::{
+    individual::Chromosome,
+    operators::{replacement::ReplacementOperator, selection::SelectionOperator},
+    seed::Reseed,
+    value_provider::ValueProvider,
+    Metrics,
+};
+
+use super::{sorting::assign_rank_and_crowding, MoIndividual};
+
+/// ### Crowded tournament selection operator
+///
+/// This struct implements [SelectionOperator] trait and can be used with GA.
+///
+/// Binary tournament with crowded comparison operator: out of two randomly chosen individuals
+/// the one with lower non-domination rank is selected, ties are broken in favour of greater
+/// crowding distance. Requires ranks & crowding distances to be up to date.
+///
+/// **Note**: The same individual *can* be selected multiple times.
+pub struct CrowdedTournament<SizeValue: ValueProvider<usize>, R: Rng = ThreadRng> {
+    selection_size: SizeValue,
+    rng: R,
+}
+
+impl<SizeValue: ValueProvider<usize>> CrowdedTournament<SizeValue, ThreadRng> {
+    /// Returns new instance of [CrowdedTournament] selection operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `selection_size` - value provider deciding how many individuals will selection operator
+    ///   produce
+    pub fn new(selection_size: SizeValue) -> Self {
+        Self::with_rng(selection_size, rand::thread_rng())
+    }
+}
+
+impl<SizeValue: ValueProvider<usize>, R: Rng> CrowdedTournament<SizeValue, R> {
+    /// Returns new instance of [CrowdedTournament] selection operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `selection_size` - value provider deciding how many individuals will selection operator
+    ///   produce
+    /// * `rng` - random numbers generator
+    pub fn with_rng(selection_size: SizeValue, rng: R) -> Self {
+        Self { selection_size, rng }
+    }
+}
+
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for CrowdedTournament<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<T: Chromosome, SizeValue: ValueProvider<usize>, R: Rng> SelectionOperator<MoIndividual<T>>
+    for CrowdedTournament<SizeValue, R>
+{
+    /// Returns a vector of references to individuals selected to mating pool.
+    ///
+    /// ### Arguments
+    ///
+    /// * `metrics` - [crate::ga::Metrics] information on current stage of the algorithm (iteration, elapsed time, etc.)
+    /// * `population` - individuals to choose mating pool from
+    fn apply<'a>(
+        &mut self,
+        metrics: &Metrics,
+        population: &'a [MoIndividual<T>],
+    ) -> Vec<&'a MoIndividual<T>> {
+        let count = self.selection_size.get(metrics);
+        let mut selected: Vec<&MoIndividual<T>> = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let first = &population[self.rng.gen_range(0..population.len())];
+            let second = &population[self.rng.gen_range(0..population.len())];
+            selected.push(std::cmp::max(first, second));
+        }
+        selected
+    }
+}
+
+/// # NSGA-II replacement operator
+///
+/// This struct implements [ReplacementOperator] trait and can be used with GA.
+///
+/// Merges population with children, sorts the union into non-dominated fronts & fills the next
+/// generation front by front. Individuals from the front that does not fit entirely are chosen
+/// by descending crowding distance. Size of `population` is preserved. Ranks & crowding distances
+/// of returned individuals are the ones computed for the union.
+pub struct Nsga2Replacement;
+
+impl Nsga2Replacement {
+    /// Returns new instance of [Nsga2Replacement] operator
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Reseed for Nsga2Replacement {
+    /// [Nsga2Replacement] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
+impl<T: Chromosome> ReplacementOperator<MoIndividual<T>> for Nsga2Replacement {
+    /// Returns next generation chosen from union of `population` & `children`.
+    ///
+    /// ### Arguments
+    ///
+    /// * `population` - Original population, input to the crossover phase.
+    /// * `children` - Result of the crossover phase, must be evaluated
+    fn apply(
+        &mut self,
+        _metrics: &Metrics,
+        mut population: Vec<MoIndividual<T>>,
+        children: Vec<MoIndividual<T>>,
+    ) -> Vec<MoIndividual<T>> {
+        let target_size = population.len();
+        population.extend(children);
+        let fronts = assign_rank_and_crowding(&mut population);
+
+        let mut survives = vec![false; population.len()];
+        let mut survivor_count = 0;
+        for mut front in fronts {
+            if survivor_count + front.len() > target_size {
+                front.sort_by(|&a, &b| {
+                    population[b]
+                        .crowding_distance
+                        .total_cmp(&population[a].crowding_distance)
+                });
+                front.truncate(target_size - survivor_count);
+            }
+            survivor_count += front.len();
+            front.into_iter().for_each(|idx| survives[idx] = true);
+            if survivor_count == target_size {
+                break;
+            }
+        }
+
+        population
+            .into_iter()
+            .zip(survives)
+            .filter_map(|(individual, survives)| survives.then_some(individual))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CrowdedTournament, Nsga2Replacement};
+    use crate::ga::{
+        multiobjective::MoIndividual,
+        operators::{replacement::ReplacementOperator, selection::SelectionOperator},
+        Metrics,
+    };
+
+    fn individual(objectives: [f64; 2]) -> MoIndividual<f64> {
+        MoIndividual {
+            objectives: objectives.to_vec(),
+            ..MoIndividual::from(0.0)
+        }
+    }
+
+    #[test]
+    fn replacement_keeps_best_fronts() {
+        let population = vec![individual([5.0, 5.0]), individual([4.0, 4.0])];
+        let children = vec![individual([1.0, 2.0]), individual([2.0, 1.0])];
+
+        let result = Nsga2Replacement::new().apply(&Metrics::default(), population, children);
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|idv| idv.rank == 0));
+    }
+
+    #[test]
+    fn replacement_truncates_last_front_by_crowding_distance() {
+        let population = vec![
+            individual([0.0, 4.0]),
+            individual([1.0, 3.0]),
+            individual([2.0, 2.5]),
+        ];
+        let children = vec![
+            individual([4.0, 0.0]),
+            individual([6.0, 6.0]),
+            individual([7.0, 7.0]),
+        ];
+
+        let result = Nsga2Replacement::new().apply(&Metrics::default(), population, children);
+
+        assert_eq!(result.len(), 3);
+        // Boundary solutions of the first front have infinite crowding distance
+        assert!(result.iter().any(|idv| idv.objectives == vec![0.0, 4.0]));
+        assert!(result.iter().any(|idv| idv.objectives == vec![4.0, 0.0]));
+        assert!(result.iter().all(|idv| idv.rank == 0));
+    }
+
+    #[test]
+    fn tournament_prefers_lower_rank() {
+        let mut population = vec![individual([1.0, 1.0]), individual([2.0, 2.0])];
+        population[0].rank = 0;
+        population[1].rank = 1;
+
+        let selected = CrowdedTournament::new(50).apply(&Metrics::default(), &population);
+
+        assert_eq!(selected.len(), 50);
+        assert!(selected.iter().any(|idv| idv.rank == 0));
+        // Worse individual can win only against itself
+        assert!(selected.iter().filter(|idv| idv.rank == 1).count() < 50);
+    }
+}
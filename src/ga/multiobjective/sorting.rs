@@ -0,0 +1,179 @@
+//! Fast non-dominated sorting & crowding distance computation (Deb et al., 2002)
+
+use crate::ga::individual::Chromosome;
+
+use super::MoIndividual;
+
+/// Returns `true` iff objectives vector `a` dominates `b`, i.e. `a` is not worse in any
+/// objective & strictly better in at least one (all objectives are minimized).
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    debug_assert_eq!(a.len(), b.len(), "Objective vectors must be of the same length");
+    let mut strictly_better = false;
+    for (x, y) in a.iter().zip(b.iter()) {
+        if x > y {
+            return false;
+        }
+        if x < y {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Partitions population into non-dominated fronts. Returns indices of individuals in
+/// consecutive fronts, first one being the Pareto front of the population.
+///
+/// Runs in O(MN^2) time, where M is number of objectives & N is population size.
+///
+/// ## Arguments
+///
+/// * `population` - evaluated individuals
+pub fn fast_non_dominated_sort<T: Chromosome>(population: &[MoIndividual<T>]) -> Vec<Vec<usize>> {
+    let n = population.len();
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut domination_count: Vec<usize> = vec![0; n];
+    let mut current_front: Vec<usize> = Vec::new();
+
+    for p in 0..n {
+        for q in (p + 1)..n {
+            if population[p].dominates(&population[q]) {
+                dominated_by[p].push(q);
+                domination_count[q] += 1;
+            } else if population[q].dominates(&population[p]) {
+                dominated_by[q].push(p);
+                domination_count[p] += 1;
+            }
+        }
+    }
+
+    for (p, &count) in domination_count.iter().enumerate() {
+        if count == 0 {
+            current_front.push(p);
+        }
+    }
+
+    let mut fronts: Vec<Vec<usize>> = Vec::new();
+    while !current_front.is_empty() {
+        let mut next_front: Vec<usize> = Vec::new();
+        for &p in current_front.iter() {
+            for &q in dominated_by[p].iter() {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next_front.push(q);
+                }
+            }
+        }
+        fronts.push(current_front);
+        current_front = next_front;
+    }
+    fronts
+}
+
+/// Returns crowding distances of individuals from given front, in the order of `front`.
+/// Boundary individuals (w.r.t. any objective) get infinite distance.
+///
+/// ## Arguments
+///
+/// * `population` - evaluated individuals
+/// * `front` - indices of individuals (in `population`) forming single front
+pub fn crowding_distance<T: Chromosome>(population: &[MoIndividual<T>], front: &[usize]) -> Vec<f64> {
+    let mut distances = vec![0.0; front.len()];
+    if front.len() <= 2 {
+        distances.fill(f64::INFINITY);
+        return distances;
+    }
+
+    let objective_count = population[front[0]].objectives.len();
+    let mut order: Vec<usize> = (0..front.len()).collect();
+
+    for m in 0..objective_count {
+        let objective = |i: usize| population[front[i]].objectives[m];
+        order.sort_by(|&a, &b| objective(a).total_cmp(&objective(b)));
+
+        let min = objective(order[0]);
+        let max = objective(order[front.len() - 1]);
+        distances[order[0]] = f64::INFINITY;
+        distances[order[front.len() - 1]] = f64::INFINITY;
+
+        if max <= min {
+            continue;
+        }
+
+        for w in order.windows(3) {
+            distances[w[1]] += (objective(w[2]) - objective(w[0])) / (max - min);
+        }
+    }
+    distances
+}
+
+/// Computes & stores in individuals their non-domination rank & crowding distance.
+/// Returns fronts as computed by [fast_non_dominated_sort].
+///
+/// ## Arguments
+///
+/// * `population` - evaluated individuals
+pub fn assign_rank_and_crowding<T: Chromosome>(population: &mut [MoIndividual<T>]) -> Vec<Vec<usize>> {
+    let fronts = fast_non_dominated_sort(population);
+    for (rank, front) in fronts.iter().enumerate() {
+        let distances = crowding_distance(population, front);
+        for (&idx, distance) in front.iter().zip(distances) {
+            let individual = &mut population[idx];
+            individual.rank = rank;
+            individual.crowding_distance = distance;
+            individual.fitness = -(rank as f64);
+        }
+    }
+    fronts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assign_rank_and_crowding, crowding_distance, dominates, fast_non_dominated_sort};
+    use crate::ga::multiobjective::MoIndividual;
+
+    fn population(objectives: &[[f64; 2]]) -> Vec<MoIndividual<f64>> {
+        objectives
+            .iter()
+            .map(|obj| MoIndividual {
+                objectives: obj.to_vec(),
+                ..MoIndividual::from(0.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn domination_requires_strict_improvement() {
+        assert!(dominates(&[1.0, 1.0], &[1.0, 2.0]));
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[0.0, 3.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn sort_partitions_population_into_fronts() {
+        let pop = population(&[[1.0, 4.0], [2.0, 2.0], [4.0, 1.0], [3.0, 3.0], [5.0, 5.0]]);
+        let mut fronts = fast_non_dominated_sort(&pop);
+        fronts.iter_mut().for_each(|front| front.sort());
+
+        assert_eq!(fronts, vec![vec![0, 1, 2], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn boundary_individuals_have_infinite_crowding_distance() {
+        let pop = population(&[[0.0, 4.0], [1.0, 3.0], [3.0, 1.0], [4.0, 0.0]]);
+        let distances = crowding_distance(&pop, &[0, 1, 2, 3]);
+
+        assert!(distances[0].is_infinite() && distances[3].is_infinite());
+        assert!((distances[1] - 1.5).abs() < 1e-9);
+        assert!((distances[2] - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rank_is_stored_in_individuals() {
+        let mut pop = population(&[[1.0, 1.0], [2.0, 2.0]]);
+        assign_rank_and_crowding(&mut pop);
+
+        assert_eq!(pop[0].rank, 0);
+        assert_eq!(pop[1].rank, 1);
+        assert!(pop[0] > pop[1]);
+    }
+}
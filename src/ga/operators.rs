@@ -1,5 +1,6 @@
 pub mod crossover;
 pub mod fitness;
+pub mod local_search;
 pub mod mutation;
 pub mod replacement;
 pub mod selection;
@@ -1,21 +1,29 @@
 use super::CrossoverOperator;
 
+pub mod arithmetic;
+pub mod blx_alpha;
 pub mod fixed_point;
+pub mod linear;
 pub mod multi_point;
 pub mod ordered;
 pub mod pmx;
 pub mod ppx;
+pub mod sbx;
 pub mod shuffle;
 pub mod single_point;
 pub mod two_point;
 pub mod uniform;
 pub mod uniform_parameterized;
 
+pub use arithmetic::Arithmetic;
+pub use blx_alpha::BlxAlpha;
 pub use fixed_point::FixedPoint;
+pub use linear::Linear;
 pub use multi_point::MultiPoint;
 pub use ordered::OrderedCrossover;
 pub use pmx::Pmx;
 pub use ppx::Ppx;
+pub use sbx::Sbx;
 pub use shuffle::Shuffle;
 pub use single_point::SinglePoint;
 pub use two_point::TwoPoint;
@@ -24,8 +32,11 @@ pub use uniform_parameterized::UniformParameterized;
 
 #[cfg(test)]
 mod test {
+    use crate::ga::bounds::Bounds;
     use crate::ga::individual::IndividualTrait;
-    use crate::ga::operators::crossover::{CrossoverOperator, FixedPoint, Pmx, Ppx, Shuffle};
+    use crate::ga::operators::crossover::{
+        Arithmetic, BlxAlpha, CrossoverOperator, FixedPoint, Linear, Pmx, Ppx, Sbx, Shuffle,
+    };
     use crate::ga::{Individual, Metrics};
     use std::iter::zip;
 
@@ -109,4 +120,61 @@ mod test {
         assert_eq!(child_1.chromosome(), &child_1_expected_chromosome);
         assert_eq!(child_2.chromosome(), &child_2_expected_chromosome);
     }
+
+    #[test]
+    fn real_coded_crossovers_respect_bounds() {
+        let bounds = Bounds::uniform(4, -1.0..1.0);
+        let mut operators: Vec<Box<dyn CrossoverOperator<Individual<Vec<f64>>>>> = vec![
+            Box::new(Sbx::new(2.0, bounds.clone())),
+            Box::new(BlxAlpha::new(0.5, bounds.clone())),
+            Box::new(Arithmetic::new()),
+            Box::new(Linear::new(bounds.clone())),
+        ];
+
+        let p1 = Individual::from(vec![-1.0, -0.9, 0.0, 0.95]);
+        let p2 = Individual::from(vec![1.0, 0.9, 0.5, -0.95]);
+
+        for operator in operators.iter_mut() {
+            for _ in 0..50 {
+                let children = operator.apply(&Metrics::default(), &[&p1, &p2]);
+                assert_eq!(children.len(), 2);
+                for child in children.iter() {
+                    assert!(bounds.contains(child.chromosome()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_children_lie_between_parents() {
+        let mut op = Arithmetic::new();
+
+        let p1 = Individual::from(vec![0.0, 2.0, -4.0]);
+        let p2 = Individual::from(vec![1.0, -2.0, -4.0]);
+
+        let children = op.apply(&Metrics::default(), &[&p1, &p2]);
+        for child in children.iter() {
+            for ((gene, gene_1), gene_2) in zip(zip(child.chromosome(), p1.chromosome()), p2.chromosome()) {
+                assert!(gene_1.min(*gene_2) <= *gene && *gene <= gene_1.max(*gene_2));
+            }
+        }
+        for ((gene_c1, gene_c2), (gene_1, gene_2)) in zip(
+            zip(children[0].chromosome(), children[1].chromosome()),
+            zip(p1.chromosome(), p2.chromosome()),
+        ) {
+            assert!((gene_c1 + gene_c2 - gene_1 - gene_2).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sbx_does_not_change_identical_parents() {
+        let mut op = Sbx::new(15.0, Bounds::uniform(3, -5.0..5.0));
+
+        let p1 = Individual::from(vec![0.5, -1.5, 3.0]);
+        let p2 = p1.clone();
+
+        let children = op.apply(&Metrics::default(), &[&p1, &p2]);
+        assert_eq!(children[0].chromosome(), p1.chromosome());
+        assert_eq!(children[1].chromosome(), p1.chromosome());
+    }
 }
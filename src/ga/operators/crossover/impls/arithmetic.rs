@@ -0,0 +1,98 @@
+use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
+use crate::ga::Metrics;
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use super::CrossoverOperator;
+
+/// # Whole arithmetic crossover operator
+///
+/// This struct implements [CrossoverOperator] trait and can be used with GA.
+///
+/// For each pair of parents weight `w` is drawn uniformly from [0, 1) and children are created as
+/// `w * parent_1 + (1 - w) * parent_2` and `(1 - w) * parent_1 + w * parent_2`.
+///
+/// Children are convex combinations of parents, thus they always stay within parents' bounds
+/// (as long as the bounds are box constraints) & no explicit bounds are needed.
+pub struct Arithmetic<R: Rng = ThreadRng> {
+    rng: R,
+}
+
+impl Arithmetic<ThreadRng> {
+    /// Creates new [Arithmetic] crossover operator with default RNG
+    pub fn new() -> Self {
+        Self::with_rng(rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Arithmetic<R> {
+    /// Creates new [Arithmetic] crossover operator with custom RNG
+    pub fn with_rng(rng: R) -> Self {
+        Self { rng }
+    }
+
+    /// Returns a tuple of children
+    ///
+    /// ## Arguments
+    ///
+    /// * `parent_1` - First parent to take part in recombination
+    /// * `parent_2` - Second parent to take part in recombination
+    fn apply_single(&mut self, parent_1: &[f64], parent_2: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        assert_eq!(
+            parent_1.len(),
+            parent_2.len(),
+            "Parent chromosome length must match"
+        );
+
+        let weight: f64 = self.rng.gen();
+
+        let child_1 = parent_1
+            .iter()
+            .zip(parent_2.iter())
+            .map(|(gene_1, gene_2)| weight * gene_1 + (1.0 - weight) * gene_2)
+            .collect();
+        let child_2 = parent_1
+            .iter()
+            .zip(parent_2.iter())
+            .map(|(gene_1, gene_2)| (1.0 - weight) * gene_1 + weight * gene_2)
+            .collect();
+
+        (child_1, child_2)
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Arithmetic<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> CrossoverOperator<IndividualT> for Arithmetic<R>
+where
+    IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>,
+    R: Rng,
+{
+    /// Returns vector of owned individuals which were created in result of applying crossover
+    /// operator.
+    ///
+    /// Children are weighted averages of parents, with weight drawn once per pair of parents.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - algorithm state metrics, see the structure details for more info,
+    /// * `selected` - references to individuals selected during selection step.
+    fn apply(&mut self, _metrics: &Metrics, selected: &[&IndividualT]) -> Vec<IndividualT> {
+        assert!(selected.len() & 1 == 0);
+
+        let mut output = Vec::with_capacity(selected.len());
+
+        for parents in selected.chunks(2) {
+            let (child_1, child_2) = self.apply_single(parents[0].chromosome(), parents[1].chromosome());
+            output.push(IndividualT::from(child_1));
+            output.push(IndividualT::from(child_2));
+        }
+
+        output
+    }
+}
@@ -0,0 +1,118 @@
+use crate::ga::bounds::Bounds;
+use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
+use crate::ga::Metrics;
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use super::CrossoverOperator;
+
+/// # BLX-alpha crossover operator
+///
+/// This struct implements [CrossoverOperator] trait and can be used with GA.
+///
+/// Blend crossover proposed by L. J. Eshelman & J. D. Schaffer. For each locus, let `d` be the distance
+/// between parent genes. Children genes are drawn uniformly from the interval spanned by parent genes,
+/// extended by `alpha * d` on both sides. Out-of-bounds genes are repaired with strategy of the [Bounds].
+pub struct BlxAlpha<R: Rng = ThreadRng> {
+    alpha: f64,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl BlxAlpha<ThreadRng> {
+    /// Creates new [BlxAlpha] crossover operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - extension of the sampling interval, must be >= 0, usually 0.5
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(alpha: f64, bounds: Bounds) -> Self {
+        Self::with_rng(alpha, bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> BlxAlpha<R> {
+    /// Creates new [BlxAlpha] crossover operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - extension of the sampling interval, must be >= 0, usually 0.5
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(alpha: f64, bounds: Bounds, rng: R) -> Self {
+        assert!(alpha >= 0.0, "Alpha must be >= 0");
+        Self { alpha, bounds, rng }
+    }
+
+    /// Returns a tuple of children
+    ///
+    /// ## Arguments
+    ///
+    /// * `parent_1` - First parent to take part in recombination
+    /// * `parent_2` - Second parent to take part in recombination
+    fn apply_single(&mut self, parent_1: &[f64], parent_2: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        assert_eq!(
+            parent_1.len(),
+            parent_2.len(),
+            "Parent chromosome length must match"
+        );
+        debug_assert_eq!(
+            parent_1.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        let mut child_1 = Vec::with_capacity(parent_1.len());
+        let mut child_2 = Vec::with_capacity(parent_1.len());
+
+        for (locus, (gene_1, gene_2)) in parent_1.iter().zip(parent_2.iter()).enumerate() {
+            let extension = self.alpha * (gene_1 - gene_2).abs();
+            let low = gene_1.min(*gene_2) - extension;
+            let width = gene_1.max(*gene_2) + extension - low;
+
+            let gene = low + self.rng.gen::<f64>() * width;
+            child_1.push(self.bounds.repair_gene(locus, gene, &mut self.rng));
+            let gene = low + self.rng.gen::<f64>() * width;
+            child_2.push(self.bounds.repair_gene(locus, gene, &mut self.rng));
+        }
+
+        (child_1, child_2)
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for BlxAlpha<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> CrossoverOperator<IndividualT> for BlxAlpha<R>
+where
+    IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>,
+    R: Rng,
+{
+    /// Returns vector of owned individuals which were created in result of applying crossover
+    /// operator.
+    ///
+    /// Children genes are drawn uniformly from the interval spanned by parent genes extended by
+    /// `alpha` times the distance between them on both sides.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - algorithm state metrics, see the structure details for more info,
+    /// * `selected` - references to individuals selected during selection step.
+    fn apply(&mut self, _metrics: &Metrics, selected: &[&IndividualT]) -> Vec<IndividualT> {
+        assert!(selected.len() & 1 == 0);
+
+        let mut output = Vec::with_capacity(selected.len());
+
+        for parents in selected.chunks(2) {
+            let (child_1, child_2) = self.apply_single(parents[0].chromosome(), parents[1].chromosome());
+            output.push(IndividualT::from(child_1));
+            output.push(IndividualT::from(child_2));
+        }
+
+        output
+    }
+}
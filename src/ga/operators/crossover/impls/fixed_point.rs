@@ -3,6 +3,7 @@ use len_trait::Len;
 use std::ops::IndexMut;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 
 use super::CrossoverOperator;
@@ -57,6 +58,11 @@ impl FixedPoint {
     }
 }
 
+impl Reseed for FixedPoint {
+    /// [FixedPoint] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
 impl<GeneT, IndividualT> CrossoverOperator<IndividualT> for FixedPoint
 where
     IndividualT: IndividualTrait,
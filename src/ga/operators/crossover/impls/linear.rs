@@ -0,0 +1,125 @@
+use crate::ga::bounds::Bounds;
+use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
+use crate::ga::Metrics;
+
+use rand::{rngs::ThreadRng, seq::index, Rng, SeedableRng};
+
+use super::CrossoverOperator;
+
+/// # Linear crossover operator
+///
+/// This struct implements [CrossoverOperator] trait and can be used with GA.
+///
+/// Crossover proposed by A. H. Wright. Three candidates are created from each pair of parents:
+/// `0.5 * parent_1 + 0.5 * parent_2`, `1.5 * parent_1 - 0.5 * parent_2` and `-0.5 * parent_1 + 1.5 * parent_2`.
+/// Out-of-bounds genes of candidates are repaired with strategy of the [Bounds].
+///
+/// Original formulation keeps two best candidates, however crossover operator has no access to the fitness
+/// function, therefore two of the candidates are chosen at random & selection pressure is left to the
+/// replacement operator.
+pub struct Linear<R: Rng = ThreadRng> {
+    bounds: Bounds,
+    rng: R,
+}
+
+impl Linear<ThreadRng> {
+    /// Creates new [Linear] crossover operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(bounds: Bounds) -> Self {
+        Self::with_rng(bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Linear<R> {
+    /// Creates new [Linear] crossover operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(bounds: Bounds, rng: R) -> Self {
+        Self { bounds, rng }
+    }
+
+    /// Returns candidate created as `weight_1 * parent_1 + weight_2 * parent_2`, repaired to fit bounds
+    fn candidate(&mut self, parent_1: &[f64], parent_2: &[f64], weight_1: f64, weight_2: f64) -> Vec<f64> {
+        let mut candidate: Vec<f64> = parent_1
+            .iter()
+            .zip(parent_2.iter())
+            .map(|(gene_1, gene_2)| weight_1 * gene_1 + weight_2 * gene_2)
+            .collect();
+        self.bounds.repair(&mut candidate, &mut self.rng);
+        candidate
+    }
+
+    /// Returns a tuple of children
+    ///
+    /// ## Arguments
+    ///
+    /// * `parent_1` - First parent to take part in recombination
+    /// * `parent_2` - Second parent to take part in recombination
+    fn apply_single(&mut self, parent_1: &[f64], parent_2: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        assert_eq!(
+            parent_1.len(),
+            parent_2.len(),
+            "Parent chromosome length must match"
+        );
+        debug_assert_eq!(
+            parent_1.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        let mut candidates = [
+            self.candidate(parent_1, parent_2, 0.5, 0.5),
+            self.candidate(parent_1, parent_2, 1.5, -0.5),
+            self.candidate(parent_1, parent_2, -0.5, 1.5),
+        ];
+
+        let chosen = index::sample(&mut self.rng, candidates.len(), 2);
+        let (first, second) = (chosen.index(0), chosen.index(1));
+
+        let child_1 = std::mem::take(&mut candidates[first]);
+        let child_2 = std::mem::take(&mut candidates[second]);
+        (child_1, child_2)
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Linear<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> CrossoverOperator<IndividualT> for Linear<R>
+where
+    IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>,
+    R: Rng,
+{
+    /// Returns vector of owned individuals which were created in result of applying crossover
+    /// operator.
+    ///
+    /// Two of three linear combinations of parents are returned for each pair of parents.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - algorithm state metrics, see the structure details for more info,
+    /// * `selected` - references to individuals selected during selection step.
+    fn apply(&mut self, _metrics: &Metrics, selected: &[&IndividualT]) -> Vec<IndividualT> {
+        assert!(selected.len() & 1 == 0);
+
+        let mut output = Vec::with_capacity(selected.len());
+
+        for parents in selected.chunks(2) {
+            let (child_1, child_2) = self.apply_single(parents[0].chromosome(), parents[1].chromosome());
+            output.push(IndividualT::from(child_1));
+            output.push(IndividualT::from(child_2));
+        }
+
+        output
+    }
+}
@@ -4,10 +4,11 @@ use std::hash::Hash;
 use std::ops::Index;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
 use super::CrossoverOperator;
 
@@ -136,6 +137,12 @@ impl<R: Rng> OrderedCrossover<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for OrderedCrossover<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<GeneT, IndividualT, R> CrossoverOperator<IndividualT> for OrderedCrossover<R>
 where
     IndividualT: IndividualTrait,
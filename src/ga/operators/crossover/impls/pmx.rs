@@ -5,10 +5,11 @@ use std::hash::Hash;
 use std::ops::Index;
 
 use crate::ga::individual::{Chromosome, IndividualTrait};
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
 use super::CrossoverOperator;
 
@@ -175,6 +176,12 @@ impl<R: Rng> Pmx<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for Pmx<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<GeneT, IndividualT, R> CrossoverOperator<IndividualT> for Pmx<R>
 where
     IndividualT: IndividualTrait,
@@ -0,0 +1,151 @@
+use crate::ga::bounds::Bounds;
+use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
+use crate::ga::Metrics;
+
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use super::CrossoverOperator;
+
+/// # Simulated binary crossover operator (SBX)
+///
+/// This struct implements [CrossoverOperator] trait and can be used with GA.
+///
+/// Crossover proposed by K. Deb & R. B. Agrawal (bounded variant, as used in NSGA-II).
+/// Each pair of genes is recombined with probability 0.5 so that children spread around parents
+/// following polynomial distribution with distribution index `eta`. The greater the index,
+/// the closer children stay to their parents. Children genes never leave their bounds (up to rounding errors,
+/// which are repaired with strategy of the [Bounds]).
+pub struct Sbx<R: Rng = ThreadRng> {
+    eta: f64,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl Sbx<ThreadRng> {
+    /// Creates new [Sbx] crossover operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `eta` - distribution index, must be >= 0, usually from range [2, 20]
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(eta: f64, bounds: Bounds) -> Self {
+        Self::with_rng(eta, bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Sbx<R> {
+    /// Creates new [Sbx] crossover operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `eta` - distribution index, must be >= 0, usually from range [2, 20]
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(eta: f64, bounds: Bounds, rng: R) -> Self {
+        assert!(eta >= 0.0, "Distribution index must be >= 0");
+        Self { eta, bounds, rng }
+    }
+
+    /// Returns spread factor for given random number `u` & bound-dependent `beta`
+    fn spread_factor(&self, u: f64, beta: f64) -> f64 {
+        let alpha = 2.0 - beta.powf(-(self.eta + 1.0));
+        if u <= 1.0 / alpha {
+            (u * alpha).powf(1.0 / (self.eta + 1.0))
+        } else {
+            (1.0 / (2.0 - u * alpha)).powf(1.0 / (self.eta + 1.0))
+        }
+    }
+
+    /// Returns a tuple of children
+    ///
+    /// ## Arguments
+    ///
+    /// * `parent_1` - First parent to take part in recombination
+    /// * `parent_2` - Second parent to take part in recombination
+    fn apply_single(&mut self, parent_1: &[f64], parent_2: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        assert_eq!(
+            parent_1.len(),
+            parent_2.len(),
+            "Parent chromosome length must match"
+        );
+        debug_assert_eq!(
+            parent_1.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        let mut child_1 = parent_1.to_vec();
+        let mut child_2 = parent_2.to_vec();
+
+        for locus in 0..parent_1.len() {
+            let bounds = self.bounds.range(locus);
+            let (y1, y2) = if parent_1[locus] < parent_2[locus] {
+                (parent_1[locus], parent_2[locus])
+            } else {
+                (parent_2[locus], parent_1[locus])
+            };
+
+            if self.rng.gen::<f64>() >= 0.5 || y2 - y1 <= f64::EPSILON {
+                continue;
+            }
+
+            let u: f64 = self.rng.gen();
+
+            let beta_q = self.spread_factor(u, 1.0 + 2.0 * (y1 - bounds.start) / (y2 - y1));
+            let c1 = 0.5 * ((y1 + y2) - beta_q * (y2 - y1));
+
+            let beta_q = self.spread_factor(u, 1.0 + 2.0 * (bounds.end - y2) / (y2 - y1));
+            let c2 = 0.5 * ((y1 + y2) + beta_q * (y2 - y1));
+
+            let c1 = self.bounds.repair_gene(locus, c1, &mut self.rng);
+            let c2 = self.bounds.repair_gene(locus, c2, &mut self.rng);
+
+            if self.rng.gen::<bool>() {
+                child_1[locus] = c2;
+                child_2[locus] = c1;
+            } else {
+                child_1[locus] = c1;
+                child_2[locus] = c2;
+            }
+        }
+
+        (child_1, child_2)
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Sbx<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> CrossoverOperator<IndividualT> for Sbx<R>
+where
+    IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>,
+    R: Rng,
+{
+    /// Returns vector of owned individuals which were created in result of applying crossover
+    /// operator.
+    ///
+    /// Each pair of genes is recombined with probability 0.5, children genes are drawn
+    /// from polynomial distribution centered around parent genes.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - algorithm state metrics, see the structure details for more info,
+    /// * `selected` - references to individuals selected during selection step.
+    fn apply(&mut self, _metrics: &Metrics, selected: &[&IndividualT]) -> Vec<IndividualT> {
+        assert!(selected.len() & 1 == 0);
+
+        let mut output = Vec::with_capacity(selected.len());
+
+        for parents in selected.chunks(2) {
+            let (child_1, child_2) = self.apply_single(parents[0].chromosome(), parents[1].chromosome());
+            output.push(IndividualT::from(child_1));
+            output.push(IndividualT::from(child_2));
+        }
+
+        output
+    }
+}
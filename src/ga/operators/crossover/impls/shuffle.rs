@@ -2,10 +2,11 @@ use itertools::{enumerate, Itertools};
 use len_trait::Len;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 use rand::prelude::SliceRandom;
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 use std::ops::Index;
 
 use super::CrossoverOperator;
@@ -89,6 +90,12 @@ impl<R: Rng> Shuffle<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for Shuffle<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<GeneT, IndividualT, R> CrossoverOperator<IndividualT> for Shuffle<R>
 where
     IndividualT: IndividualTrait,
@@ -2,10 +2,11 @@ use len_trait::Len;
 use std::ops::Index;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
 use super::CrossoverOperator;
 
@@ -80,6 +81,12 @@ impl<R: Rng> SinglePoint<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for SinglePoint<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<GeneT, IndividualT, R> CrossoverOperator<IndividualT> for SinglePoint<R>
 where
     IndividualT: IndividualTrait,
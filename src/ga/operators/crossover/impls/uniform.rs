@@ -3,10 +3,11 @@ use len_trait::Len;
 use std::ops::Index;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
 use super::CrossoverOperator;
 
@@ -34,6 +35,12 @@ impl<R: Rng + Clone> Uniform<R> {
     }
 }
 
+impl<R: Rng + Clone + SeedableRng> Reseed for Uniform<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<R> Uniform<R>
 where
     R: Rng + Clone,
@@ -69,9 +76,7 @@ where
         let mut child_1_ch = IndividualT::ChromosomeT::default();
         let mut child_2_ch = IndividualT::ChromosomeT::default();
 
-        let mask = self
-            .rng
-            .clone()
+        let mask = (&mut self.rng)
             .sample_iter(rand::distributions::Uniform::new(0.0, 1.0))
             .take(chromosome_len);
 
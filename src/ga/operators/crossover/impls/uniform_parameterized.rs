@@ -3,10 +3,11 @@ use len_trait::Len;
 use std::ops::Index;
 
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 use crate::ga::Metrics;
 use push_trait::{Nothing, Push};
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
 use super::CrossoverOperator;
 
@@ -40,6 +41,12 @@ impl<R: Rng> UniformParameterized<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for UniformParameterized<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<R> UniformParameterized<R>
 where
     R: Rng + Clone,
@@ -75,7 +82,7 @@ where
         let mut child_1_ch = IndividualT::ChromosomeT::default();
         let mut child_2_ch = IndividualT::ChromosomeT::default();
 
-        let mask = self.rng.clone().sample_iter(self.distr).take(chromosome_len);
+        let mask = (&mut self.rng).sample_iter(self.distr).take(chromosome_len);
 
         for (locus, val) in mask.enumerate() {
             if val <= self.bias {
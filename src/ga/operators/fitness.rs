@@ -1,7 +1,34 @@
 use crate::ga::individual::IndividualTrait;
 
+#[cfg(feature = "ga_parallel")]
+use rayon::prelude::*;
+
+/// # Fitness
+///
+/// This trait defines common behaviour for fitness functions used by genetic algorithm.
+/// You can implement this trait to provide your custom (possibly stateful) fitness function.
 pub trait Fitness<IndividualT: IndividualTrait> {
+    /// Returns fitness value of given individual
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - individual to evaluate
     fn apply(&mut self, individual: &mut IndividualT) -> IndividualT::FitnessValueT;
+
+    /// Evaluates every individual from `population` that requires evaluation
+    /// (see [IndividualTrait::requires_evaluation]) & stores the result in the individual.
+    ///
+    /// Default implementation evaluates individuals sequentially with [Fitness::apply].
+    ///
+    /// ## Arguments
+    ///
+    /// * `population` - individuals to evaluate
+    fn apply_batch(&mut self, population: &mut [IndividualT]) {
+        population
+            .iter_mut()
+            .filter(|idv| idv.requires_evaluation())
+            .for_each(|idv| *idv.fitness_mut() = self.apply(idv));
+    }
 }
 
 pub struct FnBasedFitness<IndividualT: IndividualTrait> {
@@ -14,8 +41,151 @@ impl<IndividualT: IndividualTrait> FnBasedFitness<IndividualT> {
     }
 }
 
+impl<IndividualT: IndividualTrait> Clone for FnBasedFitness<IndividualT> {
+    fn clone(&self) -> Self {
+        Self { fn_ptr: self.fn_ptr }
+    }
+}
+
 impl<IndividualT: IndividualTrait> Fitness<IndividualT> for FnBasedFitness<IndividualT> {
     fn apply(&mut self, individual: &mut IndividualT) -> IndividualT::FitnessValueT {
         (self.fn_ptr)(individual.chromosome())
     }
 }
+
+/// # Parallel fitness
+///
+/// Wrapper around any [Fitness] implementation, which evaluates population in parallel
+/// with use of [rayon]. Available with `ga_parallel` feature.
+///
+/// Every worker operates on its own clone of the wrapped fitness, created from the instance
+/// passed to the constructor, therefore stateful fitness functions are supported as long as
+/// the computed value depends only on the evaluated individual (e.g. any internal buffers are
+/// reset before each evaluation). Under this condition results are identical to sequential
+/// evaluation, thus runs with seeded operators stay deterministic.
+///
+/// **Note**: State of the wrapped instance is not modified by batch evaluation.
+#[cfg(feature = "ga_parallel")]
+pub struct ParallelFitness<F> {
+    fitness: F,
+    pool: Option<rayon::ThreadPool>,
+}
+
+#[cfg(feature = "ga_parallel")]
+impl<F> ParallelFitness<F> {
+    /// Returns new instance of [ParallelFitness] using global rayon thread pool
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - fitness function to evaluate individuals with
+    pub fn new(fitness: F) -> Self {
+        Self { fitness, pool: None }
+    }
+
+    /// Returns new instance of [ParallelFitness] using dedicated thread pool
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - fitness function to evaluate individuals with
+    /// * `threads` - number of worker threads, must be > 0
+    ///
+    /// ## Panics
+    ///
+    /// Iff thread pool could not be created or `threads` == 0
+    pub fn with_threads(fitness: F, threads: usize) -> Self {
+        assert!(threads > 0, "Number of threads must be > 0");
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("Failed to create thread pool for fitness evaluation");
+
+        Self {
+            fitness,
+            pool: Some(pool),
+        }
+    }
+}
+
+#[cfg(feature = "ga_parallel")]
+impl<IndividualT, F> Fitness<IndividualT> for ParallelFitness<F>
+where
+    IndividualT: IndividualTrait + Send,
+    F: Fitness<IndividualT> + Clone + Send + Sync,
+{
+    fn apply(&mut self, individual: &mut IndividualT) -> IndividualT::FitnessValueT {
+        self.fitness.apply(individual)
+    }
+
+    /// Evaluates every individual from `population` that requires evaluation in parallel.
+    ///
+    /// ## Arguments
+    ///
+    /// * `population` - individuals to evaluate
+    fn apply_batch(&mut self, population: &mut [IndividualT]) {
+        let fitness = &self.fitness;
+        match &self.pool {
+            Some(pool) => pool.install(|| par_apply_batch(fitness, population)),
+            None => par_apply_batch(fitness, population),
+        }
+    }
+}
+
+/// Evaluates individuals requiring evaluation in parallel, each worker using its own clone of `fitness`
+#[cfg(feature = "ga_parallel")]
+fn par_apply_batch<IndividualT, F>(fitness: &F, population: &mut [IndividualT])
+where
+    IndividualT: IndividualTrait + Send,
+    F: Fitness<IndividualT> + Clone + Send + Sync,
+{
+    population
+        .par_iter_mut()
+        .filter(|idv| idv.requires_evaluation())
+        .for_each_init(
+            || fitness.clone(),
+            |local_fitness, idv| *idv.fitness_mut() = local_fitness.apply(idv),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fitness, FnBasedFitness};
+    use crate::ga::individual::{IndividualTrait, RealValueIndividual};
+
+    #[allow(clippy::ptr_arg)]
+    fn sum(chromosome: &Vec<f64>) -> f64 {
+        chromosome.iter().sum()
+    }
+
+    fn population() -> Vec<RealValueIndividual> {
+        (0..64)
+            .map(|i| RealValueIndividual::from(vec![i as f64, 0.5 * i as f64, 1.0]))
+            .collect()
+    }
+
+    #[test]
+    fn batch_evaluation_sets_fitness_of_every_individual() {
+        let mut population = population();
+        FnBasedFitness::new(sum).apply_batch(&mut population);
+
+        for idv in population.iter() {
+            assert_eq!(idv.fitness(), sum(idv.chromosome()));
+        }
+    }
+
+    #[cfg(feature = "ga_parallel")]
+    #[test]
+    fn parallel_evaluation_matches_sequential_one() {
+        use super::ParallelFitness;
+
+        let mut sequential = population();
+        let mut parallel = population();
+
+        FnBasedFitness::new(sum).apply_batch(&mut sequential);
+        ParallelFitness::with_threads(FnBasedFitness::new(sum), 4).apply_batch(&mut parallel);
+
+        assert_eq!(
+            sequential.iter().map(|idv| idv.fitness()).collect::<Vec<f64>>(),
+            parallel.iter().map(|idv| idv.fitness()).collect::<Vec<f64>>()
+        );
+    }
+}
@@ -0,0 +1,433 @@
+//! Local search step of memetic genetic algorithm
+//!
+//! Memetic algorithm improves children with a local search after they are mutated & before
+//! replacement operator is applied. The step is optional & can be enabled through builder, e.g.
+//! [GenericBuilder::set_local_search](crate::ga::builder::GenericBuilder::set_local_search).
+//!
+//! The step is configured with [LocalSearchStage], which wraps any [LocalSearchOperator]
+//! together with:
+//!
+//! * [WriteBack] strategy - whether improved chromosome (Lamarckian) or only its fitness (Baldwinian)
+//!   is written back to the child,
+//! * probability of applying local search to a child,
+//! * per-generation budget of fitness evaluations.
+//!
+//! With `local_search` feature enabled, any [LocalSearch](crate::local_search::LocalSearch)
+//! implementation (e.g. [SimulatedAnnealing](crate::local_search::annealing::SimulatedAnnealing))
+//! can be used through [LocalSearchAdapter].
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::ga::{individual::IndividualTrait, operators::fitness::Fitness, seed::Reseed, Metrics};
+
+#[cfg(feature = "local_search")]
+use crate::local_search::LocalSearch;
+
+/// Fitness function wrapper handed to [LocalSearchOperator], which enforces evaluation budget
+/// & counts performed evaluations.
+pub struct Evaluator<'a, IndividualT: IndividualTrait> {
+    fitness: &'a mut dyn Fitness<IndividualT>,
+    budget: Option<usize>,
+    evaluation_count: usize,
+}
+
+impl<'a, IndividualT: IndividualTrait> Evaluator<'a, IndividualT> {
+    /// Returns new instance of [Evaluator]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fitness` - fitness function used to evaluate individuals
+    /// * `budget` - maximal number of evaluations, `None` for unlimited
+    pub fn new(fitness: &'a mut dyn Fitness<IndividualT>, budget: Option<usize>) -> Self {
+        Self {
+            fitness,
+            budget,
+            evaluation_count: 0,
+        }
+    }
+
+    /// Evaluates `individual`, stores the result in it & returns it. Returns `None` without evaluating
+    /// the individual if the budget is exhausted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - individual to evaluate
+    pub fn evaluate(&mut self, individual: &mut IndividualT) -> Option<IndividualT::FitnessValueT> {
+        if self.is_exhausted() {
+            return None;
+        }
+        self.evaluation_count += 1;
+        *individual.fitness_mut() = self.fitness.apply(individual);
+        Some(individual.fitness())
+    }
+
+    /// Returns number of evaluations left, `None` if the budget is unlimited
+    pub fn remaining(&self) -> Option<usize> {
+        self.budget.map(|budget| budget - self.evaluation_count)
+    }
+
+    /// Returns `true` iff no more evaluations can be performed
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+
+    /// Returns number of evaluations performed so far
+    pub fn evaluation_count(&self) -> usize {
+        self.evaluation_count
+    }
+}
+
+/// # Local Search Operator
+///
+/// This trait defines common behaviour for local search operators used by memetic genetic algorithm.
+/// You can implement this trait to provide your custom local search to the GA.
+pub trait LocalSearchOperator<IndividualT: IndividualTrait> {
+    /// Returns improved version of `individual` with up to date fitness. Returning a clone of `individual`
+    /// is fine in case no improvement was found.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - metrics of the algorithm
+    /// * `individual` - individual to improve, already evaluated
+    /// * `evaluator` - fitness function, which must be used for all the evaluations
+    fn apply(
+        &mut self,
+        metrics: &Metrics,
+        individual: &IndividualT,
+        evaluator: &mut Evaluator<IndividualT>,
+    ) -> IndividualT;
+}
+
+/// Describes how result of local search is written back to the child
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WriteBack {
+    /// Child is replaced with the improved individual
+    #[default]
+    Lamarckian,
+    /// Child keeps its chromosome, but takes over fitness of the improved individual.
+    ///
+    /// **Note**: Solver reevaluates every individual, for which [IndividualTrait::requires_evaluation] returns `true`,
+    /// therefore, unless the individual type tracks changes of its chromosome, the fitness is used only by
+    /// replacement operator. Builders reject this strategy when replacement operator does not use children
+    /// fitness (see [ReplacementOperator::requires_children_fitness](crate::ga::operators::replacement::ReplacementOperator::requires_children_fitness)).
+    Baldwinian,
+}
+
+/// Type of local search operator stored in [LocalSearchStage]
+pub type BoxedLocalSearchOperator<IndividualT> = Box<dyn LocalSearchOperator<IndividualT> + Send>;
+
+/// # Local Search Stage
+///
+/// Local search step of memetic genetic algorithm, see [module](self) docs.
+///
+/// Implements [Reseed]. Only the RNG deciding which children are improved is reseeded,
+/// the operator should be seeded by the user.
+pub struct LocalSearchStage<IndividualT: IndividualTrait> {
+    operator: BoxedLocalSearchOperator<IndividualT>,
+    write_back: WriteBack,
+    probability: f64,
+    budget: Option<usize>,
+    rng: StdRng,
+}
+
+impl<IndividualT: IndividualTrait> LocalSearchStage<IndividualT> {
+    /// Returns new instance of [LocalSearchStage], which improves every child with Lamarckian write back
+    /// & unlimited budget
+    ///
+    /// ## Arguments
+    ///
+    /// * `operator` - struct implementing [LocalSearchOperator] trait
+    pub fn new<OpT>(operator: OpT) -> Self
+    where
+        OpT: LocalSearchOperator<IndividualT> + Send + 'static,
+    {
+        Self {
+            operator: Box::new(operator),
+            write_back: WriteBack::default(),
+            probability: 1.0,
+            budget: None,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Sets write back strategy ([WriteBack::Lamarckian] by default)
+    ///
+    /// ## Arguments
+    ///
+    /// * `write_back` - write back strategy
+    pub fn with_write_back(mut self, write_back: WriteBack) -> Self {
+        self.write_back = write_back;
+        self
+    }
+
+    /// Returns write back strategy
+    pub fn write_back(&self) -> WriteBack {
+        self.write_back
+    }
+
+    /// Sets probability of applying local search to a child (1.0 by default)
+    ///
+    /// ## Arguments
+    ///
+    /// * `probability` - probability from range [0, 1]
+    pub fn with_probability(mut self, probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "Probability must be in range [0, 1]"
+        );
+        self.probability = probability;
+        self
+    }
+
+    /// Sets maximal number of fitness evaluations performed by local search in a single generation.
+    /// Once it is exhausted, remaining children are left untouched.
+    ///
+    /// ## Arguments
+    ///
+    /// * `budget` - number of evaluations per generation
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Applies local search to the children & returns number of performed fitness evaluations
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - metrics of the algorithm
+    /// * `children` - evaluated children
+    /// * `fitness` - fitness function
+    pub(crate) fn apply(
+        &mut self,
+        metrics: &Metrics,
+        children: &mut [IndividualT],
+        fitness: &mut dyn Fitness<IndividualT>,
+    ) -> usize {
+        let mut evaluator = Evaluator::new(fitness, self.budget);
+        for child in children.iter_mut() {
+            if evaluator.is_exhausted() {
+                break;
+            }
+            if self.rng.gen::<f64>() >= self.probability {
+                continue;
+            }
+            let improved = self.operator.apply(metrics, child, &mut evaluator);
+            match self.write_back {
+                WriteBack::Lamarckian => *child = improved,
+                WriteBack::Baldwinian => *child.fitness_mut() = improved.fitness(),
+            }
+        }
+        evaluator.evaluation_count()
+    }
+}
+
+impl<IndividualT: IndividualTrait> Reseed for LocalSearchStage<IndividualT> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+}
+
+/// # Local Search Adapter
+///
+/// Implements [LocalSearchOperator] for any [LocalSearch] implementation working on chromosomes
+/// of individuals with `f64` fitness. Available with `local_search` feature.
+///
+/// Local search algorithms always minimise, therefore fitness is negated when maximising.
+/// Once the evaluation budget is exhausted, every further solution is assessed as infinitely bad.
+#[cfg(feature = "local_search")]
+pub struct LocalSearchAdapter<L> {
+    search: L,
+    sign: f64,
+}
+
+#[cfg(feature = "local_search")]
+impl<L> LocalSearchAdapter<L> {
+    /// Returns adapter for problems, where lower fitness is better
+    ///
+    /// ## Arguments
+    ///
+    /// * `search` - local search algorithm
+    pub fn minimizing(search: L) -> Self {
+        Self { search, sign: 1.0 }
+    }
+
+    /// Returns adapter for problems, where higher fitness is better
+    ///
+    /// ## Arguments
+    ///
+    /// * `search` - local search algorithm
+    pub fn maximizing(search: L) -> Self {
+        Self { search, sign: -1.0 }
+    }
+
+    /// Returns wrapped local search algorithm, e.g. to reseed it
+    pub fn search_mut(&mut self) -> &mut L {
+        &mut self.search
+    }
+}
+
+#[cfg(feature = "local_search")]
+impl<IndividualT, L> LocalSearchOperator<IndividualT> for LocalSearchAdapter<L>
+where
+    IndividualT: IndividualTrait<FitnessValueT = f64>,
+    L: LocalSearch<IndividualT::ChromosomeT>,
+{
+    fn apply(
+        &mut self,
+        _metrics: &Metrics,
+        individual: &IndividualT,
+        evaluator: &mut Evaluator<IndividualT>,
+    ) -> IndividualT {
+        let sign = self.sign;
+        let result = self.search.search(individual.chromosome().clone(), |chromosome| {
+            let mut candidate = IndividualT::from(chromosome.clone());
+            match evaluator.evaluate(&mut candidate) {
+                Some(fitness) => sign * fitness,
+                None => f64::INFINITY,
+            }
+        });
+
+        if result.fitness < sign * individual.fitness() {
+            let mut improved = IndividualT::from(result.solution);
+            *improved.fitness_mut() = sign * result.fitness;
+            improved
+        } else {
+            individual.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Evaluator, LocalSearchOperator, LocalSearchStage, WriteBack};
+    use crate::ga::{
+        individual::IndividualTrait, operators::fitness::FnBasedFitness, seed::Reseed, Individual, Metrics,
+    };
+
+    /// Moves every gene halfway towards 0, as long as it improves the fitness
+    struct Halving;
+
+    impl LocalSearchOperator<Individual<Vec<f64>>> for Halving {
+        fn apply(
+            &mut self,
+            _metrics: &Metrics,
+            individual: &Individual<Vec<f64>>,
+            evaluator: &mut Evaluator<Individual<Vec<f64>>>,
+        ) -> Individual<Vec<f64>> {
+            let mut best = individual.clone();
+            loop {
+                let mut candidate =
+                    Individual::from(best.chromosome.iter().map(|x| x / 2.0).collect::<Vec<_>>());
+                match evaluator.evaluate(&mut candidate) {
+                    Some(fitness) if fitness < best.fitness => best = candidate,
+                    _ => return best,
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn sphere(x: &Vec<f64>) -> f64 {
+        x.iter().map(|v| v * v).sum()
+    }
+
+    fn evaluated_children(count: usize) -> Vec<Individual<Vec<f64>>> {
+        (0..count)
+            .map(|i| {
+                let mut child = Individual::from(vec![i as f64 + 1.0; 2]);
+                child.fitness = sphere(&child.chromosome);
+                child
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lamarckian_write_back_replaces_chromosome() {
+        let mut fitness = FnBasedFitness::new(sphere);
+        let mut stage = LocalSearchStage::new(Halving).with_budget(3);
+        let mut children = evaluated_children(2);
+
+        let evaluations = stage.apply(&Metrics::default(), &mut children, &mut fitness);
+
+        assert_eq!(evaluations, 3);
+        assert_eq!(children[0].chromosome, vec![0.125; 2]);
+        assert_eq!(children[0].fitness(), sphere(&vec![0.125; 2]));
+        // Budget was exhausted on the first child
+        assert_eq!(children[1].chromosome, vec![2.0; 2]);
+    }
+
+    #[test]
+    fn baldwinian_write_back_keeps_chromosome() {
+        let mut fitness = FnBasedFitness::new(sphere);
+        let mut stage = LocalSearchStage::new(Halving)
+            .with_write_back(WriteBack::Baldwinian)
+            .with_budget(1);
+        let mut children = evaluated_children(1);
+
+        stage.apply(&Metrics::default(), &mut children, &mut fitness);
+
+        assert_eq!(children[0].chromosome, vec![1.0; 2]);
+        assert_eq!(children[0].fitness(), 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Baldwinian local search requires replacement operator using children fitness")]
+    fn baldwinian_write_back_is_rejected_when_children_fitness_is_not_used() {
+        crate::ga::Builder::with_rvc()
+            .fitness_fn(sphere)
+            .dim(2)
+            .set_local_search(LocalSearchStage::new(Halving).with_write_back(WriteBack::Baldwinian))
+            .build();
+    }
+
+    #[test]
+    fn probability_limits_improved_children() {
+        let mut fitness = FnBasedFitness::new(sphere);
+        let mut stage = LocalSearchStage::new(Halving).with_probability(0.0);
+        let mut children = evaluated_children(5);
+
+        assert_eq!(stage.apply(&Metrics::default(), &mut children, &mut fitness), 0);
+
+        let mut stage = LocalSearchStage::new(Halving).with_probability(0.5);
+        stage.reseed(7);
+        let mut children = evaluated_children(100);
+        stage.apply(&Metrics::default(), &mut children, &mut fitness);
+
+        let improved = children
+            .iter()
+            .enumerate()
+            .filter(|(i, child)| child.chromosome[0] != *i as f64 + 1.0)
+            .count();
+        assert!(improved > 30 && improved < 70);
+    }
+
+    #[cfg(feature = "local_search")]
+    #[test]
+    fn adapter_runs_local_search_on_chromosome() {
+        use super::LocalSearchAdapter;
+        use crate::local_search::{annealing::SimulatedAnnealing, cooling::Geometric, neighbourhood::Swap};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // Number of inversions, minimised by sorting
+        #[allow(clippy::ptr_arg)]
+        fn inversions(p: &Vec<usize>) -> f64 {
+            (0..p.len())
+                .flat_map(|i| (i + 1..p.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| p[i] > p[j])
+                .count() as f64
+        }
+
+        let mut fitness = FnBasedFitness::new(inversions);
+        let search =
+            SimulatedAnnealing::with_rng(Swap, Geometric::new(0.95), 1.0, 2000, StdRng::seed_from_u64(3));
+        let mut stage = LocalSearchStage::new(LocalSearchAdapter::minimizing(search)).with_budget(5000);
+        let mut child = Individual::from(vec![4, 2, 0, 3, 1]);
+        child.fitness = inversions(&child.chromosome);
+        let mut children = vec![child];
+
+        stage.apply(&Metrics::default(), &mut children, &mut fitness);
+
+        assert_eq!(children[0].chromosome, vec![0, 1, 2, 3, 4]);
+        assert_eq!(children[0].fitness, 0.0);
+    }
+}
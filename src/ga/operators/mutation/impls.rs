@@ -2,9 +2,12 @@ use std::{marker::PhantomData, ops::IndexMut};
 
 use len_trait::Len;
 use push_trait::{Nothing, Push};
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
 
-use crate::ga::{individual::IndividualTrait, Metrics};
+use crate::ga::{
+    bounds::Bounds, individual::IndividualTrait, seed::Reseed, value_provider::ValueProvider, Metrics,
+};
+use crate::util::standard_normal;
 
 use super::MutationOperator;
 
@@ -23,6 +26,11 @@ impl Identity {
     }
 }
 
+impl Reseed for Identity {
+    /// [Identity] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
 impl<IndividualT: IndividualTrait> MutationOperator<IndividualT> for Identity {
     fn apply(&mut self, _metrics: &Metrics, _individual: &mut IndividualT) {}
 }
@@ -53,6 +61,12 @@ impl<R: Rng> FlipBit<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for FlipBit<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT, R> MutationOperator<IndividualT> for FlipBit<R>
 where
     IndividualT: IndividualTrait,
@@ -106,6 +120,12 @@ impl<R: Rng> Interchange<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for Interchange<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT, G, R> MutationOperator<IndividualT> for Interchange<R>
 where
     G: Copy,
@@ -129,7 +149,7 @@ where
 
         for i in 0..chromosome_len {
             if self.rng.sample(dist) < self.mutation_rate {
-                let rand_index = rand::thread_rng().sample(index_dist);
+                let rand_index = self.rng.sample(index_dist);
                 let gene = chromosome_ref[rand_index];
                 chromosome_ref[rand_index] = chromosome_ref[i];
                 chromosome_ref[i] = gene;
@@ -164,6 +184,12 @@ impl<R: Rng> Reversing<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for Reversing<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT, GeneT, R> MutationOperator<IndividualT> for Reversing<R>
 where
     GeneT: Copy,
@@ -225,6 +251,12 @@ impl<R: Rng, GeneT: Copy> Inversion<GeneT, R> {
     }
 }
 
+impl<GeneT: Copy, R: Rng + SeedableRng> Reseed for Inversion<GeneT, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, GeneT: Copy, R: Rng> MutationOperator<IndividualT> for Inversion<GeneT, R>
 where
     IndividualT::ChromosomeT: Len + AsMut<[GeneT]>,
@@ -258,13 +290,387 @@ where
     }
 }
 
+/// ### Gaussian mutation operator
+///
+/// This struct implements [MutationOperator] trait and can be used with GA
+///
+/// Each gene is mutated with probability `mutation_rate` by adding a value drawn from normal
+/// distribution N(0, sigma^2). Sigma is obtained from [ValueProvider] each time the operator is applied,
+/// so that it can change during the run (pass plain `f64` for constant sigma).
+/// Out-of-bounds genes are repaired with strategy of the [Bounds].
+pub struct Gaussian<SigmaValue: ValueProvider<f64>, R: Rng = ThreadRng> {
+    mutation_rate: f64,
+    sigma: SigmaValue,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl<SigmaValue: ValueProvider<f64>> Gaussian<SigmaValue, ThreadRng> {
+    /// Returns new instance of [Gaussian] mutation operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `sigma` - standard deviation of the perturbation
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(mutation_rate: f64, sigma: SigmaValue, bounds: Bounds) -> Self {
+        Self::with_rng(mutation_rate, sigma, bounds, rand::thread_rng())
+    }
+}
+
+impl<SigmaValue: ValueProvider<f64>, R: Rng> Gaussian<SigmaValue, R> {
+    /// Returns new instance of [Gaussian] mutation operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `sigma` - standard deviation of the perturbation
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(mutation_rate: f64, sigma: SigmaValue, bounds: Bounds, rng: R) -> Self {
+        Self {
+            mutation_rate,
+            sigma,
+            bounds,
+            rng,
+        }
+    }
+}
+
+impl<SigmaValue: ValueProvider<f64>, R: Rng + SeedableRng> Reseed for Gaussian<SigmaValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, SigmaValue, R> MutationOperator<IndividualT> for Gaussian<SigmaValue, R>
+where
+    IndividualT: IndividualTrait,
+    IndividualT::ChromosomeT: AsMut<[f64]>,
+    SigmaValue: ValueProvider<f64>,
+    R: Rng,
+{
+    /// Mutates provided solution in place
+    ///
+    /// Each gene is perturbed with probability `mutation_rate` by value drawn from N(0, sigma^2)
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - mutable reference to to-be-mutated individual
+    fn apply(&mut self, metrics: &Metrics, individual: &mut IndividualT) {
+        let sigma = self.sigma.get(metrics);
+        let chromosome = individual.chromosome_mut().as_mut();
+        debug_assert_eq!(
+            chromosome.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        for (locus, gene) in chromosome.iter_mut().enumerate() {
+            if self.rng.gen::<f64>() < self.mutation_rate {
+                let perturbation = sigma * standard_normal(&mut self.rng);
+                *gene = self
+                    .bounds
+                    .repair_gene(locus, *gene + perturbation, &mut self.rng);
+            }
+        }
+    }
+}
+
+/// ### Polynomial mutation operator
+///
+/// This struct implements [MutationOperator] trait and can be used with GA
+///
+/// Polynomial mutation proposed by K. Deb & M. Goyal (bounded variant, as used in NSGA-II).
+/// Each gene is mutated with probability `mutation_rate`. The perturbation follows polynomial
+/// distribution with distribution index `eta` - the greater the index, the closer the mutated gene
+/// stays to its original value. Mutated genes never leave their bounds (up to rounding errors,
+/// which are repaired with strategy of the [Bounds]).
+pub struct Polynomial<R: Rng = ThreadRng> {
+    mutation_rate: f64,
+    eta: f64,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl Polynomial<ThreadRng> {
+    /// Returns new instance of [Polynomial] mutation operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation, usually 1 / chromosome length
+    /// * `eta` - distribution index, must be >= 0, usually from range [20, 100]
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(mutation_rate: f64, eta: f64, bounds: Bounds) -> Self {
+        Self::with_rng(mutation_rate, eta, bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Polynomial<R> {
+    /// Returns new instance of [Polynomial] mutation operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation, usually 1 / chromosome length
+    /// * `eta` - distribution index, must be >= 0, usually from range [20, 100]
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(mutation_rate: f64, eta: f64, bounds: Bounds, rng: R) -> Self {
+        assert!(eta >= 0.0, "Distribution index must be >= 0");
+        Self {
+            mutation_rate,
+            eta,
+            bounds,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Polynomial<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> MutationOperator<IndividualT> for Polynomial<R>
+where
+    IndividualT: IndividualTrait,
+    IndividualT::ChromosomeT: AsMut<[f64]>,
+    R: Rng,
+{
+    /// Mutates provided solution in place
+    ///
+    /// Each gene is perturbed with probability `mutation_rate` by value drawn from polynomial distribution
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - mutable reference to to-be-mutated individual
+    fn apply(&mut self, _metrics: &Metrics, individual: &mut IndividualT) {
+        let chromosome = individual.chromosome_mut().as_mut();
+        debug_assert_eq!(
+            chromosome.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        let mut_pow = 1.0 / (self.eta + 1.0);
+
+        for (locus, gene) in chromosome.iter_mut().enumerate() {
+            let bounds = self.bounds.range(locus);
+            let range = bounds.end - bounds.start;
+            if self.rng.gen::<f64>() >= self.mutation_rate || range <= 0.0 {
+                continue;
+            }
+
+            let delta_lower = (*gene - bounds.start) / range;
+            let delta_upper = (bounds.end - *gene) / range;
+            let u: f64 = self.rng.gen();
+
+            let delta_q = if u < 0.5 {
+                let val = 2.0 * u + (1.0 - 2.0 * u) * (1.0 - delta_lower).powf(self.eta + 1.0);
+                val.powf(mut_pow) - 1.0
+            } else {
+                let val = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * (1.0 - delta_upper).powf(self.eta + 1.0);
+                1.0 - val.powf(mut_pow)
+            };
+
+            *gene = self
+                .bounds
+                .repair_gene(locus, *gene + delta_q * range, &mut self.rng);
+        }
+    }
+}
+
+/// ### Uniform reset mutation operator
+///
+/// This struct implements [MutationOperator] trait and can be used with GA
+///
+/// Each gene is mutated with probability `mutation_rate` by replacing it with value
+/// drawn uniformly from its bounds.
+pub struct UniformReset<R: Rng = ThreadRng> {
+    mutation_rate: f64,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl UniformReset<ThreadRng> {
+    /// Returns new instance of [UniformReset] mutation operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(mutation_rate: f64, bounds: Bounds) -> Self {
+        Self::with_rng(mutation_rate, bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> UniformReset<R> {
+    /// Returns new instance of [UniformReset] mutation operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(mutation_rate: f64, bounds: Bounds, rng: R) -> Self {
+        Self {
+            mutation_rate,
+            bounds,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for UniformReset<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> MutationOperator<IndividualT> for UniformReset<R>
+where
+    IndividualT: IndividualTrait,
+    IndividualT::ChromosomeT: AsMut<[f64]>,
+    R: Rng,
+{
+    /// Mutates provided solution in place
+    ///
+    /// Each gene is replaced with probability `mutation_rate` by value drawn uniformly from its bounds
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - mutable reference to to-be-mutated individual
+    fn apply(&mut self, _metrics: &Metrics, individual: &mut IndividualT) {
+        let chromosome = individual.chromosome_mut().as_mut();
+        debug_assert_eq!(
+            chromosome.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        for (locus, gene) in chromosome.iter_mut().enumerate() {
+            if self.rng.gen::<f64>() < self.mutation_rate {
+                let bounds = self.bounds.range(locus);
+                *gene = bounds.start + self.rng.gen::<f64>() * (bounds.end - bounds.start);
+            }
+        }
+    }
+}
+
+/// ### Non-uniform mutation operator
+///
+/// This struct implements [MutationOperator] trait and can be used with GA
+///
+/// Non-uniform mutation proposed by Z. Michalewicz. Each gene is mutated with probability `mutation_rate`
+/// by moving it towards one of its bounds (chosen at random) by
+/// `delta(t, y) = y * (1 - r^((1 - t / T)^b))`, where `y` is the distance to the bound, `r` is uniform
+/// random number from [0, 1), `t` is current generation ([Metrics::generation]) and `T` is the generation limit.
+/// Strength of the mutation therefore decays as the run progresses, fine tuning solutions in late generations.
+pub struct NonUniform<R: Rng = ThreadRng> {
+    mutation_rate: f64,
+    shape: f64,
+    generation_limit: usize,
+    bounds: Bounds,
+    rng: R,
+}
+
+impl NonUniform<ThreadRng> {
+    /// Returns new instance of [NonUniform] mutation operator with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `shape` - parameter `b` controlling speed of the decay, usually 5
+    /// * `generation_limit` - generation after which genes are no longer mutated, should equal generation
+    ///   limit of the algorithm
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    pub fn new(mutation_rate: f64, shape: f64, generation_limit: usize, bounds: Bounds) -> Self {
+        Self::with_rng(mutation_rate, shape, generation_limit, bounds, rand::thread_rng())
+    }
+}
+
+impl<R: Rng> NonUniform<R> {
+    /// Returns new instance of [NonUniform] mutation operator with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `mutation_rate` - probability of gene mutation
+    /// * `shape` - parameter `b` controlling speed of the decay, usually 5
+    /// * `generation_limit` - generation after which genes are no longer mutated, should equal generation
+    ///   limit of the algorithm
+    /// * `bounds` - domain of the problem, must be of chromosome's length
+    /// * `rng` - random number generator
+    pub fn with_rng(mutation_rate: f64, shape: f64, generation_limit: usize, bounds: Bounds, rng: R) -> Self {
+        assert!(generation_limit > 0, "Generation limit must be > 0");
+        Self {
+            mutation_rate,
+            shape,
+            generation_limit,
+            bounds,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for NonUniform<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT, R> MutationOperator<IndividualT> for NonUniform<R>
+where
+    IndividualT: IndividualTrait,
+    IndividualT::ChromosomeT: AsMut<[f64]>,
+    R: Rng,
+{
+    /// Mutates provided solution in place
+    ///
+    /// Each gene is moved with probability `mutation_rate` towards one of its bounds, the step
+    /// decreasing with generation number
+    ///
+    /// ## Arguments
+    ///
+    /// * `individual` - mutable reference to to-be-mutated individual
+    fn apply(&mut self, metrics: &Metrics, individual: &mut IndividualT) {
+        let chromosome = individual.chromosome_mut().as_mut();
+        debug_assert_eq!(
+            chromosome.len(),
+            self.bounds.dim(),
+            "Bounds must be given for every gene"
+        );
+
+        let progress = metrics.generation.min(self.generation_limit) as f64 / self.generation_limit as f64;
+        let exponent = (1.0 - progress).powf(self.shape);
+
+        for (locus, gene) in chromosome.iter_mut().enumerate() {
+            if self.rng.gen::<f64>() >= self.mutation_rate {
+                continue;
+            }
+
+            let bounds = self.bounds.range(locus);
+            let step = 1.0 - self.rng.gen::<f64>().powf(exponent);
+            let mutated = if self.rng.gen::<bool>() {
+                *gene + (bounds.end - *gene) * step
+            } else {
+                *gene - (*gene - bounds.start) * step
+            };
+            *gene = self.bounds.repair_gene(locus, mutated, &mut self.rng);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ga::{individual::IndividualTrait, Individual, Metrics};
+    use crate::ga::{bounds::Bounds, individual::IndividualTrait, Individual, Metrics};
     use itertools::Itertools;
     use rand::{distributions::Uniform, Rng};
 
-    use super::{FlipBit, Identity, Interchange, MutationOperator, Reversing};
+    use super::{
+        FlipBit, Gaussian, Identity, Interchange, MutationOperator, NonUniform, Polynomial, Reversing,
+        UniformReset,
+    };
 
     #[test]
     fn identity_does_not_change_chromosome() {
@@ -404,4 +810,63 @@ mod tests {
             individual.chromosome()[individual.chromosome().len() - 1]
         );
     }
+
+    fn real_valued_individual() -> Individual<Vec<f64>> {
+        Individual {
+            chromosome: rand::thread_rng()
+                .sample_iter(Uniform::from(-1.0..1.0))
+                .take(50)
+                .collect_vec(),
+            fitness: f64::default(),
+        }
+    }
+
+    #[test]
+    fn real_valued_mutations_respect_bounds() {
+        let bounds = Bounds::uniform(50, -1.0..1.0);
+        let mut operators: Vec<Box<dyn MutationOperator<Individual<Vec<f64>>>>> = vec![
+            Box::new(Gaussian::new(1.0, 10.0, bounds.clone())),
+            Box::new(Polynomial::new(1.0, 20.0, bounds.clone())),
+            Box::new(UniformReset::new(1.0, bounds.clone())),
+            Box::new(NonUniform::new(1.0, 5.0, 100, bounds.clone())),
+        ];
+
+        for operator in operators.iter_mut() {
+            for _ in 0..20 {
+                let mut individual = real_valued_individual();
+                operator.apply(&Metrics::default(), &mut individual);
+                assert!(bounds.contains(individual.chromosome()));
+            }
+        }
+    }
+
+    #[test]
+    fn real_valued_mutations_do_not_mutate_rate_0() {
+        let bounds = Bounds::uniform(50, -1.0..1.0);
+        let mut operators: Vec<Box<dyn MutationOperator<Individual<Vec<f64>>>>> = vec![
+            Box::new(Gaussian::new(0.0, 1.0, bounds.clone())),
+            Box::new(Polynomial::new(0.0, 20.0, bounds.clone())),
+            Box::new(UniformReset::new(0.0, bounds.clone())),
+            Box::new(NonUniform::new(0.0, 5.0, 100, bounds)),
+        ];
+
+        for operator in operators.iter_mut() {
+            let mut individual = real_valued_individual();
+            let chromosome = individual.chromosome.clone();
+            operator.apply(&Metrics::default(), &mut individual);
+            assert_eq!(chromosome, individual.chromosome);
+        }
+    }
+
+    #[test]
+    fn non_uniform_does_not_mutate_after_generation_limit() {
+        let mut individual = real_valued_individual();
+        let chromosome = individual.chromosome.clone();
+
+        let mut operator = NonUniform::new(1.0, 5.0, 100, Bounds::uniform(50, -1.0..1.0));
+        let metrics = Metrics::new(None, None, 100, 0);
+        operator.apply(&metrics, &mut individual);
+
+        assert_eq!(chromosome, individual.chromosome);
+    }
 }
@@ -1,4 +1,6 @@
-use crate::ga::{individual::IndividualTrait, Metrics};
+use rand::{rngs::ThreadRng, Rng, SeedableRng};
+
+use crate::ga::{individual::IndividualTrait, seed::Reseed, Metrics};
 
 use super::ReplacementOperator;
 
@@ -22,6 +24,11 @@ impl BothParents {
     }
 }
 
+impl Reseed for BothParents {
+    /// [BothParents] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
 impl<IndividualT: IndividualTrait> ReplacementOperator<IndividualT> for BothParents {
     /// Works simply by replacing parents with their children
     ///
@@ -68,6 +75,11 @@ impl Noop {
     }
 }
 
+impl Reseed for Noop {
+    /// [Noop] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
 impl<IndividualT: IndividualTrait> ReplacementOperator<IndividualT> for Noop {
     /// Returns input `population`.
     #[inline(always)]
@@ -117,6 +129,11 @@ impl WeakParent {
     }
 }
 
+impl Reseed for WeakParent {
+    /// [WeakParent] does not use randomness, thus it is a noop
+    fn reseed(&mut self, _seed: u64) {}
+}
+
 impl<IndividualT: IndividualTrait> ReplacementOperator<IndividualT> for WeakParent {
     /// Works by taking two out of four individuals (two parents and two children) with the largest fitness.
     ///
@@ -180,11 +197,81 @@ impl<IndividualT: IndividualTrait> ReplacementOperator<IndividualT> for WeakPare
     }
 }
 
+/// # Random replacement operator
+///
+/// This struct implements [ReplacementOperator] trait and can be used with genetic algorithm.
+///
+/// Works by replacing randomly chosen individuals from `population` with `children`.
+/// Each individual from `population` is replaced at most once, therefore when there are more
+/// children than individuals in population, the excess children are discarded.
+///
+/// It does not take fitness into account, thus it is well suited e.g. for merging immigrants
+/// into island's population (see [crate::ga::island]).
+pub struct Random<R: Rng = ThreadRng> {
+    rng: R,
+}
+
+impl Random<ThreadRng> {
+    /// Returns new instance of [Random] replacement operator with default RNG
+    pub fn new() -> Self {
+        Self::with_rng(rand::thread_rng())
+    }
+}
+
+impl<R: Rng> Random<R> {
+    /// Returns new instance of [Random] replacement operator with custom RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `rng` - random numbers generator
+    pub fn with_rng(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<R: Rng + SeedableRng> Reseed for Random<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<IndividualT: IndividualTrait, R: Rng> ReplacementOperator<IndividualT> for Random<R> {
+    /// Replaces randomly chosen individuals from `population` with `children`.
+    ///
+    /// ### Arguments
+    ///
+    /// * `population` - Original population, input to the crossover phase.
+    ///   This collection should be modified in place by the operator.
+    /// * `children` - Individuals to insert into population
+    fn apply(
+        &mut self,
+        _metrics: &Metrics,
+        mut population: Vec<IndividualT>,
+        children: Vec<IndividualT>,
+    ) -> Vec<IndividualT> {
+        let count = usize::min(population.len(), children.len());
+        let indices = rand::seq::index::sample(&mut self.rng, population.len(), count);
+
+        for (idx, child) in indices.into_iter().zip(children) {
+            population[idx] = child;
+        }
+        population
+    }
+
+    /// Returns `true` when the operator requires children to possess valid fitness values.
+    ///
+    /// This implementation returns `false`.
+    #[inline(always)]
+    fn requires_children_fitness(&self) -> bool {
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ga::{Individual, Metrics};
 
-    use super::{BothParents, Noop, ReplacementOperator, WeakParent};
+    use super::{BothParents, Noop, Random, ReplacementOperator, WeakParent};
 
     #[test]
     fn noop_has_new_method() {
@@ -377,4 +464,29 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn random_replaces_as_many_individuals_as_possible() {
+        let parents = vec![
+            Individual {
+                chromosome: 0.0,
+                fitness: 0.0,
+            };
+            5
+        ];
+        let children = vec![
+            Individual {
+                chromosome: 1.0,
+                fitness: 1.0,
+            };
+            3
+        ];
+
+        let result = Random::new().apply(&Metrics::default(), parents.clone(), children.clone());
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.iter().filter(|idv| idv.chromosome == 1.0).count(), 3);
+
+        let result = Random::new().apply(&Metrics::default(), children, parents);
+        assert!(result.iter().all(|idv| idv.chromosome == 0.0));
+    }
 }
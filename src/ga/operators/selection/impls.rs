@@ -5,10 +5,10 @@ use rand::{
     distributions::{Standard, Uniform},
     prelude::Distribution,
     rngs::ThreadRng,
-    Rng,
+    Rng, SeedableRng,
 };
 
-use crate::ga::{individual::IndividualTrait, value_provider::ValueProvider, Metrics};
+use crate::ga::{individual::IndividualTrait, seed::Reseed, value_provider::ValueProvider, Metrics};
 
 use super::SelectionOperator;
 
@@ -53,6 +53,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> RouletteWheel<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for RouletteWheel<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 // FIXME: It will return empty vector if total_fitness == 0
 // WORKING CHANGE: crt >= threshold instead of crt_sum > threshold
 // But this should be resolved some other way
@@ -136,6 +142,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng + Clone> Random<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + Clone + SeedableRng> Reseed for Random<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng + Clone>
     SelectionOperator<IndividualT> for Random<SizeValue, R>
 {
@@ -150,9 +162,7 @@ impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng + Clo
     fn apply<'a>(&mut self, metrics: &Metrics, population: &'a [IndividualT]) -> Vec<&'a IndividualT> {
         let count = self.selection_size.get(metrics);
         let distr_ind = Uniform::new(0, population.len());
-        let selection_iter = self
-            .rng
-            .clone()
+        let selection_iter = (&mut self.rng)
             .sample_iter(distr_ind)
             .take(count)
             .map(|i| &population[i]);
@@ -193,6 +203,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> UniqueRandom<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for UniqueRandom<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng + Clone>
     SelectionOperator<IndividualT> for UniqueRandom<SizeValue, R>
 {
@@ -263,6 +279,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> Rank<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for Rank<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng> SelectionOperator<IndividualT>
     for Rank<SizeValue, R>
 where
@@ -347,6 +369,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> RankR<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for RankR<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng> SelectionOperator<IndividualT>
     for RankR<SizeValue, R>
 {
@@ -436,6 +464,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> Tournament<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for Tournament<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait, SizeValue: ValueProvider<usize>, R: Rng> SelectionOperator<IndividualT>
     for Tournament<SizeValue, R>
 {
@@ -527,6 +561,14 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> StochasticUniversalSampling<SizeVa
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed
+    for StochasticUniversalSampling<SizeValue, R>
+{
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 // FIXME: Panics then total_fitness == 0
 // Should this be expected or do we want to handle this?
 impl<IndividualT: IndividualTrait<FitnessValueT = f64>, SizeValue: ValueProvider<usize>, R: Rng>
@@ -666,6 +708,12 @@ impl<SizeValue: ValueProvider<usize>, R: Rng> Boltzmann<SizeValue, R> {
     }
 }
 
+impl<SizeValue: ValueProvider<usize>, R: Rng + SeedableRng> Reseed for Boltzmann<SizeValue, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT, SizeValue, R> SelectionOperator<IndividualT> for Boltzmann<SizeValue, R>
 where
     IndividualT: IndividualTrait<FitnessValueT = f64>,
@@ -1,11 +1,13 @@
 use itertools::Itertools;
 use rand::prelude::ThreadRng;
 use rand::seq::SliceRandom;
-use rand::{thread_rng, Rng};
+use rand::{thread_rng, Rng, SeedableRng};
 use std::fmt::Debug;
 use std::ops::{Range, RangeInclusive};
 
+use crate::ga::bounds::Bounds;
 use crate::ga::individual::IndividualTrait;
+use crate::ga::seed::Reseed;
 
 use super::{tools, PopulationGenerator};
 
@@ -14,7 +16,7 @@ use super::{tools, PopulationGenerator};
 /// Generates vector of random points from R^(dim) space within passed domain constraints.
 pub struct RandomPoints<R: Rng = ThreadRng> {
     dim: usize,
-    constraints: Vec<Range<f64>>,
+    bounds: Bounds,
     rng: R,
 }
 
@@ -29,6 +31,15 @@ impl RandomPoints<ThreadRng> {
         Self::with_constraints_and_rng(dim, constraints, thread_rng())
     }
 
+    /// Returns [RandomPoints] population generator sampling points from given bounds with default RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `bounds` -- Domain of the problem
+    pub fn with_bounds(bounds: Bounds) -> Self {
+        Self::with_bounds_and_rng(bounds, thread_rng())
+    }
+
     /// Returns [RandomPoints] population generator with given constraints and default RNG
     ///
     /// ### Arguments
@@ -86,13 +97,29 @@ impl<R: Rng> RandomPoints<R> {
             "Number of constraints must match dimension of sampled space"
         );
 
+        Self::with_bounds_and_rng(Bounds::new(constraints), rng)
+    }
+
+    /// Returns [RandomPoints] population generator sampling points from given bounds with custom RNG
+    ///
+    /// ### Arguments
+    ///
+    /// * `bounds` -- Domain of the problem
+    /// * `rng` -- Random numbers generator
+    pub fn with_bounds_and_rng(bounds: Bounds, rng: R) -> Self {
         RandomPoints {
-            dim,
-            constraints,
+            dim: bounds.dim(),
+            bounds,
             rng,
         }
     }
 
+    /// Returns bounds points are sampled from
+    #[inline]
+    pub fn bounds(&self) -> &Bounds {
+        &self.bounds
+    }
+
     /// Returns [RandomPoints] population generator with no explicit constraints and custom RNG.
     /// Points coords will be from range 0.0..1.0.
     ///
@@ -104,12 +131,18 @@ impl<R: Rng> RandomPoints<R> {
         assert!(dim > 0, "Space dimension must be > 0");
         RandomPoints {
             dim,
-            constraints: Vec::from_iter(std::iter::repeat(0.0..1.0).take(dim)),
+            bounds: Bounds::uniform(dim, 0.0..1.0),
             rng,
         }
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for RandomPoints<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>, R: Rng + Clone> PopulationGenerator<IndividualT>
     for RandomPoints<R>
 {
@@ -120,8 +153,8 @@ impl<IndividualT: IndividualTrait<ChromosomeT = Vec<f64>>, R: Rng + Clone> Popul
     ///
     /// * `count` -- Number of points to generate
     fn generate(&mut self, count: usize) -> Vec<IndividualT> {
-        tools::PointGenerator::with_rng(self.rng.clone())
-            .generate_with_constraints(self.dim, count, &self.constraints)
+        tools::PointGenerator::with_rng(&mut self.rng)
+            .generate_with_constraints(self.dim, count, &self.bounds.ranges().to_vec())
             .into_iter()
             .map(|chromosome| IndividualT::from(chromosome))
             .collect_vec()
@@ -160,6 +193,12 @@ impl<R: Rng> BitStrings<R> {
     }
 }
 
+impl<R: Rng + SeedableRng> Reseed for BitStrings<R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait<ChromosomeT = Vec<bool>>, R: Rng> PopulationGenerator<IndividualT>
     for BitStrings<R>
 {
@@ -215,6 +254,12 @@ impl<GeneT: Copy, R: Rng> RandomPermutations<GeneT, R> {
     }
 }
 
+impl<GeneT: Copy, R: Rng + SeedableRng> Reseed for RandomPermutations<GeneT, R> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
 impl<IndividualT: IndividualTrait<ChromosomeT = Vec<GeneT>>, GeneT, R> PopulationGenerator<IndividualT>
     for RandomPermutations<GeneT, R>
 where
@@ -18,7 +18,7 @@ impl PointGenerator<ThreadRng> {
     }
 }
 
-impl<R: Rng + Clone> PointGenerator<R> {
+impl<R: Rng> PointGenerator<R> {
     /// Returns new instance of [PointGenerator with given RNG.
     pub fn with_rng(rng: R) -> Self {
         Self { rng }
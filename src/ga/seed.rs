@@ -0,0 +1,123 @@
+//! Seeding of genetic operators for reproducible runs
+//!
+//! Operators holding an RNG implement [Reseed] as long as the RNG type implements [SeedableRng](rand::SeedableRng)
+//! (e.g. [StdRng](rand::rngs::StdRng), but not [ThreadRng](rand::rngs::ThreadRng)). Operators without
+//! any randomness implement it as a noop.
+//!
+//! Builders expose `set_seed` method (e.g. [GenericBuilder::set_seed](crate::ga::builder::GenericBuilder::set_seed)),
+//! which derives independent RNG stream for every operator & population generator from single seed,
+//! so that the whole run can be repeated by passing the same seed again.
+//!
+//! Seeded solver additionally reseeds its operators at the beginning of every generation with a seed derived from
+//! run's seed & generation number (see [generation_seed]). State of all RNGs between generations is therefore
+//! determined by the seed & generation counter alone, which lets [checkpoint](crate::ga::checkpoint) resume
+//! a run exactly.
+//!
+//! **Note**: Fitness functions & probes are not reseeded. Neither are local search operators - only the decision
+//! which children are improved (see [LocalSearchStage](crate::ga::operators::local_search::LocalSearchStage)).
+
+use super::{
+    individual::IndividualTrait,
+    operators::{
+        crossover::CrossoverOperator, fitness::Fitness, mutation::MutationOperator,
+        replacement::ReplacementOperator, selection::SelectionOperator,
+    },
+    population::PopulationGenerator,
+    GAConfig, Probe,
+};
+
+/// Common behaviour for structs holding random number generator, which can be reseeded.
+pub trait Reseed {
+    /// Replaces internal random number generator with a new one, created from `seed`
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - seed for the random number generator
+    fn reseed(&mut self, seed: u64);
+}
+
+/// Identifiers of RNG streams derived for components of genetic algorithm
+pub(crate) mod stream {
+    pub const POPULATION_GENERATOR: u64 = 1;
+    pub const SELECTION: u64 = 2;
+    pub const CROSSOVER: u64 = 3;
+    pub const MUTATION: u64 = 4;
+    pub const REPLACEMENT: u64 = 5;
+    pub const GENERATION: u64 = 6;
+    pub const LOCAL_SEARCH: u64 = 7;
+}
+
+/// Returns seed for stream `stream` derived from `seed`.
+///
+/// Derivation uses SplitMix64 finalizer, therefore seeds of different streams (as well as
+/// of the same stream for different base seeds) are uncorrelated.
+///
+/// ## Arguments
+///
+/// * `seed` - base seed
+/// * `stream` - stream identifier
+pub fn derive_seed(seed: u64, stream: u64) -> u64 {
+    let mut z = seed.wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E3779B97F4A7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Returns seed operators are reseeded with at the beginning of given generation.
+///
+/// ## Arguments
+///
+/// * `seed` - seed of the run
+/// * `generation` - generation number
+pub fn generation_seed(seed: u64, generation: usize) -> u64 {
+    derive_seed(derive_seed(seed, stream::GENERATION), generation as u64)
+}
+
+/// Reseeds all operators & population generator of given config with streams derived from `seed`
+pub(crate) fn reseed_config<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>(
+    config: &mut GAConfig<IndividualT, MutOpT, CrossOpT, SelOpT, ReplOpT, PopGenT, FitnessT, ProbeT>,
+    seed: u64,
+) where
+    IndividualT: IndividualTrait,
+    MutOpT: MutationOperator<IndividualT> + Reseed,
+    CrossOpT: CrossoverOperator<IndividualT> + Reseed,
+    SelOpT: SelectionOperator<IndividualT> + Reseed,
+    ReplOpT: ReplacementOperator<IndividualT> + Reseed,
+    PopGenT: PopulationGenerator<IndividualT> + Reseed,
+    FitnessT: Fitness<IndividualT>,
+    ProbeT: Probe<IndividualT>,
+{
+    config
+        .population_factory
+        .reseed(derive_seed(seed, stream::POPULATION_GENERATOR));
+    config
+        .selection_operator
+        .reseed(derive_seed(seed, stream::SELECTION));
+    config
+        .crossover_operator
+        .reseed(derive_seed(seed, stream::CROSSOVER));
+    config
+        .mutation_operator
+        .reseed(derive_seed(seed, stream::MUTATION));
+    config
+        .replacement_operator
+        .reseed(derive_seed(seed, stream::REPLACEMENT));
+    if let Some(local_search) = config.local_search.as_mut() {
+        local_search.reseed(derive_seed(seed, stream::LOCAL_SEARCH));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_seed;
+
+    #[test]
+    fn derived_seeds_differ_between_streams() {
+        let seeds: Vec<u64> = (0..5).map(|stream| derive_seed(42, stream)).collect();
+        for (i, a) in seeds.iter().enumerate() {
+            assert!(seeds[i + 1..].iter().all(|b| a != b));
+        }
+        assert_eq!(derive_seed(42, 3), derive_seed(42, 3));
+        assert_ne!(derive_seed(42, 3), derive_seed(43, 3));
+    }
+}
@@ -0,0 +1,535 @@
+//! Termination conditions for genetic algorithm
+//!
+//! Genetic algorithm always stops once `generation_limit` or `max_duration` (see [GAParams](crate::ga::GAParams))
+//! is reached. Additional termination condition can be passed to the solver through its builder, e.g.
+//! [GenericBuilder::set_termination_condition](crate::ga::builder::GenericBuilder::set_termination_condition).
+//!
+//! Following conditions are implemented in the library:
+//!
+//! * [FitnessTarget]
+//! * [Stagnation]
+//! * [DiversityCollapse]
+//! * [EvaluationBudget]
+//! * [Any]
+//! * [All]
+
+use num_traits::ToPrimitive;
+use serde_json::Value;
+
+use super::{individual::IndividualTrait, Metrics};
+
+/// # Termination Condition
+///
+/// This trait defines common behaviour for termination conditions of genetic algorithm.
+/// You can implement this trait to provide your custom termination condition.
+pub trait TerminationCondition<IndividualT: IndividualTrait> {
+    /// Initialises condition internal state. Called once, right after initial population is created & evaluated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - metrics of the algorithm
+    /// * `population` - initial population
+    fn init(&mut self, metrics: &Metrics, population: &[IndividualT]);
+
+    /// Updates internal state & checks whether the algorithm should stop. Called after every generation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` - metrics of the algorithm
+    /// * `population` - current population, already evaluated
+    /// * `best_individual` - best individual found so far
+    fn update_and_check(
+        &mut self,
+        metrics: &Metrics,
+        population: &[IndividualT],
+        best_individual: &IndividualT,
+    ) -> bool;
+
+    /// Returns internal state of the condition, so that it can be stored in a checkpoint
+    /// (see [checkpoint](crate::ga::checkpoint) module). Stateless conditions return [None] (the default).
+    fn state(&self) -> Option<Value> {
+        None
+    }
+
+    /// Restores internal state returned by [TerminationCondition::state]. Called when a run is resumed
+    /// from checkpoint, right after [TerminationCondition::init].
+    ///
+    /// ## Arguments
+    ///
+    /// * `state` - state stored in the checkpoint
+    /// * `best_individual` - best individual found so far
+    fn restore_state(&mut self, _state: Value, _best_individual: &IndividualT) {}
+}
+
+/// Type of termination condition stored in [GAConfig](crate::ga::GAConfig)
+pub type BoxedTerminationCondition<IndividualT> = Box<dyn TerminationCondition<IndividualT> + Send>;
+
+/// # Fitness Target
+///
+/// Implements [TerminationCondition].
+///
+/// Stops the algorithm as soon as any individual in population reaches target fitness value.
+/// Use [FitnessTarget::below] when minimizing & [FitnessTarget::above] when maximizing fitness.
+pub struct FitnessTarget<FitnessValueT> {
+    target: FitnessValueT,
+    below: bool,
+}
+
+impl<FitnessValueT: PartialOrd> FitnessTarget<FitnessValueT> {
+    /// Returns new instance of [FitnessTarget], which is met when fitness <= `target`
+    ///
+    /// ## Arguments
+    ///
+    /// * `target` - fitness value to reach
+    pub fn below(target: FitnessValueT) -> Self {
+        Self { target, below: true }
+    }
+
+    /// Returns new instance of [FitnessTarget], which is met when fitness >= `target`
+    ///
+    /// ## Arguments
+    ///
+    /// * `target` - fitness value to reach
+    pub fn above(target: FitnessValueT) -> Self {
+        Self { target, below: false }
+    }
+
+    fn is_reached<IndividualT>(&self, population: &[IndividualT]) -> bool
+    where
+        IndividualT: IndividualTrait<FitnessValueT = FitnessValueT>,
+    {
+        population.iter().any(|idv| match self.below {
+            true => idv.fitness() <= self.target,
+            false => idv.fitness() >= self.target,
+        })
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT>
+    for FitnessTarget<IndividualT::FitnessValueT>
+where
+    IndividualT::FitnessValueT: PartialOrd,
+{
+    fn init(&mut self, _metrics: &Metrics, _population: &[IndividualT]) {}
+
+    fn update_and_check(
+        &mut self,
+        _metrics: &Metrics,
+        population: &[IndividualT],
+        _best_individual: &IndividualT,
+    ) -> bool {
+        self.is_reached(population)
+    }
+}
+
+/// # Stagnation
+///
+/// Implements [TerminationCondition].
+///
+/// Stops the algorithm when fitness of the best individual found so far does not change
+/// for given number of consecutive generations.
+pub struct Stagnation<FitnessValueT> {
+    generations: usize,
+    stagnant_generations: usize,
+    best_fitness: Option<FitnessValueT>,
+}
+
+impl<FitnessValueT> Stagnation<FitnessValueT> {
+    /// Returns new instance of [Stagnation]
+    ///
+    /// ## Arguments
+    ///
+    /// * `generations` - number of generations without improvement, after which the algorithm stops
+    pub fn new(generations: usize) -> Self {
+        Self {
+            generations,
+            stagnant_generations: 0,
+            best_fitness: None,
+        }
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT>
+    for Stagnation<IndividualT::FitnessValueT>
+{
+    fn init(&mut self, _metrics: &Metrics, _population: &[IndividualT]) {
+        self.stagnant_generations = 0;
+        self.best_fitness = None;
+    }
+
+    fn update_and_check(
+        &mut self,
+        _metrics: &Metrics,
+        _population: &[IndividualT],
+        best_individual: &IndividualT,
+    ) -> bool {
+        let fitness = best_individual.fitness();
+        if self.best_fitness.as_ref() == Some(&fitness) {
+            self.stagnant_generations += 1;
+        } else {
+            self.stagnant_generations = 0;
+            self.best_fitness = Some(fitness);
+        }
+
+        self.stagnant_generations >= self.generations
+    }
+
+    fn state(&self) -> Option<Value> {
+        Some(Value::from(self.stagnant_generations))
+    }
+
+    fn restore_state(&mut self, state: Value, best_individual: &IndividualT) {
+        if let Some(stagnant_generations) = state.as_u64() {
+            self.stagnant_generations = stagnant_generations as usize;
+            self.best_fitness = Some(best_individual.fitness());
+        }
+    }
+}
+
+/// # Diversity Collapse
+///
+/// Implements [TerminationCondition].
+///
+/// Stops the algorithm when diversity of the population falls below given threshold.
+/// By default diversity is measured as standard deviation of fitness values in population,
+/// custom measure can be provided with [DiversityCollapse::with_measure].
+pub struct DiversityCollapse<IndividualT: IndividualTrait> {
+    threshold: f64,
+    measure: fn(&[IndividualT]) -> f64,
+}
+
+impl<IndividualT: IndividualTrait> DiversityCollapse<IndividualT>
+where
+    IndividualT::FitnessValueT: ToPrimitive,
+{
+    /// Returns new instance of [DiversityCollapse] using standard deviation of fitness values
+    /// as diversity measure
+    ///
+    /// ## Arguments
+    ///
+    /// * `threshold` - the algorithm stops when standard deviation of fitness is below this value
+    pub fn new(threshold: f64) -> Self {
+        Self::with_measure(threshold, fitness_std_dev)
+    }
+}
+
+impl<IndividualT: IndividualTrait> DiversityCollapse<IndividualT> {
+    /// Returns new instance of [DiversityCollapse] with custom diversity measure
+    ///
+    /// ## Arguments
+    ///
+    /// * `threshold` - the algorithm stops when diversity is below this value
+    /// * `measure` - function computing diversity of population
+    pub fn with_measure(threshold: f64, measure: fn(&[IndividualT]) -> f64) -> Self {
+        Self { threshold, measure }
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT> for DiversityCollapse<IndividualT> {
+    fn init(&mut self, _metrics: &Metrics, _population: &[IndividualT]) {}
+
+    fn update_and_check(
+        &mut self,
+        _metrics: &Metrics,
+        population: &[IndividualT],
+        _best_individual: &IndividualT,
+    ) -> bool {
+        (self.measure)(population) < self.threshold
+    }
+}
+
+/// Returns standard deviation of fitness values in population. Fitness values that
+/// can not be represented as `f64` are skipped.
+pub fn fitness_std_dev<IndividualT: IndividualTrait>(population: &[IndividualT]) -> f64
+where
+    IndividualT::FitnessValueT: ToPrimitive,
+{
+    let values: Vec<f64> = population
+        .iter()
+        .filter_map(|idv| idv.fitness().to_f64())
+        .collect();
+
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt()
+}
+
+/// # Evaluation Budget
+///
+/// Implements [TerminationCondition].
+///
+/// Stops the algorithm once number of fitness evaluations (see [Metrics::evaluation_count])
+/// reaches given budget. Check is performed after each generation, thus the budget might be
+/// exceeded by at most evaluations of a single generation.
+pub struct EvaluationBudget {
+    max_evaluations: usize,
+}
+
+impl EvaluationBudget {
+    /// Returns new instance of [EvaluationBudget]
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_evaluations` - fitness evaluations limit
+    pub fn new(max_evaluations: usize) -> Self {
+        Self { max_evaluations }
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT> for EvaluationBudget {
+    fn init(&mut self, _metrics: &Metrics, _population: &[IndividualT]) {}
+
+    fn update_and_check(
+        &mut self,
+        metrics: &Metrics,
+        _population: &[IndividualT],
+        _best_individual: &IndividualT,
+    ) -> bool {
+        metrics.evaluation_count >= self.max_evaluations
+    }
+}
+
+/// # Any
+///
+/// Implements [TerminationCondition].
+///
+/// Met when any of aggregated conditions is met. All conditions are updated every generation.
+pub struct Any<IndividualT: IndividualTrait> {
+    conditions: Vec<BoxedTerminationCondition<IndividualT>>,
+}
+
+impl<IndividualT: IndividualTrait> Any<IndividualT> {
+    /// Returns new instance of [Any]
+    ///
+    /// ## Arguments
+    ///
+    /// * `conditions` - conditions to aggregate
+    pub fn new(conditions: Vec<BoxedTerminationCondition<IndividualT>>) -> Self {
+        Self { conditions }
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT> for Any<IndividualT> {
+    fn init(&mut self, metrics: &Metrics, population: &[IndividualT]) {
+        self.conditions
+            .iter_mut()
+            .for_each(|cond| cond.init(metrics, population));
+    }
+
+    fn update_and_check(
+        &mut self,
+        metrics: &Metrics,
+        population: &[IndividualT],
+        best_individual: &IndividualT,
+    ) -> bool {
+        // Every condition has to be updated, thus no short-circuiting here
+        let met_count = self
+            .conditions
+            .iter_mut()
+            .map(|cond| cond.update_and_check(metrics, population, best_individual))
+            .filter(|&is_met| is_met)
+            .count();
+        met_count > 0
+    }
+
+    fn state(&self) -> Option<Value> {
+        combined_state(&self.conditions)
+    }
+
+    fn restore_state(&mut self, state: Value, best_individual: &IndividualT) {
+        restore_combined_state(&mut self.conditions, state, best_individual);
+    }
+}
+
+/// # All
+///
+/// Implements [TerminationCondition].
+///
+/// Met when all of aggregated conditions are met. All conditions are updated every generation.
+pub struct All<IndividualT: IndividualTrait> {
+    conditions: Vec<BoxedTerminationCondition<IndividualT>>,
+}
+
+impl<IndividualT: IndividualTrait> All<IndividualT> {
+    /// Returns new instance of [All]
+    ///
+    /// ## Arguments
+    ///
+    /// * `conditions` - conditions to aggregate
+    pub fn new(conditions: Vec<BoxedTerminationCondition<IndividualT>>) -> Self {
+        Self { conditions }
+    }
+}
+
+impl<IndividualT: IndividualTrait> TerminationCondition<IndividualT> for All<IndividualT> {
+    fn init(&mut self, metrics: &Metrics, population: &[IndividualT]) {
+        self.conditions
+            .iter_mut()
+            .for_each(|cond| cond.init(metrics, population));
+    }
+
+    fn update_and_check(
+        &mut self,
+        metrics: &Metrics,
+        population: &[IndividualT],
+        best_individual: &IndividualT,
+    ) -> bool {
+        let met_count = self
+            .conditions
+            .iter_mut()
+            .map(|cond| cond.update_and_check(metrics, population, best_individual))
+            .filter(|&is_met| is_met)
+            .count();
+        met_count == self.conditions.len()
+    }
+
+    fn state(&self) -> Option<Value> {
+        combined_state(&self.conditions)
+    }
+
+    fn restore_state(&mut self, state: Value, best_individual: &IndividualT) {
+        restore_combined_state(&mut self.conditions, state, best_individual);
+    }
+}
+
+/// Returns states of aggregated conditions, in order of aggregation
+fn combined_state<IndividualT: IndividualTrait>(
+    conditions: &[BoxedTerminationCondition<IndividualT>],
+) -> Option<Value> {
+    Some(Value::Array(
+        conditions
+            .iter()
+            .map(|cond| cond.state().unwrap_or(Value::Null))
+            .collect(),
+    ))
+}
+
+/// Restores states of aggregated conditions returned by [combined_state]
+fn restore_combined_state<IndividualT: IndividualTrait>(
+    conditions: &mut [BoxedTerminationCondition<IndividualT>],
+    state: Value,
+    best_individual: &IndividualT,
+) {
+    if let Value::Array(states) = state {
+        conditions
+            .iter_mut()
+            .zip(states)
+            .filter(|(_, state)| !state.is_null())
+            .for_each(|(cond, state)| cond.restore_state(state, best_individual));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        All, Any, DiversityCollapse, EvaluationBudget, FitnessTarget, Stagnation, TerminationCondition,
+    };
+    use crate::ga::{Individual, Metrics};
+
+    fn population(fitness: &[f64]) -> Vec<Individual<f64>> {
+        fitness
+            .iter()
+            .map(|&fitness| Individual {
+                chromosome: 0.0,
+                fitness,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fitness_target_respects_direction() {
+        let metrics = Metrics::default();
+        let pop = population(&[1.0, 5.0, 3.0]);
+
+        assert!(FitnessTarget::below(1.0).update_and_check(&metrics, &pop, &pop[0]));
+        assert!(!FitnessTarget::below(0.5).update_and_check(&metrics, &pop, &pop[0]));
+        assert!(FitnessTarget::above(5.0).update_and_check(&metrics, &pop, &pop[0]));
+        assert!(!FitnessTarget::above(5.5).update_and_check(&metrics, &pop, &pop[0]));
+    }
+
+    #[test]
+    fn stagnation_is_met_after_given_number_of_generations_without_change() {
+        let metrics = Metrics::default();
+        let pop = population(&[2.0, 1.0]);
+        let mut cond = Stagnation::new(2);
+        cond.init(&metrics, &pop);
+
+        assert!(!cond.update_and_check(&metrics, &pop, &pop[0]));
+        assert!(!cond.update_and_check(&metrics, &pop, &pop[0]));
+        assert!(!cond.update_and_check(&metrics, &pop, &pop[1]));
+        assert!(!cond.update_and_check(&metrics, &pop, &pop[1]));
+        assert!(cond.update_and_check(&metrics, &pop, &pop[1]));
+    }
+
+    #[test]
+    fn stagnation_state_can_be_restored() {
+        let metrics = Metrics::default();
+        let pop = population(&[2.0, 1.0]);
+        let mut cond = Any::new(vec![
+            Box::new(FitnessTarget::below(0.0)),
+            Box::new(Stagnation::new(3)),
+        ]);
+        cond.init(&metrics, &pop);
+        cond.update_and_check(&metrics, &pop, &pop[1]);
+        cond.update_and_check(&metrics, &pop, &pop[1]);
+
+        let mut restored = Any::new(vec![
+            Box::new(FitnessTarget::below(0.0)),
+            Box::new(Stagnation::new(3)),
+        ]);
+        restored.init(&metrics, &pop);
+        restored.restore_state(cond.state().unwrap(), &pop[1]);
+
+        assert!(!restored.update_and_check(&metrics, &pop, &pop[1]));
+        assert!(restored.update_and_check(&metrics, &pop, &pop[1]));
+    }
+
+    #[test]
+    fn diversity_collapse_detects_uniform_population() {
+        let metrics = Metrics::default();
+        let uniform = population(&[2.0, 2.0, 2.0]);
+        let diverse = population(&[2.0, 4.0, 6.0]);
+        let mut cond = DiversityCollapse::new(0.1);
+
+        assert!(cond.update_and_check(&metrics, &uniform, &uniform[0]));
+        assert!(!cond.update_and_check(&metrics, &diverse, &diverse[0]));
+    }
+
+    #[test]
+    fn evaluation_budget_checks_evaluation_count() {
+        let pop = population(&[1.0]);
+        let mut metrics = Metrics::default();
+        let mut cond = EvaluationBudget::new(10);
+
+        metrics.evaluation_count = 9;
+        assert!(!TerminationCondition::update_and_check(
+            &mut cond, &metrics, &pop, &pop[0]
+        ));
+        metrics.evaluation_count = 10;
+        assert!(TerminationCondition::update_and_check(
+            &mut cond, &metrics, &pop, &pop[0]
+        ));
+    }
+
+    #[test]
+    fn combinators_aggregate_conditions() {
+        let metrics = Metrics::default();
+        let pop = population(&[1.0, 5.0]);
+
+        let mut any = Any::new(vec![
+            Box::new(FitnessTarget::below(0.0)),
+            Box::new(FitnessTarget::above(5.0)),
+        ]);
+        let mut all = All::new(vec![
+            Box::new(FitnessTarget::below(0.0)),
+            Box::new(FitnessTarget::above(5.0)),
+        ]);
+
+        assert!(any.update_and_check(&metrics, &pop, &pop[0]));
+        assert!(!all.update_and_check(&metrics, &pop, &pop[0]));
+    }
+}
@@ -15,12 +15,21 @@ extern crate core;
 
 #[cfg(feature = "aco")]
 pub mod aco;
+#[cfg(feature = "cmaes")]
+pub mod cmaes;
+#[cfg(feature = "de")]
+pub mod de;
 #[cfg(feature = "ff")]
 pub mod ff;
 #[cfg(feature = "ga")]
 pub mod ga;
+#[cfg(feature = "local_search")]
+pub mod local_search;
+#[cfg(any(feature = "cmaes", feature = "de"))]
+pub mod optimizer;
 pub mod prelude;
 #[cfg(feature = "pso")]
 pub mod pso;
 #[cfg(feature = "test_functions")]
 pub mod test_functions;
+mod util;
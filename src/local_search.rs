@@ -0,0 +1,54 @@
+//! Single-solution metaheuristics
+//!
+//! Local search methods iteratively improve a single solution by applying moves from its
+//! [neighbourhood](neighbourhood::Neighbourhood), e.g. swapping two elements of a permutation.
+//! The objective is minimised.
+//!
+//! Following methods are implemented:
+//!
+//! * [SimulatedAnnealing](annealing::SimulatedAnnealing) with pluggable [cooling schedules](cooling)
+//! * [TabuSearch](tabu::TabuSearch) with tabu tenure & [aspiration criteria](tabu::Aspiration)
+//!
+//! Both implement [LocalSearch] trait, so they can be used standalone or as an improvement step
+//! of other algorithms.
+//!
+//! # Usage example:
+//! ```rust
+//! # use ecrs::local_search::{annealing::SimulatedAnnealing, cooling::Geometric, neighbourhood::Swap, LocalSearch};
+//! // Sort the permutation, i.e. minimise number of misplaced elements
+//! let objective = |p: &Vec<usize>| p.iter().enumerate().filter(|(i, x)| *i != **x).count() as f64;
+//! let mut annealing = SimulatedAnnealing::new(Swap, Geometric::new(0.99), 2.0, 5_000);
+//! let result = annealing.search(vec![4, 2, 0, 3, 1, 5], objective);
+//! assert_eq!(result.fitness, 0.0);
+//! ```
+
+pub mod annealing;
+pub mod cooling;
+pub mod neighbourhood;
+pub mod tabu;
+
+/// Outcome of local search
+#[derive(Clone, Debug)]
+pub struct SearchResult<S> {
+    /// The best solution found
+    pub solution: S,
+    /// Objective value of the best solution
+    pub fitness: f64,
+    /// Number of iterations done
+    pub iterations: usize,
+    /// Number of objective function evaluations
+    pub evaluations: usize,
+}
+
+/// # Local search
+///
+/// This trait defines common behaviour for single-solution metaheuristics.
+pub trait LocalSearch<S> {
+    /// Returns the best solution found when starting from `initial` one
+    ///
+    /// ## Arguments
+    ///
+    /// * `initial` - starting solution
+    /// * `objective` - function to minimise
+    fn search<F: FnMut(&S) -> f64>(&mut self, initial: S, objective: F) -> SearchResult<S>;
+}
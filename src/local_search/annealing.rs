@@ -0,0 +1,201 @@
+use rand::{rngs::ThreadRng, Rng};
+
+use super::{cooling::CoolingSchedule, neighbourhood::Neighbourhood, LocalSearch, SearchResult};
+
+/// # Simulated annealing
+///
+/// Implements [LocalSearch] trait.
+///
+/// In each iteration random move is drawn from the neighbourhood. Improving moves are always accepted,
+/// worsening ones with probability `exp(-delta / T)`, where `T` is the current temperature given by
+/// [CoolingSchedule]. Search stops after given number of iterations or once temperature drops
+/// below final temperature.
+///
+/// Rejected moves are undone with [Neighbourhood::inverse], so the solution is never cloned
+/// except for storing the best one.
+pub struct SimulatedAnnealing<N, C, R: Rng = ThreadRng> {
+    neighbourhood: N,
+    cooling: C,
+    initial_temperature: f64,
+    final_temperature: f64,
+    iterations: usize,
+    rng: R,
+}
+
+impl<N, C> SimulatedAnnealing<N, C, ThreadRng> {
+    /// Returns new instance of [SimulatedAnnealing] with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `neighbourhood` - moves applied to the solution
+    /// * `cooling` - cooling schedule
+    /// * `initial_temperature` - temperature of the first iteration, must be > 0
+    /// * `iterations` - maximal number of iterations
+    pub fn new(neighbourhood: N, cooling: C, initial_temperature: f64, iterations: usize) -> Self {
+        Self::with_rng(
+            neighbourhood,
+            cooling,
+            initial_temperature,
+            iterations,
+            rand::thread_rng(),
+        )
+    }
+}
+
+impl<N, C, R: Rng> SimulatedAnnealing<N, C, R> {
+    /// Returns new instance of [SimulatedAnnealing] with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `neighbourhood` - moves applied to the solution
+    /// * `cooling` - cooling schedule
+    /// * `initial_temperature` - temperature of the first iteration, must be > 0
+    /// * `iterations` - maximal number of iterations
+    /// * `rng` - random number generator
+    pub fn with_rng(
+        neighbourhood: N,
+        cooling: C,
+        initial_temperature: f64,
+        iterations: usize,
+        rng: R,
+    ) -> Self {
+        assert!(initial_temperature > 0.0, "Initial temperature must be > 0");
+        Self {
+            neighbourhood,
+            cooling,
+            initial_temperature,
+            final_temperature: 1e-8,
+            iterations,
+            rng,
+        }
+    }
+
+    /// Sets temperature below which the search stops (1e-8 by default)
+    ///
+    /// ## Arguments
+    ///
+    /// * `final_temperature` - must be >= 0
+    pub fn with_final_temperature(mut self, final_temperature: f64) -> Self {
+        assert!(final_temperature >= 0.0, "Final temperature must be >= 0");
+        self.final_temperature = final_temperature;
+        self
+    }
+
+    /// Returns random number generator, e.g. to reseed it
+    pub fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+}
+
+impl<S, N, C, R> LocalSearch<S> for SimulatedAnnealing<N, C, R>
+where
+    S: Clone,
+    N: Neighbourhood<S>,
+    C: CoolingSchedule,
+    R: Rng,
+{
+    fn search<F: FnMut(&S) -> f64>(&mut self, initial: S, mut objective: F) -> SearchResult<S> {
+        let mut current = initial;
+        let mut current_value = objective(&current);
+        let mut best = current.clone();
+        let mut best_value = current_value;
+        let mut evaluations = 1;
+        let mut iteration = 0;
+
+        while iteration < self.iterations {
+            let temperature = self.cooling.temperature(self.initial_temperature, iteration);
+            if temperature <= self.final_temperature {
+                break;
+            }
+            iteration += 1;
+
+            let mv = self.neighbourhood.random_move(&current, &mut self.rng);
+            self.neighbourhood.apply(&mut current, &mv);
+            let value = objective(&current);
+            evaluations += 1;
+
+            let delta = value - current_value;
+            if delta <= 0.0 || self.rng.gen::<f64>() < (-delta / temperature).exp() {
+                current_value = value;
+                if value < best_value {
+                    best_value = value;
+                    best.clone_from(&current);
+                }
+            } else {
+                self.neighbourhood
+                    .apply(&mut current, &self.neighbourhood.inverse(&mv));
+            }
+        }
+
+        SearchResult {
+            solution: best,
+            fitness: best_value,
+            iterations: iteration,
+            evaluations,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::SimulatedAnnealing;
+    use crate::local_search::{
+        cooling::{Geometric, Linear},
+        neighbourhood::TwoOpt,
+        LocalSearch,
+    };
+
+    /// Returns length of tour visiting `n` points evenly spread on unit circle in given order
+    #[allow(clippy::ptr_arg)]
+    pub(in crate::local_search) fn circle_tour_length(tour: &Vec<usize>) -> f64 {
+        let n = tour.len() as f64;
+        let point = |i: usize| {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / n;
+            (angle.cos(), angle.sin())
+        };
+        (0..tour.len())
+            .map(|i| {
+                let (x1, y1) = point(tour[i]);
+                let (x2, y2) = point(tour[(i + 1) % tour.len()]);
+                ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()
+            })
+            .sum()
+    }
+
+    pub(in crate::local_search) fn shuffled_tour(n: usize, seed: u64) -> Vec<usize> {
+        let mut tour: Vec<usize> = (0..n).collect();
+        tour.shuffle(&mut StdRng::seed_from_u64(seed));
+        tour
+    }
+
+    #[test]
+    fn finds_optimal_tour() {
+        let n = 12;
+        let optimum = circle_tour_length(&(0..n).collect());
+        let mut annealing = SimulatedAnnealing::with_rng(
+            TwoOpt,
+            Geometric::new(0.995),
+            1.0,
+            20_000,
+            StdRng::seed_from_u64(1),
+        );
+
+        let result = annealing.search(shuffled_tour(n, 2), circle_tour_length);
+
+        assert!((result.fitness - optimum).abs() < 1e-9);
+        assert!((circle_tour_length(&result.solution) - result.fitness).abs() < 1e-12);
+    }
+
+    #[test]
+    fn stops_when_temperature_drops_below_final_one() {
+        let mut annealing =
+            SimulatedAnnealing::new(TwoOpt, Linear::new(0.1), 1.0, 1_000).with_final_temperature(0.0);
+
+        let result = annealing.search(shuffled_tour(6, 3), circle_tour_length);
+
+        assert_eq!(result.iterations, 10);
+        assert_eq!(result.evaluations, 11);
+    }
+}
@@ -0,0 +1,97 @@
+//! Cooling schedules of simulated annealing
+//!
+//! Following schedules are implemented:
+//!
+//! * [Geometric]
+//! * [Linear]
+//! * [Logarithmic]
+//! * [FnBasedCooling]
+
+/// # Cooling schedule
+///
+/// This trait defines how temperature of simulated annealing decreases over time.
+/// You can implement this trait to provide your custom (possibly stateful) schedule.
+pub trait CoolingSchedule {
+    /// Returns temperature in given iteration
+    ///
+    /// ## Arguments
+    ///
+    /// * `initial_temperature` - temperature of the first iteration
+    /// * `iteration` - number of the iteration, starting from 0
+    fn temperature(&mut self, initial_temperature: f64, iteration: usize) -> f64;
+}
+
+/// `T_k = T_0 * alpha^k`
+pub struct Geometric {
+    alpha: f64,
+}
+
+impl Geometric {
+    /// Returns new instance of [Geometric] cooling schedule
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - cooling factor, must be in range (0, 1), usually from range [0.8, 0.99]
+    pub fn new(alpha: f64) -> Self {
+        assert!(
+            0.0 < alpha && alpha < 1.0,
+            "Cooling factor must be in range (0, 1)"
+        );
+        Self { alpha }
+    }
+}
+
+impl CoolingSchedule for Geometric {
+    fn temperature(&mut self, initial_temperature: f64, iteration: usize) -> f64 {
+        initial_temperature * self.alpha.powf(iteration as f64)
+    }
+}
+
+/// `T_k = max(T_0 - delta * k, 0)`
+pub struct Linear {
+    delta: f64,
+}
+
+impl Linear {
+    /// Returns new instance of [Linear] cooling schedule
+    ///
+    /// ## Arguments
+    ///
+    /// * `delta` - temperature decrease per iteration, must be > 0
+    pub fn new(delta: f64) -> Self {
+        assert!(delta > 0.0, "Temperature decrease must be > 0");
+        Self { delta }
+    }
+}
+
+impl CoolingSchedule for Linear {
+    fn temperature(&mut self, initial_temperature: f64, iteration: usize) -> f64 {
+        (initial_temperature - self.delta * iteration as f64).max(0.0)
+    }
+}
+
+/// `T_k = T_0 / ln(k + e)`. Cools down very slowly.
+pub struct Logarithmic;
+
+impl CoolingSchedule for Logarithmic {
+    fn temperature(&mut self, initial_temperature: f64, iteration: usize) -> f64 {
+        initial_temperature / (iteration as f64 + std::f64::consts::E).ln()
+    }
+}
+
+/// Cooling schedule defined by pointer to a function of `(initial_temperature, iteration)`
+pub struct FnBasedCooling {
+    fn_ptr: fn(f64, usize) -> f64,
+}
+
+impl FnBasedCooling {
+    pub fn new(fn_ptr: fn(f64, usize) -> f64) -> Self {
+        Self { fn_ptr }
+    }
+}
+
+impl CoolingSchedule for FnBasedCooling {
+    fn temperature(&mut self, initial_temperature: f64, iteration: usize) -> f64 {
+        (self.fn_ptr)(initial_temperature, iteration)
+    }
+}
@@ -0,0 +1,177 @@
+//! Neighbourhood structures of permutation (sequence) problems
+//!
+//! Following neighbourhoods are implemented:
+//!
+//! * [Swap]
+//! * [Insert]
+//! * [TwoOpt]
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use rand::Rng;
+
+/// # Neighbourhood
+///
+/// This trait defines moves transforming a solution into its neighbour.
+/// You can implement this trait to provide your custom neighbourhood.
+pub trait Neighbourhood<S> {
+    /// Move description, e.g. positions of swapped elements
+    type Move: Clone + Eq + Hash + Debug;
+
+    /// Returns every move applicable to `solution`
+    fn moves(&self, solution: &S) -> Vec<Self::Move>;
+
+    /// Returns random move applicable to `solution`
+    fn random_move<R: Rng + ?Sized>(&self, solution: &S, rng: &mut R) -> Self::Move;
+
+    /// Applies `mv` to `solution` in place
+    fn apply(&self, solution: &mut S, mv: &Self::Move);
+
+    /// Returns move undoing `mv`
+    fn inverse(&self, mv: &Self::Move) -> Self::Move;
+}
+
+/// Returns two distinct positions in ascending order
+fn distinct_pair<R: Rng + ?Sized>(len: usize, rng: &mut R) -> (usize, usize) {
+    assert!(len >= 2, "Solution must have at least two elements");
+    let i = rng.gen_range(0..len);
+    let j = (i + rng.gen_range(1..len)) % len;
+    (i.min(j), i.max(j))
+}
+
+/// Exchanges elements at positions `i` & `j`, move is described by `(i, j)` with `i < j`
+pub struct Swap;
+
+impl<T> Neighbourhood<Vec<T>> for Swap {
+    type Move = (usize, usize);
+
+    fn moves(&self, solution: &Vec<T>) -> Vec<Self::Move> {
+        let n = solution.len();
+        (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))).collect()
+    }
+
+    fn random_move<R: Rng + ?Sized>(&self, solution: &Vec<T>, rng: &mut R) -> Self::Move {
+        distinct_pair(solution.len(), rng)
+    }
+
+    fn apply(&self, solution: &mut Vec<T>, mv: &Self::Move) {
+        solution.swap(mv.0, mv.1);
+    }
+
+    fn inverse(&self, mv: &Self::Move) -> Self::Move {
+        *mv
+    }
+}
+
+/// Removes element at position `from` & inserts it at position `to`, move is described by `(from, to)`.
+/// Moving element one position back is the same as moving its predecessor one position forward,
+/// therefore moves of adjacent elements are always described with `from < to`.
+pub struct Insert;
+
+impl Insert {
+    fn normalized(from: usize, to: usize) -> (usize, usize) {
+        if from == to + 1 {
+            (to, from)
+        } else {
+            (from, to)
+        }
+    }
+}
+
+impl<T> Neighbourhood<Vec<T>> for Insert {
+    type Move = (usize, usize);
+
+    fn moves(&self, solution: &Vec<T>) -> Vec<Self::Move> {
+        let n = solution.len();
+        (0..n)
+            .flat_map(|from| (0..n).map(move |to| (from, to)))
+            .filter(|(from, to)| from != to && *from != to + 1)
+            .collect()
+    }
+
+    fn random_move<R: Rng + ?Sized>(&self, solution: &Vec<T>, rng: &mut R) -> Self::Move {
+        let (i, j) = distinct_pair(solution.len(), rng);
+        if rng.gen::<bool>() {
+            Self::normalized(i, j)
+        } else {
+            Self::normalized(j, i)
+        }
+    }
+
+    fn apply(&self, solution: &mut Vec<T>, mv: &Self::Move) {
+        let element = solution.remove(mv.0);
+        solution.insert(mv.1, element);
+    }
+
+    fn inverse(&self, mv: &Self::Move) -> Self::Move {
+        Self::normalized(mv.1, mv.0)
+    }
+}
+
+/// Reverses segment between positions `i` & `j` (inclusive), move is described by `(i, j)` with `i < j`.
+/// For tours (e.g. TSP) it replaces two edges with two other edges.
+pub struct TwoOpt;
+
+impl<T> Neighbourhood<Vec<T>> for TwoOpt {
+    type Move = (usize, usize);
+
+    fn moves(&self, solution: &Vec<T>) -> Vec<Self::Move> {
+        let n = solution.len();
+        (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))).collect()
+    }
+
+    fn random_move<R: Rng + ?Sized>(&self, solution: &Vec<T>, rng: &mut R) -> Self::Move {
+        distinct_pair(solution.len(), rng)
+    }
+
+    fn apply(&self, solution: &mut Vec<T>, mv: &Self::Move) {
+        solution[mv.0..=mv.1].reverse();
+    }
+
+    fn inverse(&self, mv: &Self::Move) -> Self::Move {
+        *mv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Insert, Neighbourhood, Swap, TwoOpt};
+
+    fn assert_inverse_restores<N: Neighbourhood<Vec<usize>>>(neighbourhood: N) {
+        let original: Vec<usize> = (0..6).collect();
+        for mv in neighbourhood.moves(&original) {
+            let mut solution = original.clone();
+            neighbourhood.apply(&mut solution, &mv);
+            assert_ne!(solution, original, "{mv:?} does not change solution");
+            neighbourhood.apply(&mut solution, &neighbourhood.inverse(&mv));
+            assert_eq!(solution, original, "Inverse of {mv:?} does not restore solution");
+        }
+    }
+
+    #[test]
+    fn inverse_moves_restore_solution() {
+        assert_inverse_restores(Swap);
+        assert_inverse_restores(Insert);
+        assert_inverse_restores(TwoOpt);
+    }
+
+    #[test]
+    fn moves_are_applied_as_expected() {
+        let mut solution = vec![0, 1, 2, 3, 4];
+        Swap.apply(&mut solution, &(1, 3));
+        assert_eq!(solution, vec![0, 3, 2, 1, 4]);
+        Insert.apply(&mut solution, &(0, 3));
+        assert_eq!(solution, vec![3, 2, 1, 0, 4]);
+        TwoOpt.apply(&mut solution, &(1, 4));
+        assert_eq!(solution, vec![3, 4, 0, 1, 2]);
+    }
+
+    #[test]
+    fn neighbourhoods_have_no_duplicate_moves() {
+        let solution: Vec<usize> = (0..5).collect();
+        assert_eq!(Neighbourhood::<Vec<usize>>::moves(&Swap, &solution).len(), 10);
+        assert_eq!(Neighbourhood::<Vec<usize>>::moves(&Insert, &solution).len(), 16);
+        assert_eq!(Neighbourhood::<Vec<usize>>::moves(&TwoOpt, &solution).len(), 10);
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use rand::{rngs::ThreadRng, Rng};
+
+use super::{neighbourhood::Neighbourhood, LocalSearch, SearchResult};
+
+/// Criterion allowing tabu moves
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aspiration {
+    /// Tabu moves are never allowed
+    None,
+    /// Tabu move is allowed if it leads to solution better than the best one found so far
+    #[default]
+    BestSoFar,
+    /// Tabu move is allowed if it improves the current solution
+    ImprovesCurrent,
+}
+
+/// # Tabu search
+///
+/// Implements [LocalSearch] trait.
+///
+/// In each iteration the best non-tabu move is applied, even if it worsens the solution. Inverse of applied
+/// move becomes tabu for `tenure` iterations, which prevents the search from cycling. Tabu moves can
+/// still be applied if they satisfy [Aspiration] criterion.
+///
+/// By default whole neighbourhood is examined in each iteration. For large neighbourhoods
+/// use [TabuSearch::with_candidate_list_size] to examine only given number of random moves.
+pub struct TabuSearch<N, R: Rng = ThreadRng> {
+    neighbourhood: N,
+    tenure: usize,
+    iterations: usize,
+    aspiration: Aspiration,
+    candidate_list_size: Option<usize>,
+    stagnation_limit: Option<usize>,
+    rng: R,
+}
+
+impl<N> TabuSearch<N, ThreadRng> {
+    /// Returns new instance of [TabuSearch] with default RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `neighbourhood` - moves applied to the solution
+    /// * `tenure` - number of iterations move stays tabu for
+    /// * `iterations` - maximal number of iterations
+    pub fn new(neighbourhood: N, tenure: usize, iterations: usize) -> Self {
+        Self::with_rng(neighbourhood, tenure, iterations, rand::thread_rng())
+    }
+}
+
+impl<N, R: Rng> TabuSearch<N, R> {
+    /// Returns new instance of [TabuSearch] with custom RNG
+    ///
+    /// ## Arguments
+    ///
+    /// * `neighbourhood` - moves applied to the solution
+    /// * `tenure` - number of iterations move stays tabu for
+    /// * `iterations` - maximal number of iterations
+    /// * `rng` - random number generator, used only with candidate lists
+    pub fn with_rng(neighbourhood: N, tenure: usize, iterations: usize, rng: R) -> Self {
+        Self {
+            neighbourhood,
+            tenure,
+            iterations,
+            aspiration: Aspiration::default(),
+            candidate_list_size: None,
+            stagnation_limit: None,
+            rng,
+        }
+    }
+
+    /// Sets aspiration criterion ([Aspiration::BestSoFar] by default)
+    ///
+    /// ## Arguments
+    ///
+    /// * `aspiration` - criterion allowing tabu moves
+    pub fn with_aspiration(mut self, aspiration: Aspiration) -> Self {
+        self.aspiration = aspiration;
+        self
+    }
+
+    /// Makes the search examine only `size` random moves in each iteration
+    ///
+    /// ## Arguments
+    ///
+    /// * `size` - number of examined moves, must be > 0
+    pub fn with_candidate_list_size(mut self, size: usize) -> Self {
+        assert!(size > 0, "Candidate list size must be > 0");
+        self.candidate_list_size = Some(size);
+        self
+    }
+
+    /// Makes the search stop after `iterations` iterations without improvement of the best solution
+    ///
+    /// ## Arguments
+    ///
+    /// * `iterations` - number of iterations without improvement
+    pub fn with_stagnation_limit(mut self, iterations: usize) -> Self {
+        self.stagnation_limit = Some(iterations);
+        self
+    }
+
+    /// Returns random number generator, e.g. to reseed it
+    pub fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+}
+
+impl<S, N, R> LocalSearch<S> for TabuSearch<N, R>
+where
+    S: Clone,
+    N: Neighbourhood<S>,
+    R: Rng,
+{
+    fn search<F: FnMut(&S) -> f64>(&mut self, initial: S, mut objective: F) -> SearchResult<S> {
+        let mut current = initial;
+        let mut current_value = objective(&current);
+        let mut best = current.clone();
+        let mut best_value = current_value;
+        let mut evaluations = 1;
+        let mut iteration = 0;
+        let mut since_improvement = 0;
+
+        // Move -> first iteration it is no longer tabu in
+        let mut tabu: HashMap<N::Move, usize> = HashMap::new();
+
+        while iteration < self.iterations {
+            let candidates = match self.candidate_list_size {
+                Some(size) => (0..size)
+                    .map(|_| self.neighbourhood.random_move(&current, &mut self.rng))
+                    .collect(),
+                None => self.neighbourhood.moves(&current),
+            };
+
+            let mut chosen: Option<(N::Move, f64)> = None;
+            for mv in candidates {
+                self.neighbourhood.apply(&mut current, &mv);
+                let value = objective(&current);
+                evaluations += 1;
+                self.neighbourhood
+                    .apply(&mut current, &self.neighbourhood.inverse(&mv));
+
+                let is_tabu = matches!(tabu.get(&mv), Some(expiry) if *expiry > iteration);
+                let is_aspirated = match self.aspiration {
+                    Aspiration::None => false,
+                    Aspiration::BestSoFar => value < best_value,
+                    Aspiration::ImprovesCurrent => value < current_value,
+                };
+                if is_tabu && !is_aspirated {
+                    continue;
+                }
+                match chosen {
+                    Some((_, chosen_value)) if chosen_value <= value => {}
+                    _ => chosen = Some((mv, value)),
+                }
+            }
+
+            // Every move is tabu
+            let Some((mv, value)) = chosen else {
+                break;
+            };
+
+            iteration += 1;
+            self.neighbourhood.apply(&mut current, &mv);
+            current_value = value;
+            tabu.insert(self.neighbourhood.inverse(&mv), iteration + self.tenure);
+            tabu.retain(|_, expiry| *expiry > iteration);
+
+            if current_value < best_value {
+                best_value = current_value;
+                best.clone_from(&current);
+                since_improvement = 0;
+            } else {
+                since_improvement += 1;
+                if matches!(self.stagnation_limit, Some(limit) if since_improvement >= limit) {
+                    break;
+                }
+            }
+        }
+
+        SearchResult {
+            solution: best,
+            fitness: best_value,
+            iterations: iteration,
+            evaluations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Aspiration, TabuSearch};
+    use crate::local_search::{
+        annealing::tests::{circle_tour_length, shuffled_tour},
+        neighbourhood::{Insert, Swap, TwoOpt},
+        LocalSearch,
+    };
+
+    #[test]
+    fn finds_optimal_tour() {
+        let n = 12;
+        let optimum = circle_tour_length(&(0..n).collect());
+        let mut search = TabuSearch::new(TwoOpt, 5, 100).with_stagnation_limit(20);
+
+        let result = search.search(shuffled_tour(n, 4), circle_tour_length);
+
+        assert!((result.fitness - optimum).abs() < 1e-9);
+        assert!(result.iterations < 100);
+    }
+
+    #[test]
+    fn escapes_local_optima_with_insert_moves() {
+        // Sum of |p_i - p_(i+1)| has local optima with respect to single moves
+        let objective =
+            |p: &Vec<usize>| p.windows(2).map(|w| w[0].abs_diff(w[1]) as f64).sum::<f64>() + p[0] as f64;
+        let mut search = TabuSearch::with_rng(Insert, 3, 200, StdRng::seed_from_u64(5))
+            .with_aspiration(Aspiration::BestSoFar)
+            .with_candidate_list_size(20);
+
+        let result = search.search(vec![3, 0, 5, 1, 4, 2], objective);
+
+        assert_eq!(result.fitness, 5.0);
+        assert_eq!(result.solution, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn stops_when_every_move_is_tabu() {
+        // Only one swap is possible & it becomes tabu right after being applied
+        let mut search = TabuSearch::new(Swap, 10, 100).with_aspiration(Aspiration::None);
+
+        let result = search.search(vec![1, 0], |p: &Vec<usize>| p[0] as f64);
+
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.solution, vec![0, 1]);
+    }
+}
@@ -0,0 +1,42 @@
+//! Building blocks shared by algorithms minimising real valued functions, i.e. [cmaes](crate::cmaes)
+//! & [de](crate::de).
+//!
+//! State of each of these algorithms implements [OptimizerState], so that the same
+//! [termination conditions](termination_condition) & [probes](probe) can be used with all of them.
+
+pub mod probe;
+pub mod termination_condition;
+
+use std::time::Instant;
+
+/// Common view of the state of an algorithm minimising real valued function
+pub trait OptimizerState {
+    /// Returns number of generations done
+    fn generation(&self) -> usize;
+
+    /// Returns number of objective function evaluations done
+    fn evaluations(&self) -> usize;
+
+    /// Returns number of restarts done, 0 for algorithms without restarts
+    fn restarts(&self) -> usize {
+        0
+    }
+
+    /// Returns fitness of the best solution found so far
+    fn best_fitness(&self) -> f64;
+
+    /// Returns the best solution found so far
+    fn best_solution(&self) -> &[f64];
+
+    /// Returns start time of the algorithm
+    fn start_time(&self) -> Instant;
+
+    /// Returns summary of the last generation, printed by [StdoutProbe](probe::stdout_probe::StdoutProbe)
+    fn summary(&self) -> String {
+        format!(
+            "Generation {}: best value: {}",
+            self.generation(),
+            self.best_fitness()
+        )
+    }
+}
@@ -0,0 +1,11 @@
+pub mod aggregated_probe;
+pub mod empty_probe;
+pub mod stdout_probe;
+
+pub trait Probe<S> {
+    fn on_begin(&mut self, state: &S);
+    fn on_new_generation(&mut self, state: &S);
+    /// Called after each restart, only by algorithms supporting restarts
+    fn on_restart(&mut self, _state: &S) {}
+    fn on_end(&mut self, state: &S);
+}
@@ -0,0 +1,46 @@
+use crate::optimizer::probe::Probe;
+
+pub struct AggregatedProbe<S> {
+    probes: Vec<Box<dyn Probe<S>>>,
+}
+
+impl<S> AggregatedProbe<S> {
+    pub fn new() -> AggregatedProbe<S> {
+        AggregatedProbe { probes: vec![] }
+    }
+
+    pub fn from_probes(probes: Vec<Box<dyn Probe<S>>>) -> AggregatedProbe<S> {
+        AggregatedProbe { probes }
+    }
+
+    pub fn add_probe<Pr: Probe<S> + 'static>(mut self, probe: Pr) -> AggregatedProbe<S> {
+        self.probes.push(Box::new(probe));
+        self
+    }
+}
+
+impl<S> Probe<S> for AggregatedProbe<S> {
+    fn on_begin(&mut self, state: &S) {
+        for probe in self.probes.iter_mut() {
+            probe.on_begin(state);
+        }
+    }
+
+    fn on_new_generation(&mut self, state: &S) {
+        for probe in self.probes.iter_mut() {
+            probe.on_new_generation(state);
+        }
+    }
+
+    fn on_restart(&mut self, state: &S) {
+        for probe in self.probes.iter_mut() {
+            probe.on_restart(state);
+        }
+    }
+
+    fn on_end(&mut self, state: &S) {
+        for probe in self.probes.iter_mut() {
+            probe.on_end(state);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::optimizer::probe::Probe;
+
+pub struct EmptyProbe {}
+
+impl EmptyProbe {
+    pub fn new() -> EmptyProbe {
+        EmptyProbe {}
+    }
+}
+
+impl<S> Probe<S> for EmptyProbe {
+    fn on_begin(&mut self, _state: &S) {}
+
+    fn on_new_generation(&mut self, _state: &S) {}
+
+    fn on_end(&mut self, _state: &S) {}
+}
@@ -0,0 +1,30 @@
+use crate::optimizer::probe::Probe;
+use crate::optimizer::OptimizerState;
+
+pub struct StdoutProbe {}
+
+impl StdoutProbe {
+    pub fn new() -> StdoutProbe {
+        StdoutProbe {}
+    }
+}
+
+impl<S: OptimizerState> Probe<S> for StdoutProbe {
+    fn on_begin(&mut self, _state: &S) {
+        println!("START");
+    }
+
+    fn on_new_generation(&mut self, state: &S) {
+        println!("{}", state.summary());
+    }
+
+    fn on_restart(&mut self, state: &S) {
+        println!("RESTART {}", state.restarts());
+    }
+
+    fn on_end(&mut self, state: &S) {
+        println!("END");
+        println!("Best value: {}", state.best_fitness());
+        println!("Best solution: {:?}", state.best_solution());
+    }
+}
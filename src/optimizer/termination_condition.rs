@@ -0,0 +1,106 @@
+use crate::optimizer::OptimizerState;
+use std::time::Duration;
+
+pub trait TerminationCondition<S> {
+    fn initialize(&mut self, state: &S);
+    fn is_met(&self, state: &S) -> bool;
+}
+
+/// Met once given number of generations (summed over all restarts) is done
+pub struct GenerationLimit {
+    generation_limit: usize,
+}
+
+impl GenerationLimit {
+    pub fn new(generation_limit: usize) -> GenerationLimit {
+        GenerationLimit { generation_limit }
+    }
+}
+
+impl<S: OptimizerState> TerminationCondition<S> for GenerationLimit {
+    fn initialize(&mut self, _state: &S) {}
+
+    fn is_met(&self, state: &S) -> bool {
+        state.generation() >= self.generation_limit
+    }
+}
+
+/// Met once given number of objective function evaluations (summed over all restarts) is done
+pub struct EvaluationLimit {
+    evaluation_limit: usize,
+}
+
+impl EvaluationLimit {
+    pub fn new(evaluation_limit: usize) -> EvaluationLimit {
+        EvaluationLimit { evaluation_limit }
+    }
+}
+
+impl<S: OptimizerState> TerminationCondition<S> for EvaluationLimit {
+    fn initialize(&mut self, _state: &S) {}
+
+    fn is_met(&self, state: &S) -> bool {
+        state.evaluations() >= self.evaluation_limit
+    }
+}
+
+/// Met once the algorithm runs for given time
+pub struct TimeLimit {
+    time_limit: Duration,
+}
+
+impl TimeLimit {
+    pub fn new(time_limit: Duration) -> TimeLimit {
+        TimeLimit { time_limit }
+    }
+}
+
+impl<S: OptimizerState> TerminationCondition<S> for TimeLimit {
+    fn initialize(&mut self, _state: &S) {}
+
+    fn is_met(&self, state: &S) -> bool {
+        state.start_time().elapsed() >= self.time_limit
+    }
+}
+
+/// Met once fitness of the best solution found is <= `target`
+pub struct FitnessTarget {
+    target: f64,
+}
+
+impl FitnessTarget {
+    pub fn new(target: f64) -> FitnessTarget {
+        FitnessTarget { target }
+    }
+}
+
+impl<S: OptimizerState> TerminationCondition<S> for FitnessTarget {
+    fn initialize(&mut self, _state: &S) {}
+
+    fn is_met(&self, state: &S) -> bool {
+        state.best_fitness() <= self.target
+    }
+}
+
+/// Met once any of given conditions is met
+pub struct Any<S> {
+    conditions: Vec<Box<dyn TerminationCondition<S>>>,
+}
+
+impl<S> Any<S> {
+    pub fn new(conditions: Vec<Box<dyn TerminationCondition<S>>>) -> Any<S> {
+        Any { conditions }
+    }
+}
+
+impl<S> TerminationCondition<S> for Any<S> {
+    fn initialize(&mut self, state: &S) {
+        for condition in self.conditions.iter_mut() {
+            condition.initialize(state);
+        }
+    }
+
+    fn is_met(&self, state: &S) -> bool {
+        self.conditions.iter().any(|condition| condition.is_met(state))
+    }
+}
@@ -8,6 +8,10 @@
 
 #[cfg(feature = "aco")]
 pub use crate::aco;
+#[cfg(feature = "cmaes")]
+pub use crate::cmaes;
+#[cfg(feature = "de")]
+pub use crate::de;
 #[cfg(feature = "ff")]
 pub use crate::ff;
 #[cfg(feature = "ga")]
@@ -18,6 +22,8 @@ pub use crate::ga::operators as ops;
 pub use crate::ga::operators::{crossover, fitness, mutation, replacement, selection};
 #[cfg(feature = "ga")]
 pub use crate::ga::population;
+#[cfg(feature = "local_search")]
+pub use crate::local_search;
 #[cfg(feature = "pso")]
 pub use crate::pso;
 #[cfg(feature = "test_functions")]
@@ -14,6 +14,11 @@ pub fn ackley2(x: &Vec<f64>) -> f64 {
     f64::powf(-200_f64 * f64::consts::E, -0.02) * f64::sqrt(f64::powi(x1, 2) + f64::powi(x2, 2))
 }
 
+#[allow(clippy::ptr_arg)]
+pub fn sphere(x: &Vec<f64>) -> f64 {
+    x.iter().map(|xi| xi * xi).sum()
+}
+
 #[allow(clippy::ptr_arg)]
 pub fn wordmax(chromosome: &Vec<bool>) -> f64 {
     chromosome.iter().filter(|gene| **gene).count() as f64
@@ -91,3 +96,70 @@ fn rvc_does_not_panic_with_some_operators_unspecified() {
 fn bsc_does_not_panic_with_some_operators_unsepcified() {
     let _ = ecrs::ga::Builder::with_bsc().dim(10).fitness_fn(wordmax).build();
 }
+
+#[test]
+fn bsc_stops_when_termination_condition_is_met() {
+    let mut solver = ecrs::ga::Builder::with_bsc()
+        .fitness_fn(wordmax)
+        .dim(10)
+        .set_population_size(20)
+        .set_max_generation_count(10_000)
+        .set_termination_condition(ecrs::ga::termination_condition::EvaluationBudget::new(200))
+        .build();
+
+    assert!(solver.run().is_some());
+    assert!(solver.metrics().evaluation_count >= 200);
+    assert!(solver.metrics().generation < 10_000);
+}
+
+#[test]
+fn rvc_runs_with_same_seed_are_identical() {
+    let run = |seed: u64| {
+        ecrs::ga::Builder::with_rvc()
+            .fitness_fn(sphere)
+            .dim(2)
+            .set_population_size(30)
+            .set_max_generation_count(50)
+            .set_seed(seed)
+            .build()
+            .run()
+            .unwrap()
+    };
+
+    let first = run(42);
+    let second = run(42);
+
+    assert_eq!(first.chromosome, second.chromosome);
+    assert_eq!(first.fitness, second.fitness);
+}
+
+#[test]
+fn rvc_infers_dimension_from_bounds() {
+    let bounds = ecrs::ga::bounds::Bounds::uniform(3, 2.0..4.0);
+    let mut solver = ecrs::ga::Builder::with_rvc()
+        .fitness_fn(sphere)
+        .set_bounds(bounds.clone())
+        .set_max_generation_count(5)
+        .build();
+
+    let best = solver.run().unwrap();
+    assert!(bounds.contains(&best.chromosome));
+}
+
+#[test]
+fn rvc_default_operators_respect_bounds() {
+    let bounds = ecrs::ga::bounds::Bounds::new(vec![-1.0..0.0, 5.0..6.0, 10.0..20.0]);
+    let mut solver = ecrs::ga::Builder::with_rvc()
+        .fitness_fn(sphere)
+        .set_bounds(bounds.clone())
+        .set_population_size(20)
+        .set_max_generation_count(30)
+        .set_seed(3)
+        .build();
+
+    solver.run();
+    assert!(solver
+        .population()
+        .iter()
+        .all(|idv| bounds.contains(&idv.chromosome)));
+}